@@ -1,22 +1,93 @@
-use crate::controlroom::git_provider::get_commits;
+use crate::controlroom::config::redact_sensitive_fields;
+use crate::controlroom::events::{
+    emit_state_error, emit_state_ready, emit_workspace_archive_progress, emit_workspace_import_progress,
+};
+use crate::controlroom::git_provider::{
+    checkout, commit, diff_workspace_file_from_head, fetch, get_blame, get_branches, get_commit_detail, get_commits,
+    get_file_diff, get_file_history, get_stash_list, get_status, get_tags, pull, push, stage_paths, stash_apply,
+    stash_push, sync_state, sync_state_all, unstage_paths,
+};
 use crate::controlroom::types::{
-    ControlRoomConfig, GitCommit, RunnerCommandInput, RunnerStartResponse, ServiceConfig,
-    ServiceLogEvent, ServiceStatus, VideoLaunchNativeInput, VideoLaunchNativeResult,
-    VideoSnapshotAnalyzeInput, VideoSnapshotAnalyzeResult, WorkspaceEntry,
+    ConfigIssue, ControlRoomConfig, GitBlameLine, GitBranch, GitCheckoutResult, GitCommit, GitCommitDetail,
+    GitCommitFilter, GitCommitPage, GitFetchResult, GitFileDiff, GitPullResult, GitPushResult, GitStashApplyResult,
+    GitStashEntry, GitStatus, GitSyncState, GitTag, HealthCheckResult, LogExportQuery, LogExportSummary,
+    PRIMARY_WORKSPACE_ROOT_ID, RecentFileEntry, RunnerCommandInput, RunnerExportFormat, RunnerExportSummary,
+    RunnerHistoryEntry, RunnerListResponse, RunnerOutputEvent, RunnerPreset, RunnerStartResponse, ServiceConfig,
+    ServiceLogEvent, ServiceLogFilter, ServiceLogStats, ServiceStatus,
+    ServiceStatusSummary, VideoExportFormat, VideoExportSummary, VideoLaunchNativeInput,
+    VideoLaunchNativeResult, VideoSnapshotAnalyzeInput, VideoSnapshotAnalyzeResult,
+    WorkspaceArchiveFormat, WorkspaceArchiveProgressEvent, WorkspaceArchiveResult, WorkspaceBinaryFile,
+    WorkspaceChecksumAlgorithm, WorkspaceChecksumResult, WorkspaceDiskUsage, WorkspaceEntry,
+    WorkspaceEntryKind, WorkspaceFileContent, WorkspaceFileRange, WorkspaceGrepOptions,
+    WorkspaceGrepResult, WorkspaceImportProgressEvent, WorkspaceImportResult, WorkspaceListOptions,
+    WorkspaceListResult, WorkspaceQuickOpenEntry, WorkspaceSearchResult, WorkspaceTreeNode, WorkspaceWriteResult,
+};
+use crate::controlroom::workspace::{
+    archive_workspace_path, build_workspace_tree, checksum_workspace_entry, create_workspace_entry,
+    delete_workspace_entry, grep_workspace, import_workspace_file, list_recently_modified_files,
+    list_workspace_entries, move_workspace_entry, open_workspace_file_external, read_workspace_file,
+    read_workspace_file_binary, read_workspace_file_range, search_workspace_names,
+    set_workspace_entry_permissions, tail_workspace_file, trash_workspace_entry, workspace_disk_usage,
+    write_workspace_file, write_workspace_file_binary,
 };
-use crate::controlroom::workspace::{list_workspace_entries, read_workspace_file, write_workspace_file};
 use crate::controlroom::ControlRoomState;
 use tauri::{AppHandle, State};
 
 async fn ensure_config(state: &ControlRoomState) -> Result<ControlRoomConfig, String> {
-    state.load_config().await
+    if state.is_config_loaded() {
+        Ok(state.get_config().await)
+    } else {
+        state.load_config().await
+    }
 }
 
 #[tauri::command]
 pub async fn controlroom_load_config(
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<ControlRoomConfig, String> {
+    match state.load_config().await {
+        Ok(config) => {
+            if !state.mark_initialized() {
+                emit_state_ready(&app, &config);
+            }
+            Ok(redact_sensitive_fields(&config))
+        }
+        Err(error) => {
+            if !state.is_initialized() {
+                emit_state_error(&app, &error);
+            }
+            Err(error)
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn controlroom_is_initialized(state: State<'_, ControlRoomState>) -> Result<bool, String> {
+    Ok(state.is_initialized())
+}
+
+#[tauri::command]
+pub async fn controlroom_reload_config(
+    app: AppHandle,
     state: State<'_, ControlRoomState>,
 ) -> Result<ControlRoomConfig, String> {
-    state.load_config().await
+    state.reload_config(&app).await.map(|config| redact_sensitive_fields(&config))
+}
+
+#[tauri::command]
+pub async fn controlroom_save_config(
+    config: ControlRoomConfig,
+    state: State<'_, ControlRoomState>,
+) -> Result<(), String> {
+    state.save_config(&config).await
+}
+
+#[tauri::command]
+pub async fn controlroom_config_issues(
+    state: State<'_, ControlRoomState>,
+) -> Result<Vec<ConfigIssue>, String> {
+    Ok(state.config_issues().await)
 }
 
 #[tauri::command]
@@ -24,7 +95,7 @@ pub async fn controlroom_get_services(
     state: State<'_, ControlRoomState>,
 ) -> Result<Vec<ServiceConfig>, String> {
     let config = ensure_config(&state).await?;
-    Ok(config.services)
+    Ok(redact_sensitive_fields(&config).services)
 }
 
 #[tauri::command]
@@ -66,6 +137,62 @@ pub async fn controlroom_service_restart(
         .await
 }
 
+#[tauri::command]
+pub async fn controlroom_service_pause(
+    service_id: String,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<ServiceStatus, String> {
+    ensure_config(&state).await?;
+    state.process_manager().pause_service(&app, &service_id).await
+}
+
+#[tauri::command]
+pub async fn controlroom_service_resume(
+    service_id: String,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<ServiceStatus, String> {
+    ensure_config(&state).await?;
+    state.process_manager().resume_service(&app, &service_id).await
+}
+
+#[tauri::command]
+pub async fn controlroom_service_metrics(
+    service_id: String,
+    state: State<'_, ControlRoomState>,
+) -> Result<ServiceStatus, String> {
+    ensure_config(&state).await?;
+    state
+        .process_manager()
+        .refresh_process_metrics(&service_id)
+        .await
+        .ok_or_else(|| format!("service {service_id} is not running"))
+}
+
+#[tauri::command]
+pub async fn controlroom_service_rolling_restart(
+    tier: String,
+    concurrency: usize,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<Vec<(String, Result<ServiceStatus, String>)>, String> {
+    ensure_config(&state).await?;
+    Ok(state
+        .process_manager()
+        .rolling_restart_by_tier(&app, &tier, concurrency)
+        .await)
+}
+
+#[tauri::command]
+pub async fn controlroom_start_all_services(
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<Vec<ServiceStatus>, String> {
+    ensure_config(&state).await?;
+    state.process_manager().start_all_services(&app).await
+}
+
 #[tauri::command]
 pub async fn controlroom_service_status(
     service_id: String,
@@ -88,6 +215,23 @@ pub async fn controlroom_service_status_all(
     state.process_manager().service_status_all(&app).await
 }
 
+#[tauri::command]
+pub async fn controlroom_service_status_summary(
+    state: State<'_, ControlRoomState>,
+) -> Result<ServiceStatusSummary, String> {
+    ensure_config(&state).await?;
+    Ok(state.process_manager().service_status_summary().await)
+}
+
+#[tauri::command]
+pub async fn controlroom_service_health_history(
+    service_id: String,
+    state: State<'_, ControlRoomState>,
+) -> Result<Vec<HealthCheckResult>, String> {
+    ensure_config(&state).await?;
+    state.process_manager().get_health_history(&service_id).await
+}
+
 #[tauri::command]
 pub async fn controlroom_service_clear_logs(
     service_id: String,
@@ -110,6 +254,36 @@ pub async fn controlroom_service_logs(
         .await
 }
 
+#[tauri::command]
+pub async fn controlroom_service_log_stats(
+    service_id: String,
+    state: State<'_, ControlRoomState>,
+) -> Result<ServiceLogStats, String> {
+    ensure_config(&state).await?;
+    state.process_manager().service_log_stats(&service_id).await
+}
+
+#[tauri::command]
+pub async fn controlroom_service_log_stats_all(
+    state: State<'_, ControlRoomState>,
+) -> Result<Vec<ServiceLogStats>, String> {
+    ensure_config(&state).await?;
+    Ok(state.process_manager().service_log_stats_all().await)
+}
+
+#[tauri::command]
+pub async fn controlroom_service_logs_all(
+    filter: Option<ServiceLogFilter>,
+    limit: Option<u32>,
+    state: State<'_, ControlRoomState>,
+) -> Result<Vec<ServiceLogEvent>, String> {
+    ensure_config(&state).await?;
+    Ok(state
+        .process_manager()
+        .service_logs_all(filter, limit.map(|value| value as usize))
+        .await)
+}
+
 #[tauri::command]
 pub async fn controlroom_runner_execute(
     input: RunnerCommandInput,
@@ -123,98 +297,998 @@ pub async fn controlroom_runner_execute(
         .await
 }
 
+#[tauri::command]
+pub async fn controlroom_runner_presets(
+    state: State<'_, ControlRoomState>,
+) -> Result<Vec<RunnerPreset>, String> {
+    let config = ensure_config(&state).await?;
+    Ok(config.runner_presets)
+}
+
+#[tauri::command]
+pub async fn controlroom_runner_execute_preset(
+    preset_id: String,
+    extra_args: Option<Vec<String>>,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<RunnerStartResponse, String> {
+    let config = ensure_config(&state).await?;
+    state
+        .runner_manager()
+        .execute_preset(&app, &preset_id, extra_args, &config)
+        .await
+}
+
+#[tauri::command]
+pub async fn controlroom_runner_rerun(
+    run_id: String,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<RunnerStartResponse, String> {
+    let config = ensure_config(&state).await?;
+    state.runner_manager().rerun(&app, &run_id, &config).await
+}
+
 #[tauri::command]
 pub async fn controlroom_runner_cancel(
     run_id: String,
+    force: Option<bool>,
+    reason: Option<String>,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<bool, String> {
+    ensure_config(&state).await?;
+    state
+        .runner_manager()
+        .cancel(&app, &run_id, force.unwrap_or(false), reason)
+        .await
+}
+
+#[tauri::command]
+pub async fn controlroom_runner_cancel_all(
+    force: Option<bool>,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<Vec<String>, String> {
+    ensure_config(&state).await?;
+    Ok(state
+        .runner_manager()
+        .cancel_all(&app, force.unwrap_or(false))
+        .await)
+}
+
+#[tauri::command]
+pub async fn controlroom_runner_cancel_for_workspace(
+    workspace_id: String,
+    force: Option<bool>,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<Vec<String>, String> {
+    ensure_config(&state).await?;
+    Ok(state
+        .runner_manager()
+        .cancel_for_workspace(&app, &workspace_id, force.unwrap_or(false))
+        .await)
+}
+
+#[tauri::command]
+pub async fn controlroom_runner_write_stdin(
+    run_id: String,
+    data: String,
+    append_newline: Option<bool>,
+    state: State<'_, ControlRoomState>,
+) -> Result<bool, String> {
+    ensure_config(&state).await?;
+    state
+        .runner_manager()
+        .write_stdin(&run_id, &data, append_newline.unwrap_or(false))
+        .await?;
+    Ok(true)
+}
+
+#[tauri::command]
+pub async fn controlroom_runner_close_stdin(
+    run_id: String,
+    state: State<'_, ControlRoomState>,
+) -> Result<bool, String> {
+    ensure_config(&state).await?;
+    state.runner_manager().close_stdin(&run_id).await?;
+    Ok(true)
+}
+
+#[tauri::command]
+pub async fn controlroom_runner_resize(
+    run_id: String,
+    cols: u16,
+    rows: u16,
     state: State<'_, ControlRoomState>,
 ) -> Result<bool, String> {
     ensure_config(&state).await?;
-    state.runner_manager().cancel(&run_id).await
+    state.runner_manager().resize(&run_id, cols, rows).await?;
+    Ok(true)
+}
+
+#[tauri::command]
+pub async fn controlroom_runner_export(
+    run_id: String,
+    target_path: String,
+    format: RunnerExportFormat,
+    state: State<'_, ControlRoomState>,
+) -> Result<RunnerExportSummary, String> {
+    ensure_config(&state).await?;
+    state
+        .runner_manager()
+        .export_run(&run_id, &target_path, format)
+        .await
+}
+
+#[tauri::command]
+pub async fn controlroom_runner_list(
+    state: State<'_, ControlRoomState>,
+) -> Result<RunnerListResponse, String> {
+    ensure_config(&state).await?;
+    Ok(state.runner_manager().list_runs().await)
+}
+
+#[tauri::command]
+pub async fn controlroom_runner_history(
+    state: State<'_, ControlRoomState>,
+) -> Result<Vec<RunnerHistoryEntry>, String> {
+    ensure_config(&state).await?;
+    Ok(state.runner_manager().list_run_history().await)
+}
+
+#[tauri::command]
+pub async fn controlroom_runner_output(
+    run_id: String,
+    after_seq: Option<u64>,
+    limit: Option<u32>,
+    state: State<'_, ControlRoomState>,
+) -> Result<Vec<RunnerOutputEvent>, String> {
+    ensure_config(&state).await?;
+    state
+        .runner_manager()
+        .run_output(&run_id, after_seq, limit.map(|value| value as usize))
+        .await
 }
 
 #[tauri::command]
 pub async fn controlroom_workspace_list(
     workspace_id: String,
     relative_path: Option<String>,
+    options: Option<WorkspaceListOptions>,
     state: State<'_, ControlRoomState>,
-) -> Result<Vec<WorkspaceEntry>, String> {
+) -> Result<WorkspaceListResult, String> {
     let config = ensure_config(&state).await?;
-    list_workspace_entries(&config, &workspace_id, relative_path.as_deref().unwrap_or(""))
+    let options = options.unwrap_or(WorkspaceListOptions {
+        offset: None,
+        limit: None,
+        sort_by: None,
+        filter: None,
+        include_hidden: None,
+        extensions: None,
+        root_id: None,
+        include_hashes: None,
+    });
+    tokio::task::spawn_blocking(move || {
+        list_workspace_entries(&config, &workspace_id, relative_path.as_deref().unwrap_or(""), &options)
+    })
+    .await
+    .map_err(|e| format!("workspace list task failed: {e}"))?
 }
 
 #[tauri::command]
 pub async fn controlroom_workspace_read_file(
     workspace_id: String,
     relative_path: String,
+    root_id: Option<String>,
     state: State<'_, ControlRoomState>,
-) -> Result<String, String> {
+) -> Result<WorkspaceFileContent, String> {
     let config = ensure_config(&state).await?;
-    read_workspace_file(&config, &workspace_id, &relative_path, 512 * 1024)
+    let content = read_workspace_file(&config, &workspace_id, root_id.as_deref(), &relative_path, 512 * 1024)?;
+    state
+        .recent_files()
+        .record_open(&workspace_id, root_id.as_deref().unwrap_or(PRIMARY_WORKSPACE_ROOT_ID), &relative_path)
+        .await;
+    Ok(content)
 }
 
+/// Default `limit` for `controlroom_workspace_recent`/`controlroom_workspace_quick_open`
+/// when the frontend doesn't specify one.
+const DEFAULT_RECENT_FILES_LIMIT: u32 = 20;
+
 #[tauri::command]
-pub async fn controlroom_workspace_write_file(
+pub async fn controlroom_workspace_recent(
     workspace_id: String,
-    relative_path: String,
-    content: String,
+    limit: Option<u32>,
     state: State<'_, ControlRoomState>,
-) -> Result<bool, String> {
+) -> Result<Vec<RecentFileEntry>, String> {
     let config = ensure_config(&state).await?;
-    write_workspace_file(&config, &workspace_id, &relative_path, &content, 2 * 1024 * 1024)
+    state
+        .recent_files()
+        .recent(&config, &workspace_id, limit.unwrap_or(DEFAULT_RECENT_FILES_LIMIT) as usize)
+        .await
 }
 
 #[tauri::command]
-pub async fn controlroom_git_commits(
+pub async fn controlroom_workspace_quick_open(
     workspace_id: String,
+    query: String,
     limit: Option<u32>,
-    skip: Option<u32>,
     state: State<'_, ControlRoomState>,
-) -> Result<Vec<GitCommit>, String> {
+) -> Result<Vec<WorkspaceQuickOpenEntry>, String> {
     let config = ensure_config(&state).await?;
-    get_commits(
+    state
+        .recent_files()
+        .quick_open(&config, &workspace_id, &query, limit.unwrap_or(DEFAULT_RECENT_FILES_LIMIT) as usize)
+        .await
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_read_binary(
+    workspace_id: String,
+    relative_path: String,
+    root_id: Option<String>,
+    max_bytes: Option<u32>,
+    state: State<'_, ControlRoomState>,
+) -> Result<WorkspaceBinaryFile, String> {
+    let config = ensure_config(&state).await?;
+    read_workspace_file_binary(
         &config,
         &workspace_id,
-        limit.unwrap_or(config.git.max_commits),
-        skip.unwrap_or(0),
+        root_id.as_deref(),
+        &relative_path,
+        max_bytes.unwrap_or(10 * 1024 * 1024) as usize,
     )
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_read_range(
+    workspace_id: String,
+    relative_path: String,
+    root_id: Option<String>,
+    offset: u64,
+    length: u32,
+    state: State<'_, ControlRoomState>,
+) -> Result<WorkspaceFileRange, String> {
+    let config = ensure_config(&state).await?;
+    tokio::task::spawn_blocking(move || {
+        read_workspace_file_range(&config, &workspace_id, root_id.as_deref(), &relative_path, offset, length as usize)
+    })
     .await
+    .map_err(|e| format!("workspace read range task failed: {e}"))?
 }
 
 #[tauri::command]
-pub async fn controlroom_export_logs(
-    service_id: String,
-    target_path: String,
+pub async fn controlroom_workspace_tail(
+    workspace_id: String,
+    relative_path: String,
+    root_id: Option<String>,
+    last_n_lines: u32,
     state: State<'_, ControlRoomState>,
-) -> Result<bool, String> {
-    ensure_config(&state).await?;
-    state
-        .process_manager()
-        .export_logs(&service_id, &target_path)
-        .await
+) -> Result<WorkspaceFileRange, String> {
+    let config = ensure_config(&state).await?;
+    tokio::task::spawn_blocking(move || {
+        tail_workspace_file(&config, &workspace_id, root_id.as_deref(), &relative_path, last_n_lines as usize)
+    })
+    .await
+    .map_err(|e| format!("workspace tail task failed: {e}"))?
 }
 
 #[tauri::command]
-pub async fn controlroom_video_launch_native(
-    input: VideoLaunchNativeInput,
-    app: AppHandle,
+pub async fn controlroom_workspace_write_file(
+    workspace_id: String,
+    relative_path: String,
+    content: String,
+    root_id: Option<String>,
+    backup: Option<bool>,
+    expected_modified_ms: Option<u64>,
+    expected_hash: Option<String>,
+    force: Option<bool>,
+    encoding: Option<String>,
     state: State<'_, ControlRoomState>,
-) -> Result<VideoLaunchNativeResult, String> {
+) -> Result<WorkspaceWriteResult, String> {
     let config = ensure_config(&state).await?;
-    state
-        .video_manager()
-        .launch_native(&app, &input, &config)
-        .await
+    write_workspace_file(
+        &config,
+        &workspace_id,
+        root_id.as_deref(),
+        &relative_path,
+        &content,
+        2 * 1024 * 1024,
+        backup.unwrap_or(false),
+        expected_modified_ms,
+        expected_hash.as_deref(),
+        force.unwrap_or(false),
+        encoding.as_deref(),
+    )
 }
 
 #[tauri::command]
-pub async fn controlroom_video_snapshot_analyze(
-    input: VideoSnapshotAnalyzeInput,
-    app: AppHandle,
+pub async fn controlroom_workspace_checksum(
+    workspace_id: String,
+    relative_path: String,
+    root_id: Option<String>,
+    algorithm: WorkspaceChecksumAlgorithm,
+    max_bytes: Option<u64>,
+    operation_id: String,
     state: State<'_, ControlRoomState>,
-) -> Result<VideoSnapshotAnalyzeResult, String> {
+) -> Result<WorkspaceChecksumResult, String> {
     let config = ensure_config(&state).await?;
-    state
-        .video_manager()
+    let cancel = state.register_checksum_token(&operation_id).await;
+    let result = tokio::task::spawn_blocking(move || {
+        checksum_workspace_entry(&config, &workspace_id, root_id.as_deref(), &relative_path, algorithm, max_bytes, &cancel)
+    })
+    .await
+    .map_err(|e| format!("workspace checksum task failed: {e}"));
+    state.unregister_checksum_token(&operation_id).await;
+    result?
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_cancel_checksum(
+    operation_id: String,
+    state: State<'_, ControlRoomState>,
+) -> Result<bool, String> {
+    Ok(state.cancel_checksum(&operation_id).await)
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_create(
+    workspace_id: String,
+    relative_path: String,
+    kind: WorkspaceEntryKind,
+    root_id: Option<String>,
+    initial_content: Option<String>,
+    overwrite: Option<bool>,
+    recursive: Option<bool>,
+    state: State<'_, ControlRoomState>,
+) -> Result<WorkspaceEntry, String> {
+    let config = ensure_config(&state).await?;
+    create_workspace_entry(
+        &config,
+        &workspace_id,
+        root_id.as_deref(),
+        &relative_path,
+        kind,
+        initial_content,
+        overwrite.unwrap_or(false),
+        recursive.unwrap_or(false),
+    )
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_write_binary(
+    workspace_id: String,
+    relative_path: String,
+    base64: String,
+    root_id: Option<String>,
+    overwrite: Option<bool>,
+    state: State<'_, ControlRoomState>,
+) -> Result<WorkspaceEntry, String> {
+    let config = ensure_config(&state).await?;
+    write_workspace_file_binary(&config, &workspace_id, root_id.as_deref(), &relative_path, &base64, overwrite.unwrap_or(false))
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_import(
+    workspace_id: String,
+    source_path: String,
+    dest_relative_path: String,
+    overwrite: Option<bool>,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<WorkspaceImportResult, String> {
+    let config = ensure_config(&state).await?;
+    tokio::task::spawn_blocking(move || {
+        import_workspace_file(
+            &config,
+            &workspace_id,
+            &source_path,
+            &dest_relative_path,
+            overwrite.unwrap_or(false),
+            |bytes_done, bytes_total| {
+                emit_workspace_import_progress(
+                    &app,
+                    &WorkspaceImportProgressEvent { workspace_id: workspace_id.clone(), bytes_done, bytes_total },
+                );
+            },
+        )
+    })
+    .await
+    .map_err(|e| format!("workspace import task failed: {e}"))?
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_delete(
+    workspace_id: String,
+    relative_path: String,
+    root_id: Option<String>,
+    recursive: Option<bool>,
+    use_trash: Option<bool>,
+    state: State<'_, ControlRoomState>,
+) -> Result<bool, String> {
+    let config = ensure_config(&state).await?;
+    delete_workspace_entry(
+        &config,
+        &workspace_id,
+        root_id.as_deref(),
+        &relative_path,
+        recursive.unwrap_or(false),
+        use_trash.unwrap_or(false),
+    )
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_trash(
+    workspace_id: String,
+    relative_path: String,
+    root_id: Option<String>,
+    state: State<'_, ControlRoomState>,
+) -> Result<bool, String> {
+    let config = ensure_config(&state).await?;
+    trash_workspace_entry(&config, &workspace_id, root_id.as_deref(), &relative_path)
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_open_external(
+    workspace_id: String,
+    relative_path: String,
+    root_id: Option<String>,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<(), String> {
+    let config = ensure_config(&state).await?;
+    open_workspace_file_external(&config, &workspace_id, root_id.as_deref(), &relative_path, &app)
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_move(
+    workspace_id: String,
+    from_path: String,
+    to_path: String,
+    root_id: Option<String>,
+    overwrite: Option<bool>,
+    state: State<'_, ControlRoomState>,
+) -> Result<WorkspaceEntry, String> {
+    let config = ensure_config(&state).await?;
+    move_workspace_entry(
+        &config,
+        &workspace_id,
+        root_id.as_deref(),
+        &from_path,
+        &to_path,
+        overwrite.unwrap_or(false),
+    )
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_set_permissions(
+    workspace_id: String,
+    relative_path: String,
+    mode: u32,
+    root_id: Option<String>,
+    state: State<'_, ControlRoomState>,
+) -> Result<WorkspaceEntry, String> {
+    let config = ensure_config(&state).await?;
+    set_workspace_entry_permissions(&config, &workspace_id, root_id.as_deref(), &relative_path, mode)
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_search(
+    workspace_id: String,
+    pattern: String,
+    max_results: Option<u32>,
+    include_hidden: Option<bool>,
+    max_depth: Option<u32>,
+    exclude_dirs: Option<Vec<String>>,
+    state: State<'_, ControlRoomState>,
+) -> Result<WorkspaceSearchResult, String> {
+    let config = ensure_config(&state).await?;
+    tokio::task::spawn_blocking(move || {
+        search_workspace_names(
+            &config,
+            &workspace_id,
+            &pattern,
+            max_results.unwrap_or(200) as usize,
+            include_hidden.unwrap_or(false),
+            max_depth.map(|value| value as usize),
+            exclude_dirs.as_deref(),
+        )
+    })
+    .await
+    .map_err(|e| format!("workspace search task failed: {e}"))?
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_recent_files(
+    workspace_id: String,
+    limit: Option<u32>,
+    max_depth: Option<u32>,
+    state: State<'_, ControlRoomState>,
+) -> Result<Vec<WorkspaceEntry>, String> {
+    let config = ensure_config(&state).await?;
+    tokio::task::spawn_blocking(move || {
+        list_recently_modified_files(
+            &config,
+            &workspace_id,
+            limit.unwrap_or(50) as usize,
+            max_depth.map(|value| value as usize),
+        )
+    })
+    .await
+    .map_err(|e| format!("workspace recent files task failed: {e}"))?
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_grep(
+    workspace_id: String,
+    query: String,
+    options: Option<WorkspaceGrepOptions>,
+    state: State<'_, ControlRoomState>,
+) -> Result<WorkspaceGrepResult, String> {
+    let config = ensure_config(&state).await?;
+    let options = options.unwrap_or(WorkspaceGrepOptions {
+        regex: None,
+        case_sensitive: None,
+        include_globs: None,
+        exclude_globs: None,
+        max_matches: None,
+        max_file_size_bytes: None,
+        respect_gitignore: None,
+        timeout_ms: None,
+    });
+    tokio::task::spawn_blocking(move || grep_workspace(&config, &workspace_id, &query, &options))
+        .await
+        .map_err(|e| format!("workspace grep task failed: {e}"))?
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_tree(
+    workspace_id: String,
+    relative_path: Option<String>,
+    root_id: Option<String>,
+    max_depth: Option<u32>,
+    max_entries: Option<u32>,
+    include_hidden: Option<bool>,
+    extensions: Option<Vec<String>>,
+    state: State<'_, ControlRoomState>,
+) -> Result<WorkspaceTreeNode, String> {
+    let config = ensure_config(&state).await?;
+    tokio::task::spawn_blocking(move || {
+        build_workspace_tree(
+            &config,
+            &workspace_id,
+            root_id.as_deref(),
+            relative_path.as_deref().unwrap_or(""),
+            max_depth.unwrap_or(5) as usize,
+            max_entries.unwrap_or(500) as usize,
+            include_hidden.unwrap_or(false),
+            extensions.as_deref(),
+        )
+    })
+    .await
+    .map_err(|e| format!("workspace tree task failed: {e}"))?
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_du(
+    workspace_id: String,
+    relative_path: Option<String>,
+    max_depth: Option<u32>,
+    timeout_ms: Option<u64>,
+    state: State<'_, ControlRoomState>,
+) -> Result<WorkspaceDiskUsage, String> {
+    let config = ensure_config(&state).await?;
+    tokio::task::spawn_blocking(move || {
+        workspace_disk_usage(
+            &config,
+            &workspace_id,
+            relative_path.as_deref().unwrap_or(""),
+            max_depth.unwrap_or(5) as usize,
+            timeout_ms,
+        )
+    })
+    .await
+    .map_err(|e| format!("workspace disk usage task failed: {e}"))?
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_archive(
+    workspace_id: String,
+    relative_path: Option<String>,
+    target_path: String,
+    format: WorkspaceArchiveFormat,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<WorkspaceArchiveResult, String> {
+    let config = ensure_config(&state).await?;
+    tokio::task::spawn_blocking(move || {
+        archive_workspace_path(
+            &config,
+            &workspace_id,
+            relative_path.as_deref().unwrap_or(""),
+            &target_path,
+            format,
+            |files_done, files_total, bytes_done| {
+                emit_workspace_archive_progress(
+                    &app,
+                    &WorkspaceArchiveProgressEvent {
+                        workspace_id: workspace_id.clone(),
+                        files_done,
+                        files_total,
+                        bytes_done,
+                    },
+                );
+            },
+        )
+    })
+    .await
+    .map_err(|e| format!("workspace archive task failed: {e}"))?
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_watch(
+    workspace_id: String,
+    relative_path: String,
+    recursive: Option<bool>,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<String, String> {
+    let config = ensure_config(&state).await?;
+    state
+        .watch_manager()
+        .watch(&app, &config, &workspace_id, &relative_path, recursive.unwrap_or(false))
+        .await
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_unwatch(
+    watch_id: String,
+    state: State<'_, ControlRoomState>,
+) -> Result<bool, String> {
+    Ok(state.watch_manager().unwatch(&watch_id).await)
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_follow(
+    workspace_id: String,
+    relative_path: String,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<String, String> {
+    let config = ensure_config(&state).await?;
+    state.follow_manager().follow(&app, &config, &workspace_id, &relative_path).await
+}
+
+#[tauri::command]
+pub async fn controlroom_workspace_unfollow(
+    follow_id: String,
+    state: State<'_, ControlRoomState>,
+) -> Result<bool, String> {
+    Ok(state.follow_manager().unfollow(&follow_id).await)
+}
+
+#[tauri::command]
+pub async fn controlroom_git_commits(
+    workspace_id: String,
+    limit: Option<u32>,
+    skip: Option<u32>,
+    filter: Option<GitCommitFilter>,
+    state: State<'_, ControlRoomState>,
+) -> Result<GitCommitPage, String> {
+    let config = ensure_config(&state).await?;
+    get_commits(
+        &config,
+        &workspace_id,
+        limit.unwrap_or(config.git.max_commits),
+        skip.unwrap_or(0),
+        filter.as_ref(),
+    )
+    .await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_branches(
+    workspace_id: String,
+    state: State<'_, ControlRoomState>,
+) -> Result<Vec<GitBranch>, String> {
+    let config = ensure_config(&state).await?;
+    get_branches(&config, &workspace_id).await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_status(
+    workspace_id: String,
+    state: State<'_, ControlRoomState>,
+) -> Result<GitStatus, String> {
+    let config = ensure_config(&state).await?;
+    get_status(&config, &workspace_id).await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_commit_detail(
+    workspace_id: String,
+    hash: String,
+    diff_file_path: Option<String>,
+    state: State<'_, ControlRoomState>,
+) -> Result<GitCommitDetail, String> {
+    let config = ensure_config(&state).await?;
+    get_commit_detail(&config, &workspace_id, &hash, diff_file_path.as_deref()).await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_file_history(
+    workspace_id: String,
+    relative_path: String,
+    limit: u32,
+    skip: u32,
+    state: State<'_, ControlRoomState>,
+) -> Result<Vec<GitCommit>, String> {
+    let config = ensure_config(&state).await?;
+    get_file_history(&config, &workspace_id, &relative_path, limit, skip).await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_blame(
+    workspace_id: String,
+    relative_path: String,
+    start_line: Option<u32>,
+    end_line: Option<u32>,
+    state: State<'_, ControlRoomState>,
+) -> Result<Vec<GitBlameLine>, String> {
+    let config = ensure_config(&state).await?;
+    get_blame(&config, &workspace_id, &relative_path, start_line, end_line).await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_diff_file(
+    workspace_id: String,
+    relative_path: String,
+    staged: bool,
+    state: State<'_, ControlRoomState>,
+) -> Result<GitFileDiff, String> {
+    let config = ensure_config(&state).await?;
+    get_file_diff(&config, &workspace_id, &relative_path, staged).await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_diff_file_from_head(
+    workspace_id: String,
+    relative_path: String,
+    current_content: String,
+    state: State<'_, ControlRoomState>,
+) -> Result<GitFileDiff, String> {
+    let config = ensure_config(&state).await?;
+    diff_workspace_file_from_head(&config, &workspace_id, &relative_path, &current_content).await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_stage(
+    workspace_id: String,
+    paths: Vec<String>,
+    state: State<'_, ControlRoomState>,
+) -> Result<GitStatus, String> {
+    let config = ensure_config(&state).await?;
+    stage_paths(&config, &workspace_id, &paths).await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_unstage(
+    workspace_id: String,
+    paths: Vec<String>,
+    state: State<'_, ControlRoomState>,
+) -> Result<GitStatus, String> {
+    let config = ensure_config(&state).await?;
+    unstage_paths(&config, &workspace_id, &paths).await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_commit(
+    workspace_id: String,
+    message: String,
+    amend: bool,
+    state: State<'_, ControlRoomState>,
+) -> Result<GitCommit, String> {
+    let config = ensure_config(&state).await?;
+    commit(&config, &workspace_id, &message, amend).await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_checkout(
+    workspace_id: String,
+    git_ref: String,
+    create: bool,
+    allow_dirty: bool,
+    state: State<'_, ControlRoomState>,
+) -> Result<GitCheckoutResult, String> {
+    let config = ensure_config(&state).await?;
+    checkout(&config, &workspace_id, &git_ref, create, allow_dirty).await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_sync_state(
+    workspace_id: String,
+    state: State<'_, ControlRoomState>,
+) -> Result<GitSyncState, String> {
+    let config = ensure_config(&state).await?;
+    sync_state(&config, &workspace_id).await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_sync_state_all(
+    state: State<'_, ControlRoomState>,
+) -> Result<Vec<GitSyncState>, String> {
+    let config = ensure_config(&state).await?;
+    Ok(sync_state_all(&config).await)
+}
+
+#[tauri::command]
+pub async fn controlroom_git_tags(
+    workspace_id: String,
+    state: State<'_, ControlRoomState>,
+) -> Result<Vec<GitTag>, String> {
+    let config = ensure_config(&state).await?;
+    get_tags(&config, &workspace_id).await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_stash_list(
+    workspace_id: String,
+    state: State<'_, ControlRoomState>,
+) -> Result<Vec<GitStashEntry>, String> {
+    let config = ensure_config(&state).await?;
+    get_stash_list(&config, &workspace_id).await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_stash_push(
+    workspace_id: String,
+    message: Option<String>,
+    include_untracked: bool,
+    state: State<'_, ControlRoomState>,
+) -> Result<GitStashEntry, String> {
+    let config = ensure_config(&state).await?;
+    stash_push(&config, &workspace_id, message.as_deref(), include_untracked).await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_stash_apply(
+    workspace_id: String,
+    index: u32,
+    pop: bool,
+    state: State<'_, ControlRoomState>,
+) -> Result<GitStashApplyResult, String> {
+    let config = ensure_config(&state).await?;
+    stash_apply(&config, &workspace_id, index, pop).await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_fetch(
+    workspace_id: String,
+    remote: String,
+    branch: Option<String>,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<GitFetchResult, String> {
+    let config = ensure_config(&state).await?;
+    fetch(&config, &workspace_id, &remote, branch.as_deref(), &app).await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_pull(
+    workspace_id: String,
+    remote: String,
+    branch: String,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<GitPullResult, String> {
+    let config = ensure_config(&state).await?;
+    pull(&config, &workspace_id, &remote, &branch, &app).await
+}
+
+#[tauri::command]
+pub async fn controlroom_git_push(
+    workspace_id: String,
+    remote: String,
+    branch: String,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<GitPushResult, String> {
+    let config = ensure_config(&state).await?;
+    push(&config, &workspace_id, &remote, &branch, &app).await
+}
+
+#[tauri::command]
+pub async fn controlroom_export_logs(
+    service_id: String,
+    target_path: String,
+    state: State<'_, ControlRoomState>,
+) -> Result<bool, String> {
+    ensure_config(&state).await?;
+    state
+        .process_manager()
+        .export_logs(&service_id, &target_path)
+        .await
+}
+
+#[tauri::command]
+pub async fn controlroom_export_logs_query(
+    query: LogExportQuery,
+    target_path: String,
+    state: State<'_, ControlRoomState>,
+) -> Result<LogExportSummary, String> {
+    ensure_config(&state).await?;
+    state
+        .process_manager()
+        .export_logs_query(&query, &target_path)
+        .await
+}
+
+#[tauri::command]
+pub async fn controlroom_video_launch_native(
+    input: VideoLaunchNativeInput,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<VideoLaunchNativeResult, String> {
+    let config = ensure_config(&state).await?;
+    state
+        .video_manager()
+        .launch_native(&app, &input, &config)
+        .await
+}
+
+#[tauri::command]
+pub async fn controlroom_video_snapshot_analyze(
+    input: VideoSnapshotAnalyzeInput,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<VideoSnapshotAnalyzeResult, String> {
+    let config = ensure_config(&state).await?;
+    state
+        .video_manager()
         .snapshot_analyze(&app, &input, &config)
         .await
 }
+
+#[tauri::command]
+pub async fn controlroom_video_start_snapshot_scheduler(
+    feed_id: String,
+    app: AppHandle,
+    state: State<'_, ControlRoomState>,
+) -> Result<(), String> {
+    let config = ensure_config(&state).await?;
+    state
+        .video_manager()
+        .start_snapshot_scheduler(&app, feed_id, &config)
+        .await
+}
+
+#[tauri::command]
+pub async fn controlroom_video_stop_snapshot_scheduler(
+    feed_id: String,
+    state: State<'_, ControlRoomState>,
+) -> Result<bool, String> {
+    ensure_config(&state).await?;
+    Ok(state.video_manager().stop_snapshot_scheduler(&feed_id).await)
+}
+
+#[tauri::command]
+pub async fn controlroom_video_cleanup_snapshots(state: State<'_, ControlRoomState>) -> Result<usize, String> {
+    ensure_config(&state).await?;
+    Ok(state.video_manager().cleanup_snapshot_temp_files())
+}
+
+#[tauri::command]
+pub async fn controlroom_video_export_events(
+    target_path: String,
+    feed_id: Option<String>,
+    format: VideoExportFormat,
+    state: State<'_, ControlRoomState>,
+) -> Result<VideoExportSummary, String> {
+    ensure_config(&state).await?;
+    state
+        .video_manager()
+        .export_video_events(&target_path, feed_id, format)
+        .await
+}
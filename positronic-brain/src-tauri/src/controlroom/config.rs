@@ -1,22 +1,48 @@
-use crate::controlroom::types::ControlRoomConfig;
+use crate::controlroom::types::{
+    ConfigIssue, ControlRoomConfig, SafeCommandSpec, ServiceConfig, ServiceHealthSpec,
+};
+use crate::controlroom::video_manager::VideoManager;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
-fn resolve_config_path() -> Result<PathBuf, String> {
-    if let Ok(path) = std::env::var("CONTROLROOM_CONFIG_PATH") {
-        return Ok(PathBuf::from(path));
+/// SHA-256 of the base config file's raw bytes, used to detect edits made outside the
+/// app (e.g. by a text editor) between a load and a subsequent save.
+pub fn compute_config_checksum(bytes: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    hasher.finalize().into()
+}
+
+pub(crate) fn resolve_config_path() -> Result<PathBuf, String> {
+    let mut tried = Vec::new();
+
+    if let Ok(env_path) = std::env::var("CONTROLROOM_CONFIG_PATH") {
+        let path = PathBuf::from(&env_path);
+        // A directory means "look for controlroom.config.json inside", matching how
+        // tools like Docker Compose treat a directory config-path env var.
+        let candidate = if path.is_dir() {
+            path.join("controlroom.config.json")
+        } else {
+            path
+        };
+        if candidate.is_file() {
+            return Ok(candidate);
+        }
+        tried.push(candidate);
     }
 
     let cwd = std::env::current_dir().map_err(|e| format!("failed to read cwd: {e}"))?;
-    let mut candidates = vec![cwd.join("controlroom.config.json")];
+    tried.push(cwd.join("controlroom.config.json"));
     if let Some(parent) = cwd.parent() {
-        candidates.push(parent.join("controlroom.config.json"));
+        tried.push(parent.join("controlroom.config.json"));
     }
 
-    if let Some(found) = candidates.iter().find(|path| path.is_file()) {
+    if let Some(found) = tried.iter().find(|path| path.is_file()) {
         return Ok(found.clone());
     }
 
-    let looked_up = candidates
+    let looked_up = tried
         .iter()
         .map(|path| path.display().to_string())
         .collect::<Vec<_>>()
@@ -27,13 +53,535 @@ fn resolve_config_path() -> Result<PathBuf, String> {
     ))
 }
 
-pub fn load_controlroom_config() -> Result<ControlRoomConfig, String> {
+pub fn load_controlroom_config() -> Result<(ControlRoomConfig, Vec<ConfigIssue>, [u8; 32]), String> {
     let path = resolve_config_path()?;
     let raw = std::fs::read_to_string(&path)
         .map_err(|e| format!("failed reading {}: {e}", path.display()))?;
+    let checksum = compute_config_checksum(raw.as_bytes());
+
+    let mut value: serde_json::Value = serde_json::from_str(&raw).map_err(|e| {
+        format!(
+            "invalid controlroom config JSON at line {} column {}: {e}",
+            e.line(),
+            e.column()
+        )
+    })?;
+
+    let raw_services = value
+        .get_mut("services")
+        .map(|services| std::mem::take(services))
+        .unwrap_or(serde_json::Value::Array(Vec::new()));
+
+    if let Some(map) = value.as_object_mut() {
+        map.insert("services".to_string(), serde_json::Value::Array(Vec::new()));
+    }
+
+    let mut config: ControlRoomConfig = serde_json::from_value(value)
+        .map_err(|e| format!("invalid controlroom config JSON: {e}"))?;
+
+    let mut issues = Vec::new();
+    if let serde_json::Value::Array(entries) = raw_services {
+        for (index, entry) in entries.into_iter().enumerate() {
+            match serde_json::from_value::<ServiceConfig>(entry) {
+                Ok(service) => config.services.push(service),
+                Err(e) => issues.push(ConfigIssue {
+                    path: format!("services[{index}]"),
+                    message: e.to_string(),
+                    suggestion: None,
+                }),
+            }
+        }
+    }
+
+    if let Some(overlay_path) = resolve_overlay_path(&path) {
+        let overlay_raw = std::fs::read_to_string(&overlay_path)
+            .map_err(|e| format!("failed reading {}: {e}", overlay_path.display()))?;
+        let overlay: serde_json::Value = serde_json::from_str(&overlay_raw).map_err(|e| {
+            format!(
+                "invalid controlroom config overlay JSON at line {} column {}: {e}",
+                e.line(),
+                e.column()
+            )
+        })?;
+        config = deep_merge_config(config, overlay)?;
+    }
+
+    let workspace_ids = config
+        .workspaces
+        .iter()
+        .map(|workspace| workspace.id.clone())
+        .collect::<HashSet<_>>();
+
+    let mut valid_presets = Vec::new();
+    for (index, preset) in config.runner_presets.drain(..).enumerate() {
+        if preset.program.trim().is_empty() {
+            issues.push(ConfigIssue {
+                path: format!("runnerPresets[{index}]"),
+                message: "runner preset program cannot be empty".to_string(),
+                suggestion: None,
+            });
+        } else if preset
+            .workspace_id
+            .as_ref()
+            .is_some_and(|id| !workspace_ids.contains(id))
+        {
+            issues.push(ConfigIssue {
+                path: format!("runnerPresets[{index}]"),
+                message: format!(
+                    "runner preset references unknown workspace_id {:?}",
+                    preset.workspace_id
+                ),
+                suggestion: None,
+            });
+        } else {
+            valid_presets.push(preset);
+        }
+    }
+    config.runner_presets = valid_presets;
+
+    for workspace in &mut config.workspaces {
+        let Some(extra_paths) = &mut workspace.extra_paths else { continue };
+        let mut kept: Vec<crate::controlroom::types::WorkspaceRoot> = Vec::new();
+        for root in extra_paths.drain(..) {
+            let overlaps_primary = paths_overlap(&root.path, &workspace.path);
+            let overlaps_kept = kept.iter().any(|other| paths_overlap(&root.path, &other.path));
+            if overlaps_primary || overlaps_kept {
+                issues.push(ConfigIssue {
+                    path: format!("workspaces[{}].extraPaths[{}]", workspace.id, root.id),
+                    message: format!(
+                        "root {:?} overlaps another root in workspace {:?}; dropping it",
+                        root.path, workspace.id
+                    ),
+                    suggestion: None,
+                });
+            } else {
+                kept.push(root);
+            }
+        }
+        *extra_paths = kept;
+    }
+
+    issues.extend(validate_controlroom_config(&config));
+
+    Ok((config, issues, checksum))
+}
+
+/// Checks for problems that don't prevent the config from loading but should be
+/// surfaced to the user as warnings, e.g. via `controlroom_config_issues`. Currently
+/// just flags `WorkspaceConfig::path`s that don't exist yet (or aren't directories) so
+/// that shows up as an actionable warning instead of a confusing `read_dir` error the
+/// first time something tries to list that workspace.
+fn validate_controlroom_config(config: &ControlRoomConfig) -> Vec<ConfigIssue> {
+    let mut issues = Vec::new();
+    for workspace in &config.workspaces {
+        if !PathBuf::from(&workspace.path).is_dir() {
+            issues.push(ConfigIssue {
+                path: format!("workspaces[{}].path", workspace.id),
+                message: format!("workspace path {:?} does not exist or is not a directory", workspace.path),
+                suggestion: Some("create the directory or update the path".to_string()),
+            });
+        }
+    }
+    issues
+}
+
+/// Whether `a` and `b` are the same directory or one is nested inside the other, based on
+/// their path components (no filesystem access, so this works for paths that don't exist
+/// yet). Used to reject ambiguous overlapping workspace roots at config load time.
+fn paths_overlap(a: &str, b: &str) -> bool {
+    let a_components: Vec<_> = PathBuf::from(a).components().collect();
+    let b_components: Vec<_> = PathBuf::from(b).components().collect();
+    a_components.starts_with(&b_components) || b_components.starts_with(&a_components)
+}
+
+/// Writes `config` back to the base config file, refusing to overwrite it if
+/// `expected_checksum` (the checksum from the last successful load) doesn't match the
+/// file's current on-disk checksum, meaning something else modified it in the
+/// meantime. Returns the checksum of what was just written, for the caller to remember
+/// as the new expected checksum. `expected_checksum` of `None` skips the check
+/// (there's nothing to compare against before the first load).
+pub fn save_controlroom_config(
+    config: &ControlRoomConfig,
+    expected_checksum: Option<[u8; 32]>,
+) -> Result<[u8; 32], String> {
+    let path = resolve_config_path()?;
+
+    if let Some(expected) = expected_checksum {
+        let current_raw = std::fs::read(&path).map_err(|e| format!("failed reading {}: {e}", path.display()))?;
+        if compute_config_checksum(&current_raw) != expected {
+            return Err("config was modified externally; reload before saving".to_string());
+        }
+    }
+
+    let serialized = serde_json::to_vec_pretty(config)
+        .map_err(|e| format!("failed to serialize controlroom config: {e}"))?;
+    std::fs::write(&path, &serialized).map_err(|e| format!("failed writing {}: {e}", path.display()))?;
+
+    Ok(compute_config_checksum(&serialized))
+}
+
+/// Looks for `controlroom.config.{env}.json` next to the base config, where `env`
+/// comes from `CONTROLROOM_ENV` (defaulting to `dev`). Returns `None` when the
+/// overlay file doesn't exist, in which case the base config is used as-is.
+fn resolve_overlay_path(base_config_path: &PathBuf) -> Option<PathBuf> {
+    let env = std::env::var("CONTROLROOM_ENV").unwrap_or_else(|_| "dev".to_string());
+    let overlay_path = base_config_path
+        .parent()?
+        .join(format!("controlroom.config.{env}.json"));
+    overlay_path.is_file().then_some(overlay_path)
+}
+
+/// Deep-merges `overlay` over `base`: fields present in the overlay win, absent fields
+/// retain the base value, and `Vec` fields whose entries are all objects with an `id`
+/// (e.g. `services`, `workspaces`) are merged element-by-id rather than replaced
+/// wholesale. Any other array is replaced outright, matching plain JSON-merge semantics.
+pub fn deep_merge_config(base: ControlRoomConfig, overlay: serde_json::Value) -> Result<ControlRoomConfig, String> {
+    let mut merged =
+        serde_json::to_value(&base).map_err(|e| format!("failed to serialize base config: {e}"))?;
+    json_merge(&mut merged, overlay);
+    serde_json::from_value(merged).map_err(|e| format!("invalid merged controlroom config: {e}"))
+}
+
+fn json_merge(base: &mut serde_json::Value, overlay: serde_json::Value) {
+    match overlay {
+        serde_json::Value::Object(overlay_map) => {
+            let base_map = match base {
+                serde_json::Value::Object(map) => map,
+                other => {
+                    *other = serde_json::Value::Object(overlay_map);
+                    return;
+                }
+            };
+            for (key, overlay_value) in overlay_map {
+                match base_map.get_mut(&key) {
+                    Some(existing) => json_merge(existing, overlay_value),
+                    None => {
+                        base_map.insert(key, overlay_value);
+                    }
+                }
+            }
+        }
+        serde_json::Value::Array(overlay_items) => {
+            let merges_by_id = matches!(
+                base,
+                serde_json::Value::Array(items) if items.iter().all(|item| item.get("id").is_some())
+            ) && overlay_items.iter().all(|item| item.get("id").is_some());
+
+            if merges_by_id {
+                if let serde_json::Value::Array(base_items) = base {
+                    for overlay_item in overlay_items {
+                        let overlay_id = overlay_item.get("id").cloned();
+                        match base_items
+                            .iter_mut()
+                            .find(|item| item.get("id").cloned() == overlay_id)
+                        {
+                            Some(existing) => json_merge(existing, overlay_item),
+                            None => base_items.push(overlay_item),
+                        }
+                    }
+                }
+            } else {
+                *base = serde_json::Value::Array(overlay_items);
+            }
+        }
+        other => {
+            *base = other;
+        }
+    }
+}
+
+/// Env var keys treated as secret-bearing regardless of case, e.g. `DB_PASSWORD`,
+/// `api_key`, or `AUTH_TOKEN`.
+const SENSITIVE_ENV_KEY_MARKERS: [&str; 5] = ["PASSWORD", "SECRET", "TOKEN", "KEY", "API"];
+
+fn is_sensitive_env_key(key: &str) -> bool {
+    let upper = key.to_uppercase();
+    SENSITIVE_ENV_KEY_MARKERS.iter().any(|marker| upper.contains(marker))
+}
+
+fn redact_env(env: Option<HashMap<String, String>>) -> Option<HashMap<String, String>> {
+    env.map(|map| {
+        map.into_iter()
+            .map(|(key, value)| {
+                if is_sensitive_env_key(&key) {
+                    (key, "***".to_string())
+                } else {
+                    (key, value)
+                }
+            })
+            .collect()
+    })
+}
+
+fn redact_command_spec(spec: SafeCommandSpec) -> SafeCommandSpec {
+    SafeCommandSpec {
+        program: spec.program,
+        args: spec.args.iter().map(|arg| VideoManager::redact_url(arg)).collect(),
+        cwd: spec.cwd,
+        env: redact_env(spec.env),
+    }
+}
+
+fn redact_health_spec(spec: ServiceHealthSpec) -> ServiceHealthSpec {
+    ServiceHealthSpec {
+        http_url: spec.http_url.as_deref().map(VideoManager::redact_url),
+        http_headers: redact_env(spec.http_headers),
+        ..spec
+    }
+}
+
+/// Returns a clone of `config` with known-sensitive values replaced by `***`: URL
+/// credentials (via `VideoManager::redact_url`) in command arguments and health-check
+/// URLs, and any env var / HTTP header whose key looks like a secret. Use this for
+/// anything that leaves the backend — event payloads and Tauri command responses —
+/// never the config held in `ControlRoomState`, which services still need in full.
+pub fn redact_sensitive_fields(config: &ControlRoomConfig) -> ControlRoomConfig {
+    let mut redacted = config.clone();
+
+    for service in &mut redacted.services {
+        service.pre_start = service.pre_start.take().map(redact_command_spec);
+        service.start = redact_command_spec(service.start.clone());
+        service.stop = service.stop.take().map(redact_command_spec);
+        service.post_stop = service.post_stop.take().map(redact_command_spec);
+        service.restart = service.restart.take().map(redact_command_spec);
+        service.health = service.health.take().map(redact_health_spec);
+        service.ready_probe = service.ready_probe.take().map(redact_health_spec);
+    }
+
+    for preset in &mut redacted.runner_presets {
+        preset.args = preset.args.iter().map(|arg| VideoManager::redact_url(arg)).collect();
+        preset.env = redact_env(preset.env.take());
+    }
+
+    if let Some(video_wall) = &mut redacted.video_wall {
+        if let Some(launchers) = &mut video_wall.native_launchers {
+            for launcher in launchers {
+                launcher.command = redact_command_spec(launcher.command.clone());
+            }
+        }
+        if let Some(snapshot) = &mut video_wall.snapshot {
+            snapshot.analyzer_command = snapshot.analyzer_command.take().map(redact_command_spec);
+        }
+    }
+
+    redacted
+}
+
+fn restore_args(args: &mut [String], previous: &[String], redacted: &[String]) {
+    for ((arg, prev), red) in args.iter_mut().zip(previous.iter()).zip(redacted.iter()) {
+        if arg == red && arg != prev {
+            *arg = prev.clone();
+        }
+    }
+}
 
-    let config: ControlRoomConfig =
-        serde_json::from_str(&raw).map_err(|e| format!("invalid controlroom config JSON: {e}"))?;
+fn restore_env(env: &mut Option<HashMap<String, String>>, previous: &Option<HashMap<String, String>>) {
+    let (Some(env), Some(previous)) = (env, previous) else {
+        return;
+    };
+    for (key, value) in env.iter_mut() {
+        if value == "***" {
+            if let Some(prev_value) = previous.get(key) {
+                *value = prev_value.clone();
+            }
+        }
+    }
+}
+
+fn restore_command_spec(spec: &mut SafeCommandSpec, previous: &SafeCommandSpec, redacted: &SafeCommandSpec) {
+    restore_args(&mut spec.args, &previous.args, &redacted.args);
+    restore_env(&mut spec.env, &previous.env);
+}
+
+fn restore_optional_command_spec(
+    spec: &mut Option<SafeCommandSpec>,
+    previous: Option<&SafeCommandSpec>,
+    redacted: Option<&SafeCommandSpec>,
+) {
+    if let (Some(spec), Some(previous), Some(redacted)) = (spec, previous, redacted) {
+        restore_command_spec(spec, previous, redacted);
+    }
+}
+
+fn restore_health_spec(spec: &mut ServiceHealthSpec, previous: &ServiceHealthSpec, redacted: &ServiceHealthSpec) {
+    if spec.http_url.as_deref() == redacted.http_url.as_deref() {
+        spec.http_url = previous.http_url.clone();
+    }
+    restore_env(&mut spec.http_headers, &previous.http_headers);
+}
+
+fn restore_optional_health_spec(
+    spec: &mut Option<ServiceHealthSpec>,
+    previous: Option<&ServiceHealthSpec>,
+    redacted: Option<&ServiceHealthSpec>,
+) {
+    if let (Some(spec), Some(previous), Some(redacted)) = (spec, previous, redacted) {
+        restore_health_spec(spec, previous, redacted);
+    }
+}
 
-    Ok(config)
+/// Replaces values in `incoming` that still equal the redacted placeholder they'd have
+/// been shown as in `previous` (the raw config `ControlRoomState` last loaded) with their
+/// real values, matching services/presets/launchers by id. This is what keeps a
+/// settings-editor round trip — load the redacted config, tweak an unrelated field, save
+/// it back — from permanently clobbering real secrets on disk with the literal `"***"`
+/// (or a `VideoManager::redact_url`-obscured URL) shown by `redact_sensitive_fields`.
+/// Fields the caller actually changed no longer match the redacted form and are left
+/// untouched.
+pub fn restore_redacted_secrets(incoming: &mut ControlRoomConfig, previous: &ControlRoomConfig) {
+    let redacted_previous = redact_sensitive_fields(previous);
+
+    let previous_services: HashMap<&str, &ServiceConfig> =
+        previous.services.iter().map(|service| (service.id.as_str(), service)).collect();
+    let redacted_services: HashMap<&str, &ServiceConfig> =
+        redacted_previous.services.iter().map(|service| (service.id.as_str(), service)).collect();
+    for service in &mut incoming.services {
+        let (Some(prev), Some(redacted)) =
+            (previous_services.get(service.id.as_str()), redacted_services.get(service.id.as_str()))
+        else {
+            continue;
+        };
+        restore_command_spec(&mut service.start, &prev.start, &redacted.start);
+        restore_optional_command_spec(&mut service.pre_start, prev.pre_start.as_ref(), redacted.pre_start.as_ref());
+        restore_optional_command_spec(&mut service.stop, prev.stop.as_ref(), redacted.stop.as_ref());
+        restore_optional_command_spec(&mut service.post_stop, prev.post_stop.as_ref(), redacted.post_stop.as_ref());
+        restore_optional_command_spec(&mut service.restart, prev.restart.as_ref(), redacted.restart.as_ref());
+        restore_optional_health_spec(&mut service.health, prev.health.as_ref(), redacted.health.as_ref());
+        restore_optional_health_spec(&mut service.ready_probe, prev.ready_probe.as_ref(), redacted.ready_probe.as_ref());
+    }
+
+    let previous_presets: HashMap<&str, &crate::controlroom::types::RunnerPreset> =
+        previous.runner_presets.iter().map(|preset| (preset.id.as_str(), preset)).collect();
+    let redacted_presets: HashMap<&str, &crate::controlroom::types::RunnerPreset> =
+        redacted_previous.runner_presets.iter().map(|preset| (preset.id.as_str(), preset)).collect();
+    for preset in &mut incoming.runner_presets {
+        let (Some(prev), Some(redacted)) =
+            (previous_presets.get(preset.id.as_str()), redacted_presets.get(preset.id.as_str()))
+        else {
+            continue;
+        };
+        restore_args(&mut preset.args, &prev.args, &redacted.args);
+        restore_env(&mut preset.env, &prev.env);
+    }
+
+    if let (Some(video_wall), Some(prev_video_wall), Some(redacted_video_wall)) =
+        (&mut incoming.video_wall, &previous.video_wall, &redacted_previous.video_wall)
+    {
+        if let Some(launchers) = &mut video_wall.native_launchers {
+            let previous_launchers: HashMap<&str, &crate::controlroom::types::VideoNativeLauncherConfig> = prev_video_wall
+                .native_launchers
+                .iter()
+                .flatten()
+                .map(|launcher| (launcher.id.as_str(), launcher))
+                .collect();
+            let redacted_launchers: HashMap<&str, &crate::controlroom::types::VideoNativeLauncherConfig> = redacted_video_wall
+                .native_launchers
+                .iter()
+                .flatten()
+                .map(|launcher| (launcher.id.as_str(), launcher))
+                .collect();
+            for launcher in launchers {
+                let (Some(prev), Some(redacted)) =
+                    (previous_launchers.get(launcher.id.as_str()), redacted_launchers.get(launcher.id.as_str()))
+                else {
+                    continue;
+                };
+                restore_command_spec(&mut launcher.command, &prev.command, &redacted.command);
+            }
+        }
+
+        if let (Some(snapshot), Some(prev_snapshot), Some(redacted_snapshot)) =
+            (&mut video_wall.snapshot, &prev_video_wall.snapshot, &redacted_video_wall.snapshot)
+        {
+            restore_optional_command_spec(
+                &mut snapshot.analyzer_command,
+                prev_snapshot.analyzer_command.as_ref(),
+                redacted_snapshot.analyzer_command.as_ref(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service_with_secret(name: &str, api_key: &str, note: &str) -> ServiceConfig {
+        let mut env = HashMap::new();
+        env.insert("API_KEY".to_string(), api_key.to_string());
+        env.insert("NOTE".to_string(), note.to_string());
+
+        ServiceConfig {
+            id: "svc".to_string(),
+            name: name.to_string(),
+            tier: None,
+            tier_order: None,
+            depends_on: None,
+            cwd: None,
+            pre_start: None,
+            start: SafeCommandSpec {
+                program: "service-bin".to_string(),
+                args: vec!["--url".to_string(), "postgres://user:hunter2@db/app".to_string()],
+                cwd: None,
+                env: Some(env),
+            },
+            stop: None,
+            post_stop: None,
+            restart: None,
+            health: None,
+            ready_probe: None,
+            startup_timeout_ms: None,
+            log_sources: None,
+            inherit_env: None,
+            env_remove: None,
+            log_max_age_sec: None,
+        }
+    }
+
+    #[test]
+    fn redact_replaces_secret_env_and_url_credentials() {
+        let mut config = ControlRoomConfig::default();
+        config.services.push(service_with_secret("svc", "s3cr3t", "hello"));
+
+        let redacted = redact_sensitive_fields(&config);
+
+        let env = redacted.services[0].start.env.as_ref().unwrap();
+        assert_eq!(env.get("API_KEY").unwrap(), "***");
+        assert_eq!(env.get("NOTE").unwrap(), "hello");
+        assert!(redacted.services[0].start.args[1].contains("***:***@"));
+    }
+
+    #[test]
+    fn restore_recovers_untouched_secret_after_redacted_round_trip() {
+        let mut previous = ControlRoomConfig::default();
+        previous.services.push(service_with_secret("svc", "s3cr3t", "hello"));
+
+        // Simulate a settings-editor flow: load the redacted config, then save it back
+        // unchanged.
+        let mut incoming = redact_sensitive_fields(&previous);
+        restore_redacted_secrets(&mut incoming, &previous);
+
+        let env = incoming.services[0].start.env.as_ref().unwrap();
+        assert_eq!(env.get("API_KEY").unwrap(), "s3cr3t");
+        assert_eq!(incoming.services[0].start.args[1], "postgres://user:hunter2@db/app");
+    }
+
+    #[test]
+    fn restore_leaves_a_deliberately_edited_field_alone() {
+        let mut previous = ControlRoomConfig::default();
+        previous.services.push(service_with_secret("svc", "s3cr3t", "hello"));
+
+        let mut incoming = redact_sensitive_fields(&previous);
+        // The caller actually edited the non-secret field; it no longer matches the
+        // redacted form of `previous` and must not be clobbered by restore.
+        incoming.services[0].name = "renamed".to_string();
+        incoming.services[0].start.env.as_mut().unwrap().insert("NOTE".to_string(), "edited".to_string());
+
+        restore_redacted_secrets(&mut incoming, &previous);
+
+        assert_eq!(incoming.services[0].name, "renamed");
+        assert_eq!(incoming.services[0].start.env.as_ref().unwrap().get("NOTE").unwrap(), "edited");
+        // The untouched secret is still restored alongside the real edit.
+        assert_eq!(incoming.services[0].start.env.as_ref().unwrap().get("API_KEY").unwrap(), "s3cr3t");
+    }
 }
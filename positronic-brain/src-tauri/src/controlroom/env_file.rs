@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Parses a `.env`-style file: `KEY=VALUE` per line, `#` comments and blank lines
+/// ignored, an optional leading `export `, and surrounding quotes stripped from
+/// values. Not a full dotenv implementation (no variable expansion, no multiline
+/// values) — just enough for the common case of connection strings and API keys.
+fn parse_env_file(contents: &str) -> HashMap<String, String> {
+    let mut vars = HashMap::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let Some((key, value)) = line.split_once('=') else { continue };
+        let key = key.trim();
+        if key.is_empty() {
+            continue;
+        }
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+            .unwrap_or(value);
+        vars.insert(key.to_string(), value.to_string());
+    }
+    vars
+}
+
+/// Reads and parses a `.env` file at `dir/.env`, returning an empty map (not an
+/// error) if the file doesn't exist, so callers can load it unconditionally.
+pub fn load_env_file(dir: &Path) -> HashMap<String, String> {
+    match std::fs::read_to_string(dir.join(".env")) {
+        Ok(contents) => parse_env_file(&contents),
+        Err(_) => HashMap::new(),
+    }
+}
@@ -1,6 +1,9 @@
+use crate::controlroom::config::redact_sensitive_fields;
 use crate::controlroom::types::{
-    ControlRoomBackendError, RunnerExitEvent, RunnerOutputEvent, ServiceLogEvent, ServiceStatus,
-    VideoEventPayload,
+    ControlRoomBackendError, ControlRoomConfig, ControlRoomInitErrorEvent, ControlRoomShutdownEvent, GitProgressEvent,
+    RunnerExitEvent, RunnerOutputBatchEvent, RunnerOutputEvent, ServiceLogEvent, ServiceStatus,
+    VideoEventPayload, VideoSnapshotRequestEvent, WorkspaceArchiveProgressEvent, WorkspaceChangeEvent,
+    WorkspaceFileDataEvent, WorkspaceFollowStatusEvent, WorkspaceImportProgressEvent,
 };
 use tauri::{AppHandle, Emitter};
 
@@ -16,6 +19,10 @@ pub fn emit_runner_output(app: &AppHandle, event: &RunnerOutputEvent) {
     let _ = app.emit("controlroom://runner-output", event);
 }
 
+pub fn emit_runner_output_batch(app: &AppHandle, event: &RunnerOutputBatchEvent) {
+    let _ = app.emit("controlroom://runner-output-batch", event);
+}
+
 pub fn emit_runner_exit(app: &AppHandle, event: &RunnerExitEvent) {
     let _ = app.emit("controlroom://runner-exit", event);
 }
@@ -24,11 +31,63 @@ pub fn emit_video_event(app: &AppHandle, event: &VideoEventPayload) {
     let _ = app.emit("controlroom://video-event", event);
 }
 
-pub fn emit_backend_error(app: &AppHandle, scope: &str, message: impl ToString) {
+pub fn emit_snapshot_request(app: &AppHandle, event: &VideoSnapshotRequestEvent) {
+    let _ = app.emit("controlroom://snapshot-request", event);
+}
+
+pub fn emit_config_reloaded(app: &AppHandle, config: &ControlRoomConfig) {
+    let _ = app.emit("controlroom://config-reloaded", redact_sensitive_fields(config));
+}
+
+pub fn emit_state_ready(app: &AppHandle, config: &ControlRoomConfig) {
+    let _ = app.emit("controlroom://state-ready", redact_sensitive_fields(config));
+}
+
+pub fn emit_state_error(app: &AppHandle, message: &str) {
+    let _ = app.emit(
+        "controlroom://state-error",
+        ControlRoomInitErrorEvent { message: message.to_string() },
+    );
+}
+
+pub fn emit_shutdown(app: &AppHandle, event: &ControlRoomShutdownEvent) {
+    let _ = app.emit("controlroom://shutdown", event);
+}
+
+pub fn emit_workspace_changed(app: &AppHandle, event: &WorkspaceChangeEvent) {
+    let _ = app.emit("controlroom://workspace-changed", event);
+}
+
+pub fn emit_workspace_archive_progress(app: &AppHandle, event: &WorkspaceArchiveProgressEvent) {
+    let _ = app.emit("controlroom://workspace-archive-progress", event);
+}
+
+pub fn emit_workspace_import_progress(app: &AppHandle, event: &WorkspaceImportProgressEvent) {
+    let _ = app.emit("controlroom://workspace-import-progress", event);
+}
+
+pub fn emit_workspace_file_data(app: &AppHandle, event: &WorkspaceFileDataEvent) {
+    let _ = app.emit("controlroom://workspace-file-data", event);
+}
+
+pub fn emit_workspace_follow_status(app: &AppHandle, event: &WorkspaceFollowStatusEvent) {
+    let _ = app.emit("controlroom://workspace-follow-status", event);
+}
+
+pub fn emit_git_progress(app: &AppHandle, event: &GitProgressEvent) {
+    let _ = app.emit("controlroom://git-progress", event);
+}
+
+pub fn emit_backend_error(
+    app: &AppHandle,
+    scope: &str,
+    message: impl ToString,
+    correlation_id: Option<String>,
+) {
     let payload = ControlRoomBackendError {
         scope: scope.to_string(),
         message: message.to_string(),
-        correlation_id: None,
+        correlation_id,
     };
     let _ = app.emit("controlroom://backend-error", payload);
 }
@@ -0,0 +1,281 @@
+use crate::controlroom::events::{emit_workspace_file_data, emit_workspace_follow_status};
+use crate::controlroom::types::{
+    ControlRoomConfig, WorkspaceFileDataEvent, WorkspaceFollowStatus, WorkspaceFollowStatusEvent,
+};
+use crate::controlroom::workspace::secure_target_path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use tauri::AppHandle;
+use tokio::sync::Mutex;
+use tokio::time::{sleep, Duration};
+use tokio_util::sync::CancellationToken;
+
+/// Bound on live follows per workspace, mirroring `WatchManager`'s
+/// `MAX_WATCHES_PER_WORKSPACE` so a runaway frontend can't leak unbounded background
+/// tasks.
+const MAX_FOLLOWS_PER_WORKSPACE: usize = 20;
+/// How often the follower checks the file for new bytes.
+const POLL_INTERVAL_MS: u64 = 500;
+/// A batch is flushed once it holds this many lines, so a single huge write doesn't
+/// balloon one event; the rest is picked up on the next poll.
+const BATCH_MAX_LINES: usize = 200;
+/// Upper bound on bytes read from the file in a single poll tick, matching the 1 MiB
+/// streaming-copy chunk sizes used in `workspace.rs`.
+const MAX_BYTES_PER_POLL: u64 = 1024 * 1024;
+
+struct FollowEntry {
+    workspace_id: String,
+    cancel_token: CancellationToken,
+}
+
+/// Streams appended lines from a workspace file as it grows ("tail -f"), emitting
+/// `controlroom://workspace-file-data` batches instead of one event per line. Each
+/// follow runs its own polling task and terminates itself (emitting a final
+/// `controlroom://workspace-follow-status`) if the file disappears or hits an I/O error.
+pub struct FollowManager {
+    follows: Mutex<HashMap<String, FollowEntry>>,
+    next_id: AtomicU64,
+}
+
+impl std::fmt::Debug for FollowManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FollowManager").finish()
+    }
+}
+
+impl FollowManager {
+    pub fn new() -> Self {
+        Self {
+            follows: Mutex::new(HashMap::new()),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    fn next_follow_id(&self) -> String {
+        let seq = self.next_id.fetch_add(1, Ordering::SeqCst);
+        format!("follow-{seq}")
+    }
+
+    pub async fn follow(
+        &self,
+        app: &AppHandle,
+        config: &ControlRoomConfig,
+        workspace_id: &str,
+        rel_or_abs: &str,
+    ) -> Result<String, String> {
+        let base = config
+            .workspaces
+            .iter()
+            .find(|workspace| workspace.id == workspace_id)
+            .map(|workspace| PathBuf::from(&workspace.path))
+            .ok_or_else(|| format!("workspace not found: {workspace_id}"))?;
+        let target = secure_target_path(&base, rel_or_abs)?;
+        let canonical_base = base
+            .canonicalize()
+            .map_err(|e| format!("workspace canonicalize failed: {e}"))?;
+        let relative_path = target
+            .strip_prefix(&canonical_base)
+            .ok()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|| rel_or_abs.to_string());
+
+        let meta = std::fs::metadata(&target)
+            .map_err(|e| format!("metadata failed for {}: {e}", target.display()))?;
+        if !meta.is_file() {
+            return Err(format!("not a file: {}", target.display()));
+        }
+
+        {
+            let follows = self.follows.lock().await;
+            let existing = follows.values().filter(|entry| entry.workspace_id == workspace_id).count();
+            if existing >= MAX_FOLLOWS_PER_WORKSPACE {
+                return Err(format!(
+                    "workspace {workspace_id} already has the maximum of {MAX_FOLLOWS_PER_WORKSPACE} follows"
+                ));
+            }
+        }
+
+        let follow_id = self.next_follow_id();
+        let token = CancellationToken::new();
+
+        spawn_follow_task(
+            app.clone(),
+            follow_id.clone(),
+            workspace_id.to_string(),
+            relative_path,
+            target,
+            meta.len(),
+            token.clone(),
+        );
+
+        let mut follows = self.follows.lock().await;
+        follows.insert(
+            follow_id.clone(),
+            FollowEntry { workspace_id: workspace_id.to_string(), cancel_token: token },
+        );
+        Ok(follow_id)
+    }
+
+    /// Stops the follow registered under `follow_id` and removes its entry. Returns
+    /// whether one was found. The task does not remove its own entry when it exits, so
+    /// a follow that already stopped on its own (file deleted, I/O error) stays
+    /// registered, and counts toward `MAX_FOLLOWS_PER_WORKSPACE`, until it is
+    /// unfollowed here, which then also returns `true`.
+    pub async fn unfollow(&self, follow_id: &str) -> bool {
+        let mut follows = self.follows.lock().await;
+        match follows.remove(follow_id) {
+            Some(entry) => {
+                entry.cancel_token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Drops every follow registered for `workspace_id`, e.g. when a config reload
+    /// removes that workspace.
+    pub async fn stop_workspace_follows(&self, workspace_id: &str) {
+        let mut follows = self.follows.lock().await;
+        follows.retain(|_, entry| {
+            let keep = entry.workspace_id != workspace_id;
+            if !keep {
+                entry.cancel_token.cancel();
+            }
+            keep
+        });
+    }
+}
+
+fn spawn_follow_task(
+    app: AppHandle,
+    follow_id: String,
+    workspace_id: String,
+    relative_path: String,
+    target: PathBuf,
+    start_position: u64,
+    token: CancellationToken,
+) {
+    tokio::spawn(async move {
+        let mut position = start_position;
+        let (status, message) =
+            run_follow_loop(&app, &follow_id, &workspace_id, &relative_path, &target, &mut position, &token).await;
+        emit_workspace_follow_status(
+            &app,
+            &WorkspaceFollowStatusEvent {
+                follow_id,
+                workspace_id,
+                path: relative_path,
+                status,
+                message,
+            },
+        );
+    });
+}
+
+async fn run_follow_loop(
+    app: &AppHandle,
+    follow_id: &str,
+    workspace_id: &str,
+    relative_path: &str,
+    target: &Path,
+    position: &mut u64,
+    token: &CancellationToken,
+) -> (WorkspaceFollowStatus, Option<String>) {
+    loop {
+        tokio::select! {
+            _ = token.cancelled() => return (WorkspaceFollowStatus::Stopped, None),
+            _ = sleep(Duration::from_millis(POLL_INTERVAL_MS)) => {}
+        }
+
+        let meta = match std::fs::metadata(target) {
+            Ok(meta) => meta,
+            Err(_) => return (WorkspaceFollowStatus::Deleted, Some("file no longer exists".to_string())),
+        };
+
+        // Truncation or rotation (e.g. logrotate): the file is now shorter than our
+        // last known position. Start over from the beginning of the new file.
+        if meta.len() < *position {
+            *position = 0;
+        }
+        if meta.len() == *position {
+            continue;
+        }
+
+        let end = meta.len().min(*position + MAX_BYTES_PER_POLL);
+        match read_new_lines(target, *position, end) {
+            Ok((lines, new_position)) => {
+                *position = new_position;
+                if !lines.is_empty() {
+                    emit_workspace_file_data(
+                        app,
+                        &WorkspaceFileDataEvent {
+                            follow_id: follow_id.to_string(),
+                            workspace_id: workspace_id.to_string(),
+                            path: relative_path.to_string(),
+                            lines,
+                        },
+                    );
+                }
+            }
+            Err(error) => return (WorkspaceFollowStatus::Error, Some(error)),
+        }
+    }
+}
+
+/// Reads the bytes appended to `target` between `position` and `end` and splits them
+/// into complete lines, capped at `BATCH_MAX_LINES`. Returns the new position, which
+/// only advances past bytes that ended in a newline — a trailing partial line is left
+/// on disk for the next poll to pick up once it's complete.
+fn read_new_lines(target: &Path, position: u64, end: u64) -> Result<(Vec<String>, u64), String> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut file =
+        std::fs::File::open(target).map_err(|e| format!("open failed for {}: {e}", target.display()))?;
+    file.seek(SeekFrom::Start(position))
+        .map_err(|e| format!("seek failed for {}: {e}", target.display()))?;
+
+    let mut buf = vec![0u8; (end - position) as usize];
+    file.read_exact(&mut buf).map_err(|e| format!("read failed for {}: {e}", target.display()))?;
+
+    let mut lines = Vec::new();
+    let mut consumed = 0usize;
+    let mut line_start = 0usize;
+    for (i, &byte) in buf.iter().enumerate() {
+        if byte == b'\n' {
+            let line = String::from_utf8_lossy(&buf[line_start..i]).trim_end_matches('\r').to_string();
+            lines.push(line);
+            line_start = i + 1;
+            consumed = line_start;
+            if lines.len() >= BATCH_MAX_LINES {
+                break;
+            }
+        }
+    }
+
+    Ok((lines, position + consumed as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_new_lines_leaves_trailing_partial_line_unconsumed() {
+        let dir = std::env::temp_dir().join("controlroom-follow-test-partial");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("log.txt");
+        std::fs::write(&path, b"first\nsecond\npartial").unwrap();
+
+        let (lines, position) = read_new_lines(&path, 0, 20).unwrap();
+        assert_eq!(lines, vec!["first".to_string(), "second".to_string()]);
+        assert_eq!(position, 13);
+
+        std::fs::write(&path, b"first\nsecond\npartial-done\n").unwrap();
+        let (lines, position) = read_new_lines(&path, position, 26).unwrap();
+        assert_eq!(lines, vec!["partial-done".to_string()]);
+        assert_eq!(position, 26);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -1,47 +1,139 @@
-use crate::controlroom::types::{ControlRoomConfig, GitCommit};
+use crate::controlroom::events::emit_git_progress;
+use crate::controlroom::types::{
+    ControlRoomConfig, GitBlameLine, GitBranch, GitCheckoutResult, GitCommit, GitCommitDetail, GitCommitFileChange,
+    GitCommitFilter, GitCommitPage, GitFetchResult, GitFileDiff, GitProgressEvent, GitPullResult, GitPushResult,
+    GitStashApplyResult, GitStashEntry, GitStatus, GitSyncState, GitTag,
+};
+use crate::controlroom::workspace::secure_target_path;
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Stdio;
+use tauri::AppHandle;
+use tokio::io::AsyncReadExt;
 
-pub async fn get_commits(
-    config: &ControlRoomConfig,
-    workspace_id: &str,
-    limit: u32,
-    skip: u32,
-) -> Result<Vec<GitCommit>, String> {
-    let workspace = config
+const MAX_GIT_DIFF_BYTES: usize = 512 * 1024;
+
+fn find_workspace_path(config: &ControlRoomConfig, workspace_id: &str) -> Result<String, String> {
+    config
         .workspaces
         .iter()
         .find(|workspace| workspace.id == workspace_id)
-        .ok_or_else(|| format!("workspace not found: {workspace_id}"))?;
+        .map(|workspace| workspace.path.clone())
+        .ok_or_else(|| format!("workspace not found: {workspace_id}"))
+}
 
-    let check_output = tokio::process::Command::new("git")
+async fn is_git_repo(workspace_path: &str) -> bool {
+    tokio::process::Command::new("git")
         .arg("-C")
-        .arg(&workspace.path)
+        .arg(workspace_path)
         .arg("rev-parse")
         .arg("--is-inside-work-tree")
         .output()
         .await
-        .map_err(|e| format!("git check failed: {e}"))?;
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
 
-    if !check_output.status.success() {
-        return Ok(Vec::new());
+async fn is_path_tracked(workspace_path: &str, target: &std::path::Path) -> bool {
+    tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(workspace_path)
+        .arg("ls-files")
+        .arg("--error-unmatch")
+        .arg(target)
+        .output()
+        .await
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+pub async fn get_commits(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    limit: u32,
+    skip: u32,
+    filter: Option<&GitCommitFilter>,
+) -> Result<GitCommitPage, String> {
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Ok(GitCommitPage { commits: Vec::new(), has_more: false });
     }
 
-    let output = tokio::process::Command::new("git")
-        .arg("-C")
-        .arg(&workspace.path)
+    let mut cmd = tokio::process::Command::new("git");
+    cmd.arg("-C")
+        .arg(&workspace_path)
         .arg("log")
         .arg(format!("--skip={skip}"))
-        .arg(format!("-n{limit}"))
+        // Request one extra commit so a full page tells us whether more exist, without
+        // a second git invocation just to check.
+        .arg(format!("-n{}", limit as u64 + 1))
         .arg("--date=iso-strict")
-        .arg("--pretty=format:%H%x1f%h%x1f%an%x1f%ad%x1f%s")
-        .output()
-        .await
-        .map_err(|e| format!("git log failed: {e}"))?;
+        .arg("--pretty=format:%H%x1f%h%x1f%an%x1f%ad%x1f%s");
+
+    if let Some(filter) = filter {
+        if let Some(author) = &filter.author {
+            cmd.arg(format!("--author={author}"));
+        }
+        if let Some(since) = &filter.since {
+            validate_git_date(since)?;
+            cmd.arg(format!("--since={since}"));
+        }
+        if let Some(until) = &filter.until {
+            validate_git_date(until)?;
+            cmd.arg(format!("--until={until}"));
+        }
+        if let Some(grep) = &filter.grep {
+            cmd.arg(format!("--grep={grep}"));
+        }
+        if let Some(path) = &filter.path {
+            let target = secure_target_path(Path::new(&workspace_path), path)?;
+            cmd.arg("--").arg(target);
+        }
+    }
+
+    let output = cmd.output().await.map_err(|e| format!("git log failed: {e}"))?;
 
     if !output.status.success() {
-        return Ok(Vec::new());
+        return Ok(GitCommitPage { commits: Vec::new(), has_more: false });
     }
 
     let text = String::from_utf8_lossy(&output.stdout);
+    let mut commits = parse_git_log(&text);
+    let has_more = commits.len() > limit as usize;
+    commits.truncate(limit as usize);
+    Ok(GitCommitPage { commits, has_more })
+}
+
+/// Rejects date strings `git log --since`/`--until` couldn't sensibly resolve, before
+/// they're ever passed to git. Accepts `git`'s own relative phrases ("2 weeks ago",
+/// "yesterday", "now") and ISO-8601-ish absolute dates; anything else is likely a typo
+/// that git would otherwise silently swallow (an unparseable approxidate is treated by
+/// git as "no bound" rather than an error).
+fn validate_git_date(value: &str) -> Result<(), String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return Err("date filter cannot be empty".to_string());
+    }
+    if matches!(trimmed.to_lowercase().as_str(), "now" | "today" | "yesterday") {
+        return Ok(());
+    }
+
+    let relative = regex::Regex::new(r"(?i)^\d+\s+(second|minute|hour|day|week|month|year)s?\s+ago$").unwrap();
+    if relative.is_match(trimmed) {
+        return Ok(());
+    }
+
+    let iso = regex::Regex::new(r"^\d{4}-\d{2}-\d{2}([ T]\d{2}:\d{2}(:\d{2})?([+-]\d{2}:?\d{2}|Z)?)?$").unwrap();
+    if iso.is_match(trimmed) {
+        return Ok(());
+    }
+
+    Err(format!(
+        "invalid date filter '{trimmed}'; use an ISO date (2024-01-31) or a relative phrase (\"2 weeks ago\")"
+    ))
+}
+
+fn parse_git_log(text: &str) -> Vec<GitCommit> {
     let mut commits = Vec::new();
 
     for line in text.lines() {
@@ -63,5 +155,1766 @@ pub async fn get_commits(
         });
     }
 
-    Ok(commits)
+    commits
+}
+
+/// Local branches with upstream tracking info, most useful ones (current branch first)
+/// not guaranteed — callers that care about order should sort client-side.
+pub async fn get_branches(config: &ControlRoomConfig, workspace_id: &str) -> Result<Vec<GitBranch>, String> {
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Ok(Vec::new());
+    }
+
+    let output = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(&workspace_path)
+        .arg("for-each-ref")
+        .arg("refs/heads/")
+        .arg("--format=%(refname:short)%09%(HEAD)%09%(upstream:short)%09%(upstream:track)%09%(objectname)")
+        .output()
+        .await
+        .map_err(|e| format!("git for-each-ref failed: {e}"))?;
+
+    if !output.status.success() {
+        return Ok(Vec::new());
+    }
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    Ok(parse_branch_list(&text))
+}
+
+fn parse_branch_list(text: &str) -> Vec<GitBranch> {
+    let mut branches = Vec::new();
+
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.split('\t').collect();
+        if parts.len() != 5 {
+            continue;
+        }
+
+        let upstream = if parts[2].is_empty() { None } else { Some(parts[2].to_string()) };
+        let (ahead, behind) = parse_upstream_track(parts[3]);
+
+        branches.push(GitBranch {
+            name: parts[0].to_string(),
+            is_current: parts[1] == "*",
+            upstream,
+            ahead,
+            behind,
+            last_commit_hash: parts[4].to_string(),
+        });
+    }
+
+    branches
+}
+
+/// Parses `%(upstream:track)` output like `[ahead 2, behind 1]`, `[ahead 2]`,
+/// `[behind 1]`, `[gone]`, or an empty string (up to date / no upstream) into
+/// `(ahead, behind)` counts.
+fn parse_upstream_track(track: &str) -> (u32, u32) {
+    let mut ahead = 0;
+    let mut behind = 0;
+    let trimmed = track.trim_start_matches('[').trim_end_matches(']');
+    for part in trimmed.split(", ") {
+        let mut words = part.split_whitespace();
+        match (words.next(), words.next()) {
+            (Some("ahead"), Some(n)) => ahead = n.parse().unwrap_or(0),
+            (Some("behind"), Some(n)) => behind = n.parse().unwrap_or(0),
+            _ => {}
+        }
+    }
+    (ahead, behind)
+}
+
+/// Current branch, detached-HEAD state, and staged/unstaged/untracked counts, parsed
+/// from `git status --porcelain=v2 --branch` rather than the human-readable output.
+pub async fn get_status(config: &ControlRoomConfig, workspace_id: &str) -> Result<GitStatus, String> {
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Ok(GitStatus { branch: None, detached: false, staged: 0, unstaged: 0, untracked: 0 });
+    }
+
+    let output = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(&workspace_path)
+        .arg("status")
+        .arg("--porcelain=v2")
+        .arg("--branch")
+        .output()
+        .await
+        .map_err(|e| format!("git status failed: {e}"))?;
+
+    if !output.status.success() {
+        return Ok(GitStatus { branch: None, detached: false, staged: 0, unstaged: 0, untracked: 0 });
+    }
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    Ok(parse_status_porcelain_v2(&text))
+}
+
+fn parse_status_porcelain_v2(text: &str) -> GitStatus {
+    let mut branch = None;
+    let mut detached = false;
+    let mut staged = 0;
+    let mut unstaged = 0;
+    let mut untracked = 0;
+
+    for line in text.lines() {
+        if let Some(head) = line.strip_prefix("# branch.head ") {
+            if head == "(detached)" {
+                detached = true;
+            } else {
+                branch = Some(head.to_string());
+            }
+            continue;
+        }
+
+        match line.as_bytes().first() {
+            Some(b'1') | Some(b'2') => {
+                let mut fields = line.split_whitespace();
+                fields.next();
+                if let Some(xy) = fields.next() {
+                    let mut chars = xy.chars();
+                    let x = chars.next().unwrap_or('.');
+                    let y = chars.next().unwrap_or('.');
+                    if x != '.' {
+                        staged += 1;
+                    }
+                    if y != '.' {
+                        unstaged += 1;
+                    }
+                }
+            }
+            Some(b'u') => unstaged += 1,
+            Some(b'?') => untracked += 1,
+            _ => {}
+        }
+    }
+
+    GitStatus { branch, detached, staged, unstaged, untracked }
+}
+
+/// Ahead/behind counts and remote-tracking info for the current branch. A repo with no
+/// commits, a detached HEAD, or a branch with no upstream all resolve to
+/// `NotApplicable` rather than an error, since none of those are failures — there's
+/// simply nothing to report a sync state for.
+pub async fn sync_state(config: &ControlRoomConfig, workspace_id: &str) -> Result<GitSyncState, String> {
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Ok(GitSyncState::NotApplicable {
+            workspace_id: workspace_id.to_string(),
+            reason: "not a git repository".to_string(),
+        });
+    }
+
+    let branch = abbrev_rev_parse(&workspace_path, "HEAD").await.unwrap_or_default();
+    if branch.is_empty() || branch == "HEAD" {
+        return Ok(GitSyncState::NotApplicable {
+            workspace_id: workspace_id.to_string(),
+            reason: "HEAD is detached".to_string(),
+        });
+    }
+
+    let upstream = match abbrev_rev_parse(&workspace_path, &format!("{branch}@{{upstream}}")).await {
+        Ok(upstream) if !upstream.is_empty() => upstream,
+        _ => {
+            return Ok(GitSyncState::NotApplicable {
+                workspace_id: workspace_id.to_string(),
+                reason: "branch has no upstream".to_string(),
+            });
+        }
+    };
+
+    let counts_output = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(&workspace_path)
+        .arg("rev-list")
+        .arg("--left-right")
+        .arg("--count")
+        .arg(format!("{branch}...{upstream}"))
+        .output()
+        .await
+        .map_err(|e| format!("git rev-list failed: {e}"))?;
+    let (ahead, behind) = if counts_output.status.success() {
+        parse_left_right_count(&String::from_utf8_lossy(&counts_output.stdout))
+    } else {
+        (0, 0)
+    };
+
+    let dirty = !dirty_files(&workspace_path).await?.is_empty();
+    let last_fetch_ms = std::fs::metadata(Path::new(&workspace_path).join(".git").join("FETCH_HEAD"))
+        .ok()
+        .and_then(|meta| meta.modified().ok())
+        .and_then(|modified| modified.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as u64);
+
+    Ok(GitSyncState::Tracking {
+        workspace_id: workspace_id.to_string(),
+        branch,
+        upstream,
+        ahead,
+        behind,
+        dirty,
+        last_fetch_ms,
+    })
+}
+
+/// Runs `sync_state` for every configured workspace concurrently.
+pub async fn sync_state_all(config: &ControlRoomConfig) -> Vec<GitSyncState> {
+    let pending = config.workspaces.iter().map(|workspace| sync_state(config, &workspace.id));
+    futures::future::join_all(pending)
+        .await
+        .into_iter()
+        .zip(config.workspaces.iter())
+        .map(|(result, workspace)| {
+            result.unwrap_or_else(|error| GitSyncState::NotApplicable { workspace_id: workspace.id.clone(), reason: error })
+        })
+        .collect()
+}
+
+async fn abbrev_rev_parse(workspace_path: &str, rev: &str) -> Result<String, String> {
+    let output = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(workspace_path)
+        .arg("rev-parse")
+        .arg("--abbrev-ref")
+        .arg(rev)
+        .output()
+        .await
+        .map_err(|e| format!("git rev-parse failed: {e}"))?;
+    if !output.status.success() {
+        return Err(format!("git rev-parse failed: {}", String::from_utf8_lossy(&output.stderr)));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Parses `git rev-list --left-right --count a...b` output, `"<ahead>\t<behind>"`.
+fn parse_left_right_count(text: &str) -> (u32, u32) {
+    let mut parts = text.trim().split_whitespace();
+    let ahead = parts.next().and_then(|n| n.parse().ok()).unwrap_or(0);
+    let behind = parts.next().and_then(|n| n.parse().ok()).unwrap_or(0);
+    (ahead, behind)
+}
+
+/// History of a single file, following renames, scoped with `-- <path>`.
+pub async fn get_file_history(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    relative_path: &str,
+    limit: u32,
+    skip: u32,
+) -> Result<Vec<GitCommit>, String> {
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Ok(Vec::new());
+    }
+    let target = secure_target_path(Path::new(&workspace_path), relative_path)?;
+
+    let output = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(&workspace_path)
+        .arg("log")
+        .arg("--follow")
+        .arg(format!("--skip={skip}"))
+        .arg(format!("-n{limit}"))
+        .arg("--date=iso-strict")
+        .arg("--pretty=format:%H%x1f%h%x1f%an%x1f%ad%x1f%s")
+        .arg("--")
+        .arg(&target)
+        .output()
+        .await
+        .map_err(|e| format!("git log failed: {e}"))?;
+
+    if !output.status.success() {
+        return Ok(Vec::new());
+    }
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    Ok(parse_git_log(&text))
+}
+
+/// Line-level blame for a tracked file, optionally scoped to `[start_line, end_line]`.
+/// Untracked files produce a distinct "not tracked" error rather than an empty result.
+pub async fn get_blame(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    relative_path: &str,
+    start_line: Option<u32>,
+    end_line: Option<u32>,
+) -> Result<Vec<GitBlameLine>, String> {
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Err(format!("workspace {workspace_id} is not a git repository"));
+    }
+    let target = secure_target_path(Path::new(&workspace_path), relative_path)?;
+
+    if !is_path_tracked(&workspace_path, &target).await {
+        return Err(format!("{relative_path} is not tracked by git"));
+    }
+
+    let mut cmd = tokio::process::Command::new("git");
+    cmd.arg("-C").arg(&workspace_path).arg("blame").arg("--porcelain");
+    if let (Some(start), Some(end)) = (start_line, end_line) {
+        cmd.arg("-L").arg(format!("{start},{end}"));
+    }
+    cmd.arg("--").arg(&target);
+
+    let output = cmd.output().await.map_err(|e| format!("git blame failed: {e}"))?;
+    if !output.status.success() {
+        return Err(format!("git blame failed: {}", String::from_utf8_lossy(&output.stderr)));
+    }
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    Ok(parse_blame_porcelain(&text))
+}
+
+#[derive(Default, Clone)]
+struct BlameCommitInfo {
+    author: String,
+    author_time: String,
+    author_tz: String,
+    summary: String,
+}
+
+fn parse_blame_porcelain(text: &str) -> Vec<GitBlameLine> {
+    let mut entries = Vec::new();
+    let mut commit_info: HashMap<String, BlameCommitInfo> = HashMap::new();
+    let mut current_hash = String::new();
+    let mut current_final_line: u32 = 0;
+
+    for line in text.lines() {
+        if let Some(content) = line.strip_prefix('\t') {
+            let info = commit_info.get(&current_hash).cloned().unwrap_or_default();
+            entries.push(GitBlameLine {
+                line_number: current_final_line,
+                hash: current_hash.clone(),
+                author: info.author,
+                date: format!("{} {}", info.author_time, info.author_tz).trim().to_string(),
+                summary: info.summary,
+                line_text: content.to_string(),
+            });
+            continue;
+        }
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut fields = line.split(' ');
+        let first = fields.next().unwrap_or("");
+        if first.len() == 40 && first.chars().all(|c| c.is_ascii_hexdigit()) {
+            current_hash = first.to_string();
+            if let Some(final_line) = fields.nth(1) {
+                current_final_line = final_line.parse().unwrap_or(current_final_line);
+            }
+            commit_info.entry(current_hash.clone()).or_default();
+            continue;
+        }
+
+        let entry = commit_info.entry(current_hash.clone()).or_default();
+        if let Some(rest) = line.strip_prefix("author ") {
+            entry.author = rest.to_string();
+        } else if let Some(rest) = line.strip_prefix("author-time ") {
+            entry.author_time = rest.to_string();
+        } else if let Some(rest) = line.strip_prefix("author-tz ") {
+            entry.author_tz = rest.to_string();
+        } else if let Some(rest) = line.strip_prefix("summary ") {
+            entry.summary = rest.to_string();
+        }
+    }
+
+    entries
+}
+
+/// Unified diff of one file: the working tree against the index, or, when `staged`,
+/// the index against HEAD.
+/// Untracked files are diffed with `--no-index` against `/dev/null` so the whole
+/// content shows as added, matching how a first-time `git add` would present it.
+pub async fn get_file_diff(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    relative_path: &str,
+    staged: bool,
+) -> Result<GitFileDiff, String> {
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Err(format!("workspace {workspace_id} is not a git repository"));
+    }
+    let target = secure_target_path(Path::new(&workspace_path), relative_path)?;
+
+    let untracked = !staged && !is_path_tracked(&workspace_path, &target).await;
+
+    let output = if untracked {
+        tokio::process::Command::new("git")
+            .arg("-C")
+            .arg(&workspace_path)
+            .arg("diff")
+            .arg("--no-index")
+            .arg("--")
+            .arg("/dev/null")
+            .arg(&target)
+            .output()
+            .await
+            .map_err(|e| format!("git diff failed: {e}"))?
+    } else {
+        let mut cmd = tokio::process::Command::new("git");
+        cmd.arg("-C").arg(&workspace_path).arg("diff");
+        if staged {
+            cmd.arg("--cached");
+        }
+        cmd.arg("--").arg(&target);
+        cmd.output().await.map_err(|e| format!("git diff failed: {e}"))?
+    };
+
+    // `git diff --no-index` exits 1 to mean "files differ", not an error; treat any
+    // exit code above 1 (or a missing one, i.e. killed by a signal) as a real failure.
+    let exit_code = output.status.code().unwrap_or(2);
+    if exit_code > 1 {
+        return Err(format!("git diff failed: {}", String::from_utf8_lossy(&output.stderr)));
+    }
+
+    let full = String::from_utf8_lossy(&output.stdout).into_owned();
+    if full.contains("Binary files") && full.contains("differ") {
+        return Ok(GitFileDiff {
+            path: relative_path.to_string(),
+            staged,
+            binary: true,
+            diff: "Binary files differ".to_string(),
+            truncated: false,
+            hunks: 0,
+            insertions: 0,
+            deletions: 0,
+        });
+    }
+
+    let truncated = full.len() > MAX_GIT_DIFF_BYTES;
+    let diff = truncate_to_char_boundary(&full, MAX_GIT_DIFF_BYTES).to_string();
+    let (hunks, insertions, deletions) = summarize_diff(&full);
+
+    Ok(GitFileDiff { path: relative_path.to_string(), staged, binary: false, diff, truncated, hunks, insertions, deletions })
+}
+
+fn summarize_diff(diff: &str) -> (u32, u32, u32) {
+    let mut hunks = 0;
+    let mut insertions = 0;
+    let mut deletions = 0;
+    for line in diff.lines() {
+        if line.starts_with("@@") {
+            hunks += 1;
+        } else if line.starts_with('+') && !line.starts_with("+++") {
+            insertions += 1;
+        } else if line.starts_with('-') && !line.starts_with("---") {
+            deletions += 1;
+        }
+    }
+    (hunks, insertions, deletions)
+}
+
+/// Diffs `current_content` (e.g. an editor's unsaved buffer) against the file's blob at
+/// `HEAD`, rather than whatever is currently saved on disk. Both sides are written to
+/// temp files and compared with `git diff --no-index`, so this works whether or not the
+/// buffer matches the on-disk copy. If the file has no `HEAD` version yet (it's new and
+/// unstaged/untracked), it's diffed against an empty blob instead of erroring.
+pub async fn diff_workspace_file_from_head(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    relative_path: &str,
+    current_content: &str,
+) -> Result<GitFileDiff, String> {
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Err(format!("workspace {workspace_id} is not a git repository"));
+    }
+    // Validates the path stays inside the workspace; the target need not exist on disk
+    // since we're diffing supplied content, not the file itself.
+    if Path::new(relative_path).components().any(|c| matches!(c, std::path::Component::ParentDir)) {
+        return Err("path traversal blocked".to_string());
+    }
+
+    let current_path = write_temp_diff_file(current_content.as_bytes())?;
+    let _current_guard = TempFileGuard(current_path.clone());
+
+    let head_blob = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(&workspace_path)
+        .arg("show")
+        .arg(format!("HEAD:{relative_path}"))
+        .output()
+        .await
+        .map_err(|e| format!("git show failed: {e}"))?;
+
+    let head_path = write_temp_diff_file(if head_blob.status.success() { &head_blob.stdout } else { &[] })?;
+    let _head_guard = TempFileGuard(head_path.clone());
+
+    let output = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(&workspace_path)
+        .arg("diff")
+        .arg("--no-index")
+        .arg("--")
+        .arg(&head_path)
+        .arg(&current_path)
+        .output()
+        .await
+        .map_err(|e| format!("git diff failed: {e}"))?;
+
+    let exit_code = output.status.code().unwrap_or(2);
+    if exit_code > 1 {
+        return Err(format!("git diff failed: {}", String::from_utf8_lossy(&output.stderr)));
+    }
+
+    let full = String::from_utf8_lossy(&output.stdout).into_owned();
+    let truncated = full.len() > MAX_GIT_DIFF_BYTES;
+    let diff = truncate_to_char_boundary(&full, MAX_GIT_DIFF_BYTES).to_string();
+    let (hunks, insertions, deletions) = summarize_diff(&full);
+
+    Ok(GitFileDiff {
+        path: relative_path.to_string(),
+        staged: false,
+        binary: false,
+        diff,
+        truncated,
+        hunks,
+        insertions,
+        deletions,
+    })
+}
+
+/// Writes `contents` to a uniquely-named file under the system temp dir for use as one
+/// side of a `git diff --no-index` comparison.
+fn write_temp_diff_file(contents: &[u8]) -> Result<std::path::PathBuf, String> {
+    let name = format!("controlroom-diff-{}-{}.tmp", std::process::id(), rand_suffix());
+    let path = std::env::temp_dir().join(name);
+    std::fs::write(&path, contents).map_err(|e| format!("failed to write temp diff file: {e}"))?;
+    Ok(path)
+}
+
+fn rand_suffix() -> u128 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0)
+}
+
+/// Deletes the wrapped path when dropped, guaranteeing temp-file cleanup on every exit
+/// path (including `?` early-returns) out of `diff_workspace_file_from_head`.
+struct TempFileGuard(std::path::PathBuf);
+
+impl Drop for TempFileGuard {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+/// Rejects git write operations (stage/unstage/commit, checkout, fetch/pull/push,
+/// stash push/apply) unless the deployment has opted in via `GitConfig::allow_write`,
+/// so read-only deployments keep today's inspect-only behavior.
+fn ensure_git_write_allowed(config: &ControlRoomConfig) -> Result<(), String> {
+    if !config.git.allow_write {
+        return Err("git write operations are disabled (set git.allowWrite to enable)".to_string());
+    }
+    Ok(())
+}
+
+/// Validates every path against the workspace root before it's ever handed to git,
+/// returning the canonicalized targets in the same order.
+fn resolve_targets(workspace_path: &str, paths: &[String]) -> Result<Vec<std::path::PathBuf>, String> {
+    if paths.is_empty() {
+        return Err("no paths given".to_string());
+    }
+    paths
+        .iter()
+        .map(|path| secure_target_path(Path::new(workspace_path), path))
+        .collect()
+}
+
+/// Stages `paths` via `git add`, then returns the resulting status. The argv is built
+/// with an explicit `--` separator and never passed through a shell, so a path that
+/// happens to look like a flag can't be misinterpreted.
+pub async fn stage_paths(config: &ControlRoomConfig, workspace_id: &str, paths: &[String]) -> Result<GitStatus, String> {
+    ensure_git_write_allowed(config)?;
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Err(format!("workspace {workspace_id} is not a git repository"));
+    }
+    let targets = resolve_targets(&workspace_path, paths)?;
+
+    let mut cmd = tokio::process::Command::new("git");
+    cmd.arg("-C").arg(&workspace_path).arg("add").arg("--");
+    cmd.args(&targets);
+    let output = cmd.output().await.map_err(|e| format!("git add failed: {e}"))?;
+    if !output.status.success() {
+        return Err(format!("git add failed: {}", String::from_utf8_lossy(&output.stderr)));
+    }
+
+    get_status(config, workspace_id).await
+}
+
+/// Unstages `paths` via `git restore --staged`, then returns the resulting status.
+pub async fn unstage_paths(config: &ControlRoomConfig, workspace_id: &str, paths: &[String]) -> Result<GitStatus, String> {
+    ensure_git_write_allowed(config)?;
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Err(format!("workspace {workspace_id} is not a git repository"));
+    }
+    let targets = resolve_targets(&workspace_path, paths)?;
+
+    let mut cmd = tokio::process::Command::new("git");
+    cmd.arg("-C").arg(&workspace_path).arg("restore").arg("--staged").arg("--");
+    cmd.args(&targets);
+    let output = cmd.output().await.map_err(|e| format!("git restore failed: {e}"))?;
+    if !output.status.success() {
+        return Err(format!("git restore failed: {}", String::from_utf8_lossy(&output.stderr)));
+    }
+
+    get_status(config, workspace_id).await
+}
+
+/// Commits the currently staged changes with `message`, optionally amending the
+/// previous commit, and returns the resulting commit. Rejects an empty message and,
+/// unless amending, rejects committing with nothing staged rather than letting git's
+/// own "nothing to commit" failure leak through as a generic error.
+pub async fn commit(config: &ControlRoomConfig, workspace_id: &str, message: &str, amend: bool) -> Result<GitCommit, String> {
+    ensure_git_write_allowed(config)?;
+    if message.trim().is_empty() {
+        return Err("commit message must not be empty".to_string());
+    }
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Err(format!("workspace {workspace_id} is not a git repository"));
+    }
+
+    if !amend {
+        let status = get_status(config, workspace_id).await?;
+        if status.staged == 0 {
+            return Err("nothing staged to commit".to_string());
+        }
+    }
+
+    let mut cmd = tokio::process::Command::new("git");
+    cmd.arg("-C").arg(&workspace_path).arg("commit");
+    if amend {
+        cmd.arg("--amend");
+    }
+    cmd.arg("-m").arg(message);
+    let output = cmd.output().await.map_err(|e| format!("git commit failed: {e}"))?;
+    if !output.status.success() {
+        return Err(format!("git commit failed: {}", String::from_utf8_lossy(&output.stderr)));
+    }
+
+    let show_output = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(&workspace_path)
+        .arg("show")
+        .arg("--no-patch")
+        .arg("--date=iso-strict")
+        .arg("--pretty=format:%H%x1f%h%x1f%an%x1f%ad%x1f%s")
+        .arg("HEAD")
+        .output()
+        .await
+        .map_err(|e| format!("git show failed: {e}"))?;
+    let text = String::from_utf8_lossy(&show_output.stdout);
+    parse_git_log(&text).into_iter().next().ok_or_else(|| "failed to read new commit".to_string())
+}
+
+/// A conservative allowlist for ref/branch names passed to `git checkout`: non-empty, no
+/// leading `-` (which git would otherwise parse as a flag), and no whitespace or the
+/// `..`/`~`/`^`/`:` characters git itself rejects in ref names, so a bad ref is caught
+/// with a clear error before git is ever invoked.
+fn is_valid_git_ref(git_ref: &str) -> bool {
+    !git_ref.is_empty()
+        && !git_ref.starts_with('-')
+        && !git_ref.contains("..")
+        && !git_ref
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '~' | '^' | ':'))
+}
+
+/// Rejects a `remote`/`branch` value that would be interpreted as a command-line flag
+/// rather than a positional argument (e.g. `--upload-pack=...`), the argument-injection
+/// vector git's transport helpers are vulnerable to. Unlike `is_valid_git_ref`, this
+/// allows remote URLs and other ref-unrelated shapes (`:`, `/`, `~` are all fine here) —
+/// it only guards against a leading `-`.
+fn is_valid_remote_or_branch(value: &str) -> bool {
+    !value.is_empty() && !value.starts_with('-')
+}
+
+async fn dirty_files(workspace_path: &str) -> Result<Vec<String>, String> {
+    let output = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(workspace_path)
+        .arg("status")
+        .arg("--porcelain")
+        .output()
+        .await
+        .map_err(|e| format!("git status failed: {e}"))?;
+    if !output.status.success() {
+        return Err(format!("git status failed: {}", String::from_utf8_lossy(&output.stderr)));
+    }
+    let text = String::from_utf8_lossy(&output.stdout);
+    Ok(parse_dirty_files(&text))
+}
+
+/// Parses `git status --porcelain` (v1) lines of the form `XY path` into just the paths.
+fn parse_dirty_files(text: &str) -> Vec<String> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| line.get(3..))
+        .map(|path| path.trim().to_string())
+        .filter(|path| !path.is_empty())
+        .collect()
+}
+
+/// Switches branches (or creates one with `-b` when `create` is set), refusing to run
+/// against a dirty tree unless `allow_dirty` is set. A dirty tree is reported as a
+/// `DirtyTreeBlocked` result rather than an `Err`, since it's an expected, actionable
+/// outcome rather than a failure. Checking out a raw commit hash succeeds but comes back
+/// with `detached: true` so the UI can flag it.
+pub async fn checkout(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    git_ref: &str,
+    create: bool,
+    allow_dirty: bool,
+) -> Result<GitCheckoutResult, String> {
+    ensure_git_write_allowed(config)?;
+    if !is_valid_git_ref(git_ref) {
+        return Err(format!("invalid ref name: {git_ref:?}"));
+    }
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Err(format!("workspace {workspace_id} is not a git repository"));
+    }
+
+    if !allow_dirty {
+        let files = dirty_files(&workspace_path).await?;
+        if !files.is_empty() {
+            return Ok(GitCheckoutResult::DirtyTreeBlocked { files });
+        }
+    }
+
+    let mut cmd = tokio::process::Command::new("git");
+    cmd.arg("-C").arg(&workspace_path).arg("checkout");
+    if create {
+        cmd.arg("-b");
+    }
+    cmd.arg(git_ref);
+    let output = cmd.output().await.map_err(|e| format!("git checkout failed: {e}"))?;
+    if !output.status.success() {
+        return Err(format!("git checkout failed: {}", String::from_utf8_lossy(&output.stderr)));
+    }
+
+    let head_output = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(&workspace_path)
+        .arg("rev-parse")
+        .arg("--abbrev-ref")
+        .arg("HEAD")
+        .output()
+        .await
+        .map_err(|e| format!("git rev-parse failed: {e}"))?;
+    let head_name = String::from_utf8_lossy(&head_output.stdout).trim().to_string();
+    let detached = head_name == "HEAD";
+    let branch = if detached { None } else { Some(head_name) };
+
+    let commit_output = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(&workspace_path)
+        .arg("rev-parse")
+        .arg("HEAD")
+        .output()
+        .await
+        .map_err(|e| format!("git rev-parse failed: {e}"))?;
+    let commit = String::from_utf8_lossy(&commit_output.stdout).trim().to_string();
+
+    Ok(GitCheckoutResult::Switched { branch, commit, detached })
+}
+
+/// Reads `reader` (a child's piped stderr) splitting on either `\r` or `\n`, since git
+/// overwrites progress lines with `\r` rather than terminating them with `\n`. Each
+/// non-empty line is emitted as a `controlroom://git-progress` event and appended to the
+/// returned full text, which callers scan afterward to classify the outcome.
+async fn stream_git_progress<R: tokio::io::AsyncRead + Unpin>(
+    app: &AppHandle,
+    workspace_id: &str,
+    operation: &str,
+    mut reader: R,
+) -> String {
+    let mut pending = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let mut full_text = String::new();
+
+    loop {
+        let read = match reader.read(&mut chunk).await {
+            Ok(0) | Err(_) => break,
+            Ok(n) => n,
+        };
+        pending.extend_from_slice(&chunk[..read]);
+
+        while let Some(pos) = pending.iter().position(|&b| b == b'\r' || b == b'\n') {
+            let line_bytes: Vec<u8> = pending.drain(..=pos).collect();
+            let line = String::from_utf8_lossy(&line_bytes[..line_bytes.len() - 1]).into_owned();
+            let trimmed = line.trim();
+            if !trimmed.is_empty() {
+                full_text.push_str(trimmed);
+                full_text.push('\n');
+                emit_git_progress(
+                    app,
+                    &GitProgressEvent {
+                        workspace_id: workspace_id.to_string(),
+                        operation: operation.to_string(),
+                        message: trimmed.to_string(),
+                        percent: parse_progress_percent(trimmed),
+                    },
+                );
+            }
+        }
+    }
+
+    if !pending.is_empty() {
+        full_text.push_str(String::from_utf8_lossy(&pending).trim());
+        full_text.push('\n');
+    }
+
+    full_text
+}
+
+/// Pulls a `NN%` figure out of a line like `Receiving objects:  45% (450/1000)`.
+fn parse_progress_percent(line: &str) -> Option<f32> {
+    let percent_idx = line.find('%')?;
+    let before = &line[..percent_idx];
+    let start = before
+        .rfind(|c: char| !c.is_ascii_digit() && c != '.')
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    before[start..].parse::<f32>().ok()
+}
+
+fn is_auth_failure(text: &str) -> bool {
+    let lower = text.to_lowercase();
+    lower.contains("terminal prompts disabled")
+        || lower.contains("could not read username")
+        || lower.contains("could not read password")
+        || lower.contains("authentication failed")
+        || lower.contains("permission denied (publickey)")
+}
+
+/// Pulls the most relevant failure line out of git's stderr (a `[rejected]`/`error:`/
+/// `fatal:` line if present) rather than surfacing the whole stream.
+fn parse_rejection_reason(text: &str) -> String {
+    text.lines()
+        .rev()
+        .find(|line| {
+            line.contains("[rejected]") || line.contains("error:") || line.contains("fatal:") || line.contains("! [")
+        })
+        .or_else(|| text.lines().rev().find(|line| !line.trim().is_empty()))
+        .unwrap_or("git command failed")
+        .trim()
+        .to_string()
+}
+
+async fn rev_parse(workspace_path: &str, rev: &str) -> Result<String, String> {
+    let output = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(workspace_path)
+        .arg("rev-parse")
+        .arg(rev)
+        .output()
+        .await
+        .map_err(|e| format!("git rev-parse failed: {e}"))?;
+    if !output.status.success() {
+        return Err(format!("git rev-parse failed: {}", String::from_utf8_lossy(&output.stderr)));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Counts commits reachable from `after` but not `before` via `git rev-list --count`.
+/// Returns 0 if either endpoint is missing/unresolvable rather than erroring, since this
+/// is only used to report a "commits transferred" figure, not to gate an operation.
+async fn commits_transferred(workspace_path: &str, before: Option<&str>, after: &str) -> u32 {
+    let Some(before) = before else { return 0 };
+    if before == after {
+        return 0;
+    }
+    tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(workspace_path)
+        .arg("rev-list")
+        .arg("--count")
+        .arg(format!("{before}..{after}"))
+        .output()
+        .await
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8_lossy(&output.stdout).trim().parse().ok())
+        .unwrap_or(0)
+}
+
+/// Fetches `remote` (and optionally a single `branch`), streaming progress to
+/// `controlroom://git-progress`. `GIT_TERMINAL_PROMPT=0` keeps a missing credential
+/// helper from hanging the app on an interactive prompt; that failure mode is instead
+/// surfaced as a clear "authentication required" error. `commits_transferred` is only
+/// precise when `branch` is given (it diffs the remote-tracking ref before/after);
+/// a whole-remote fetch instead counts updated-ref lines as an approximation.
+pub async fn fetch(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    remote: &str,
+    branch: Option<&str>,
+    app: &AppHandle,
+) -> Result<GitFetchResult, String> {
+    ensure_git_write_allowed(config)?;
+    if !is_valid_remote_or_branch(remote) {
+        return Err(format!("invalid remote: {remote:?}"));
+    }
+    if let Some(branch) = branch {
+        if !is_valid_remote_or_branch(branch) {
+            return Err(format!("invalid branch name: {branch:?}"));
+        }
+    }
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Err(format!("workspace {workspace_id} is not a git repository"));
+    }
+
+    let tracking_ref = branch.map(|branch| format!("refs/remotes/{remote}/{branch}"));
+    let before = match &tracking_ref {
+        Some(r) => rev_parse(&workspace_path, r).await.ok(),
+        None => None,
+    };
+
+    let mut cmd = tokio::process::Command::new("git");
+    cmd.arg("-C").arg(&workspace_path).arg("fetch").arg("--progress").arg(remote);
+    if let Some(branch) = branch {
+        cmd.arg(branch);
+    }
+    cmd.env("GIT_TERMINAL_PROMPT", "0");
+    cmd.stdout(Stdio::null()).stderr(Stdio::piped());
+
+    let mut child = cmd.spawn().map_err(|e| format!("git fetch failed: {e}"))?;
+    let stderr = child.stderr.take().ok_or("failed to capture git fetch stderr")?;
+    let text = stream_git_progress(app, workspace_id, "fetch", stderr).await;
+    let status = child.wait().await.map_err(|e| format!("git fetch failed: {e}"))?;
+
+    if is_auth_failure(&text) {
+        return Err("authentication required, configure a credential helper".to_string());
+    }
+    if !status.success() {
+        return Ok(GitFetchResult::Rejected { reason: parse_rejection_reason(&text) });
+    }
+
+    let commits = match (&tracking_ref, before) {
+        (Some(tracking_ref), before) => {
+            let after = rev_parse(&workspace_path, tracking_ref).await.unwrap_or_default();
+            commits_transferred(&workspace_path, before.as_deref(), &after).await
+        }
+        (None, _) => text
+            .lines()
+            .filter(|line| line.contains("->") && (line.contains("..") || line.contains("[new")))
+            .count() as u32,
+    };
+
+    if commits == 0 {
+        Ok(GitFetchResult::UpToDate)
+    } else {
+        Ok(GitFetchResult::Updated { commits_transferred: commits })
+    }
+}
+
+/// Fetches and integrates `remote`/`branch` into the current branch, streaming progress
+/// the same way `fetch` does. Distinguishes a fast-forward from an actual merge by
+/// looking for git's own "Fast-forward" marker in the output.
+pub async fn pull(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    remote: &str,
+    branch: &str,
+    app: &AppHandle,
+) -> Result<GitPullResult, String> {
+    ensure_git_write_allowed(config)?;
+    if !is_valid_remote_or_branch(remote) {
+        return Err(format!("invalid remote: {remote:?}"));
+    }
+    if !is_valid_remote_or_branch(branch) {
+        return Err(format!("invalid branch name: {branch:?}"));
+    }
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Err(format!("workspace {workspace_id} is not a git repository"));
+    }
+
+    let before = rev_parse(&workspace_path, "HEAD").await.ok();
+
+    let mut cmd = tokio::process::Command::new("git");
+    cmd.arg("-C")
+        .arg(&workspace_path)
+        .arg("pull")
+        .arg("--progress")
+        .arg(remote)
+        .arg(branch);
+    cmd.env("GIT_TERMINAL_PROMPT", "0");
+    cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+
+    let mut child = cmd.spawn().map_err(|e| format!("git pull failed: {e}"))?;
+    let stderr = child.stderr.take().ok_or("failed to capture git pull stderr")?;
+    let mut stdout_text = String::new();
+    if let Some(mut stdout) = child.stdout.take() {
+        let _ = stdout.read_to_string(&mut stdout_text).await;
+    }
+    let progress_text = stream_git_progress(app, workspace_id, "pull", stderr).await;
+    let status = child.wait().await.map_err(|e| format!("git pull failed: {e}"))?;
+    let combined = format!("{progress_text}{stdout_text}");
+
+    if is_auth_failure(&combined) {
+        return Err("authentication required, configure a credential helper".to_string());
+    }
+    if !status.success() {
+        return Ok(GitPullResult::Rejected { reason: parse_rejection_reason(&combined) });
+    }
+
+    let after = rev_parse(&workspace_path, "HEAD").await.unwrap_or_default();
+    let commits = commits_transferred(&workspace_path, before.as_deref(), &after).await;
+    if commits == 0 {
+        return Ok(GitPullResult::UpToDate);
+    }
+
+    if combined.contains("Fast-forward") {
+        Ok(GitPullResult::FastForward { commits_transferred: commits })
+    } else {
+        Ok(GitPullResult::Merged { commits_transferred: commits })
+    }
+}
+
+/// Pushes the current branch to `remote`/`branch`, streaming progress the same way
+/// `fetch`/`pull` do. `commits_transferred` is measured as how far ahead of the
+/// remote-tracking ref the local branch was immediately before the push, since a plain
+/// `git push` doesn't update local remote-tracking refs itself.
+pub async fn push(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    remote: &str,
+    branch: &str,
+    app: &AppHandle,
+) -> Result<GitPushResult, String> {
+    ensure_git_write_allowed(config)?;
+    if !is_valid_remote_or_branch(remote) {
+        return Err(format!("invalid remote: {remote:?}"));
+    }
+    if !is_valid_remote_or_branch(branch) {
+        return Err(format!("invalid branch name: {branch:?}"));
+    }
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Err(format!("workspace {workspace_id} is not a git repository"));
+    }
+
+    let local_head = rev_parse(&workspace_path, "HEAD").await.ok();
+    let tracking_ref = format!("refs/remotes/{remote}/{branch}");
+    let before_remote = rev_parse(&workspace_path, &tracking_ref).await.ok();
+    let commits = match (&before_remote, &local_head) {
+        (Some(before), Some(head)) => commits_transferred(&workspace_path, Some(before), head).await,
+        _ => 0,
+    };
+
+    let mut cmd = tokio::process::Command::new("git");
+    cmd.arg("-C")
+        .arg(&workspace_path)
+        .arg("push")
+        .arg("--progress")
+        .arg(remote)
+        .arg(branch);
+    cmd.env("GIT_TERMINAL_PROMPT", "0");
+    cmd.stdout(Stdio::null()).stderr(Stdio::piped());
+
+    let mut child = cmd.spawn().map_err(|e| format!("git push failed: {e}"))?;
+    let stderr = child.stderr.take().ok_or("failed to capture git push stderr")?;
+    let text = stream_git_progress(app, workspace_id, "push", stderr).await;
+    let status = child.wait().await.map_err(|e| format!("git push failed: {e}"))?;
+
+    if is_auth_failure(&text) {
+        return Err("authentication required, configure a credential helper".to_string());
+    }
+    if !status.success() {
+        return Ok(GitPushResult::Rejected { reason: parse_rejection_reason(&text) });
+    }
+
+    if commits == 0 {
+        Ok(GitPushResult::UpToDate)
+    } else {
+        Ok(GitPushResult::Accepted { commits_transferred: commits })
+    }
+}
+
+/// Tags sorted by git's own default order (creation order for lightweight tags is not
+/// tracked, so callers that want a specific order should sort client-side).
+pub async fn get_tags(config: &ControlRoomConfig, workspace_id: &str) -> Result<Vec<GitTag>, String> {
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Ok(Vec::new());
+    }
+
+    let output = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(&workspace_path)
+        .arg("tag")
+        .arg("-l")
+        .arg(
+            "--format=%(refname:short)%09%(objectname)%09%(objecttype)%09%(contents:subject)%09%(creatordate:iso-strict)%09%(committerdate:iso-strict)",
+        )
+        .output()
+        .await
+        .map_err(|e| format!("git tag failed: {e}"))?;
+
+    if !output.status.success() {
+        return Ok(Vec::new());
+    }
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    Ok(parse_tag_list(&text))
+}
+
+fn parse_tag_list(text: &str) -> Vec<GitTag> {
+    let mut tags = Vec::new();
+
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.split('\t').collect();
+        if parts.len() != 6 {
+            continue;
+        }
+
+        let is_annotated = parts[2] == "tag";
+        let annotation = if is_annotated && !parts[3].is_empty() { Some(parts[3].to_string()) } else { None };
+        let date = if !parts[4].is_empty() {
+            Some(parts[4].to_string())
+        } else if !parts[5].is_empty() {
+            Some(parts[5].to_string())
+        } else {
+            None
+        };
+
+        tags.push(GitTag { name: parts[0].to_string(), target_hash: parts[1].to_string(), annotation, date });
+    }
+
+    tags
+}
+
+/// Stashes sorted newest-first (git's own `stash list` order).
+pub async fn get_stash_list(config: &ControlRoomConfig, workspace_id: &str) -> Result<Vec<GitStashEntry>, String> {
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Ok(Vec::new());
+    }
+
+    let output = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(&workspace_path)
+        .arg("stash")
+        .arg("list")
+        .arg("--format=%gd%x09%s")
+        .output()
+        .await
+        .map_err(|e| format!("git stash list failed: {e}"))?;
+
+    if !output.status.success() {
+        return Ok(Vec::new());
+    }
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    Ok(parse_stash_list(&text))
+}
+
+/// Parses lines like `stash@{0}\tWIP on main: 1234567 message` into entries, pulling the
+/// branch name back out of git's own "WIP on <branch>:" / "On <branch>:" message prefix
+/// (`git stash list` doesn't expose the branch as a separate field).
+fn parse_stash_list(text: &str) -> Vec<GitStashEntry> {
+    let mut entries = Vec::new();
+
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let Some((ref_part, message)) = line.split_once('\t') else { continue };
+        let Some(index_str) = ref_part.strip_prefix("stash@{").and_then(|s| s.strip_suffix('}')) else { continue };
+        let Ok(index) = index_str.parse::<u32>() else { continue };
+
+        let branch = message
+            .strip_prefix("WIP on ")
+            .or_else(|| message.strip_prefix("On "))
+            .and_then(|rest| rest.split_once(':'))
+            .map(|(branch, _)| branch.to_string());
+
+        entries.push(GitStashEntry { index, message: message.to_string(), branch });
+    }
+
+    entries
+}
+
+pub async fn stash_push(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    message: Option<&str>,
+    include_untracked: bool,
+) -> Result<GitStashEntry, String> {
+    ensure_git_write_allowed(config)?;
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Err(format!("workspace {workspace_id} is not a git repository"));
+    }
+
+    let mut cmd = tokio::process::Command::new("git");
+    cmd.arg("-C").arg(&workspace_path).arg("stash").arg("push");
+    if include_untracked {
+        cmd.arg("--include-untracked");
+    }
+    if let Some(message) = message {
+        cmd.arg("-m").arg(message);
+    }
+
+    let output = cmd.output().await.map_err(|e| format!("git stash push failed: {e}"))?;
+    if !output.status.success() {
+        return Err(format!("git stash push failed: {}", String::from_utf8_lossy(&output.stderr)));
+    }
+
+    get_stash_list(config, workspace_id)
+        .await?
+        .into_iter()
+        .find(|entry| entry.index == 0)
+        .ok_or_else(|| "stash push reported success but no stash entry was created".to_string())
+}
+
+pub async fn stash_apply(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    index: u32,
+    pop: bool,
+) -> Result<GitStashApplyResult, String> {
+    ensure_git_write_allowed(config)?;
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Err(format!("workspace {workspace_id} is not a git repository"));
+    }
+
+    let output = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(&workspace_path)
+        .arg("stash")
+        .arg(if pop { "pop" } else { "apply" })
+        .arg(format!("stash@{{{index}}}"))
+        .output()
+        .await
+        .map_err(|e| format!("git stash {} failed: {e}", if pop { "pop" } else { "apply" }))?;
+
+    let text = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    if output.status.success() && !text.contains("CONFLICT") {
+        return Ok(GitStashApplyResult::Applied);
+    }
+
+    let files = parse_stash_conflict_files(&text);
+    if files.is_empty() {
+        return Err(format!("git stash {} failed: {text}", if pop { "pop" } else { "apply" }));
+    }
+    Ok(GitStashApplyResult::Conflicted { files })
+}
+
+/// Pulls file paths out of `CONFLICT (content): Merge conflict in <path>` lines.
+fn parse_stash_conflict_files(text: &str) -> Vec<String> {
+    text.lines()
+        .filter_map(|line| line.split("Merge conflict in ").nth(1))
+        .map(|path| path.trim().to_string())
+        .collect()
+}
+
+fn is_hex_hash(hash: &str) -> bool {
+    !hash.is_empty() && hash.len() <= 40 && hash.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn status_kind(code: &str) -> &'static str {
+    match code.chars().next().unwrap_or('M') {
+        'A' => "added",
+        'D' => "deleted",
+        'R' => "renamed",
+        'C' => "copied",
+        'T' => "type-changed",
+        'U' => "conflicted",
+        _ => "modified",
+    }
+}
+
+/// Truncates `s` to at most `max_bytes` bytes without splitting a UTF-8 code point.
+fn truncate_to_char_boundary(s: &str, max_bytes: usize) -> &str {
+    if s.len() <= max_bytes {
+        return s;
+    }
+    let mut end = max_bytes;
+    while end > 0 && !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
+
+/// Full detail for one commit: header fields, per-file changes, and optionally the
+/// unified diff for a single requested file, capped at `MAX_GIT_DIFF_BYTES`.
+pub async fn get_commit_detail(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    hash: &str,
+    diff_file_path: Option<&str>,
+) -> Result<GitCommitDetail, String> {
+    if !is_hex_hash(hash) {
+        return Err(format!("invalid commit hash: {hash:?}"));
+    }
+
+    let workspace_path = find_workspace_path(config, workspace_id)?;
+    if !is_git_repo(&workspace_path).await {
+        return Err(format!("workspace {workspace_id} is not a git repository"));
+    }
+
+    let header_output = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(&workspace_path)
+        .arg("show")
+        .arg("--no-patch")
+        .arg("--date=iso-strict")
+        .arg("--format=%H%x1f%h%x1f%an%x1f%ae%x1f%ad%x1f%cn%x1f%ce%x1f%cd%x1f%P%x1f%s%x1f%b")
+        .arg(hash)
+        .output()
+        .await
+        .map_err(|e| format!("git show failed: {e}"))?;
+
+    if !header_output.status.success() {
+        return Err(format!("unknown commit: {hash}"));
+    }
+
+    let header_text = String::from_utf8_lossy(&header_output.stdout);
+    let mut detail = parse_commit_header(&header_text)?;
+
+    let numstat_output = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(&workspace_path)
+        .arg("show")
+        .arg("--format=")
+        .arg("--numstat")
+        .arg("-z")
+        .arg(hash)
+        .output()
+        .await
+        .map_err(|e| format!("git show --numstat failed: {e}"))?;
+
+    let name_status_output = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(&workspace_path)
+        .arg("show")
+        .arg("--format=")
+        .arg("--name-status")
+        .arg("-z")
+        .arg(hash)
+        .output()
+        .await
+        .map_err(|e| format!("git show --name-status failed: {e}"))?;
+
+    let numstat_text = String::from_utf8_lossy(&numstat_output.stdout);
+    let name_status_text = String::from_utf8_lossy(&name_status_output.stdout);
+    detail.files = merge_file_changes(&numstat_text, &name_status_text);
+
+    if let Some(path) = diff_file_path {
+        let diff_output = tokio::process::Command::new("git")
+            .arg("-C")
+            .arg(&workspace_path)
+            .arg("show")
+            .arg("--format=")
+            .arg(hash)
+            .arg("--")
+            .arg(path)
+            .output()
+            .await
+            .map_err(|e| format!("git show diff failed: {e}"))?;
+
+        let full = String::from_utf8_lossy(&diff_output.stdout).into_owned();
+        let truncated = full.len() > MAX_GIT_DIFF_BYTES;
+        detail.diff = Some(truncate_to_char_boundary(&full, MAX_GIT_DIFF_BYTES).to_string());
+        detail.diff_truncated = truncated;
+    }
+
+    Ok(detail)
+}
+
+fn parse_commit_header(text: &str) -> Result<GitCommitDetail, String> {
+    let trimmed = text.trim_end_matches('\n');
+    let parts: Vec<&str> = trimmed.splitn(11, '\u{1f}').collect();
+    if parts.len() != 11 {
+        return Err("failed to parse commit header".to_string());
+    }
+
+    let parent_hashes = if parts[8].is_empty() {
+        Vec::new()
+    } else {
+        parts[8].split(' ').map(|s| s.to_string()).collect()
+    };
+
+    Ok(GitCommitDetail {
+        hash: parts[0].to_string(),
+        short_hash: parts[1].to_string(),
+        author: parts[2].to_string(),
+        author_email: parts[3].to_string(),
+        author_date: parts[4].to_string(),
+        committer: parts[5].to_string(),
+        committer_email: parts[6].to_string(),
+        committer_date: parts[7].to_string(),
+        parent_hashes,
+        subject: parts[9].to_string(),
+        body: parts[10].trim_end_matches('\n').to_string(),
+        files: Vec::new(),
+        diff: None,
+        diff_truncated: false,
+    })
+}
+
+/// Parses NUL-separated `git show --numstat -z` output into `(path, previous_path,
+/// insertions, deletions, binary)` tuples. With `-z`, a rename's old and new paths are
+/// separate NUL-terminated fields instead of the human-readable `old => new` form.
+fn parse_numstat_z(raw: &str) -> Vec<(String, Option<String>, Option<u32>, Option<u32>, bool)> {
+    let tokens: Vec<&str> = raw.split('\u{0}').filter(|s| !s.is_empty()).collect();
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let parts: Vec<&str> = tokens[i].splitn(3, '\t').collect();
+        if parts.len() != 3 {
+            i += 1;
+            continue;
+        }
+        let (ins_raw, del_raw, path_field) = (parts[0], parts[1], parts[2]);
+        let binary = ins_raw == "-" || del_raw == "-";
+        let insertions = ins_raw.parse::<u32>().ok();
+        let deletions = del_raw.parse::<u32>().ok();
+
+        if i + 1 < tokens.len() && !tokens[i + 1].contains('\t') {
+            let previous = path_field.to_string();
+            let new_path = tokens[i + 1].to_string();
+            out.push((new_path, Some(previous), insertions, deletions, binary));
+            i += 2;
+        } else {
+            out.push((path_field.to_string(), None, insertions, deletions, binary));
+            i += 1;
+        }
+    }
+    out
+}
+
+/// Parses NUL-separated `git show --name-status -z` output into `(path, previous_path,
+/// status_kind)` tuples.
+fn parse_name_status_z(raw: &str) -> Vec<(String, Option<String>, String)> {
+    let tokens: Vec<&str> = raw.split('\u{0}').filter(|s| !s.is_empty()).collect();
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let code = tokens[i];
+        let first = code.chars().next().unwrap_or('M');
+        if (first == 'R' || first == 'C') && i + 2 < tokens.len() {
+            out.push((tokens[i + 2].to_string(), Some(tokens[i + 1].to_string()), status_kind(code).to_string()));
+            i += 3;
+        } else if i + 1 < tokens.len() {
+            out.push((tokens[i + 1].to_string(), None, status_kind(code).to_string()));
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+    out
+}
+
+fn merge_file_changes(numstat_raw: &str, name_status_raw: &str) -> Vec<GitCommitFileChange> {
+    let statuses: HashMap<String, (Option<String>, String)> = parse_name_status_z(name_status_raw)
+        .into_iter()
+        .map(|(path, previous, kind)| (path, (previous, kind)))
+        .collect();
+
+    parse_numstat_z(numstat_raw)
+        .into_iter()
+        .map(|(path, previous_from_numstat, insertions, deletions, binary)| {
+            let matched = statuses.get(&path);
+            let previous_path = matched.and_then(|(previous, _)| previous.clone()).or(previous_from_numstat);
+            let status = matched.map(|(_, kind)| kind.clone()).unwrap_or_else(|| "modified".to_string());
+            GitCommitFileChange { path, previous_path, status, insertions, deletions, binary }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_branch_list_with_ahead_behind_and_current_marker() {
+        let sample = "main\t*\torigin/main\t[ahead 2, behind 1]\tdeadbeefcafebabe\nfeature/x\t \t\t\t1234567890abcdef\n";
+        let branches = parse_branch_list(sample);
+        assert_eq!(branches.len(), 2);
+        assert_eq!(branches[0].name, "main");
+        assert!(branches[0].is_current);
+        assert_eq!(branches[0].upstream.as_deref(), Some("origin/main"));
+        assert_eq!(branches[0].ahead, 2);
+        assert_eq!(branches[0].behind, 1);
+        assert_eq!(branches[1].name, "feature/x");
+        assert!(!branches[1].is_current);
+        assert_eq!(branches[1].upstream, None);
+        assert_eq!(branches[1].ahead, 0);
+        assert_eq!(branches[1].behind, 0);
+    }
+
+    #[test]
+    fn parses_upstream_track_variants() {
+        assert_eq!(parse_upstream_track(""), (0, 0));
+        assert_eq!(parse_upstream_track("[ahead 3]"), (3, 0));
+        assert_eq!(parse_upstream_track("[behind 4]"), (0, 4));
+        assert_eq!(parse_upstream_track("[ahead 2, behind 1]"), (2, 1));
+        assert_eq!(parse_upstream_track("[gone]"), (0, 0));
+    }
+
+    #[test]
+    fn parses_clean_status_on_current_branch() {
+        let sample = "# branch.oid deadbeef\n# branch.head main\n# branch.upstream origin/main\n# branch.ab +0 -0\n";
+        let status = parse_status_porcelain_v2(sample);
+        assert_eq!(status.branch.as_deref(), Some("main"));
+        assert!(!status.detached);
+        assert_eq!(status.staged, 0);
+        assert_eq!(status.unstaged, 0);
+        assert_eq!(status.untracked, 0);
+    }
+
+    #[test]
+    fn parses_status_with_staged_unstaged_and_untracked_files() {
+        let sample = concat!(
+            "# branch.oid deadbeef\n",
+            "# branch.head main\n",
+            "1 M. N... 100644 100644 100644 aaaa bbbb staged.txt\n",
+            "1 .M N... 100644 100644 100644 aaaa bbbb unstaged.txt\n",
+            "1 MM N... 100644 100644 100644 aaaa bbbb both.txt\n",
+            "? new_file.txt\n",
+        );
+        let status = parse_status_porcelain_v2(sample);
+        assert_eq!(status.branch.as_deref(), Some("main"));
+        assert_eq!(status.staged, 2);
+        assert_eq!(status.unstaged, 2);
+        assert_eq!(status.untracked, 1);
+    }
+
+    #[test]
+    fn parses_detached_head_status() {
+        let sample = "# branch.oid deadbeef\n# branch.head (detached)\n";
+        let status = parse_status_porcelain_v2(sample);
+        assert_eq!(status.branch, None);
+        assert!(status.detached);
+    }
+
+    #[test]
+    fn validates_hex_hashes() {
+        assert!(is_hex_hash("deadbeef"));
+        assert!(is_hex_hash("a1b2c3d4e5f6a1b2c3d4e5f6a1b2c3d4e5f6a1b2"));
+        assert!(!is_hex_hash(""));
+        assert!(!is_hex_hash("not-a-hash"));
+        assert!(!is_hex_hash("a1b2c3d4e5f6a1b2c3d4e5f6a1b2c3d4e5f6a1b2c"));
+    }
+
+    #[test]
+    fn parses_commit_header_with_multiline_body() {
+        let sample = "abc123\u{1f}abc\u{1f}Alice\u{1f}alice@example.com\u{1f}2024-01-01T00:00:00+00:00\u{1f}Bob\u{1f}bob@example.com\u{1f}2024-01-01T00:00:00+00:00\u{1f}parent1 parent2\u{1f}Fix the bug\u{1f}Longer explanation.\n\nSecond paragraph.\n";
+        let detail = parse_commit_header(sample).unwrap();
+        assert_eq!(detail.hash, "abc123");
+        assert_eq!(detail.parent_hashes, vec!["parent1", "parent2"]);
+        assert_eq!(detail.subject, "Fix the bug");
+        assert_eq!(detail.body, "Longer explanation.\n\nSecond paragraph.");
+    }
+
+    #[test]
+    fn parses_numstat_with_binary_and_rename() {
+        let sample = "5\t2\tsrc/main.rs\0-\t-\tassets/logo.png\010\t0\told/name.rs\0new/name.rs\0";
+        let rows = parse_numstat_z(sample);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0], ("src/main.rs".to_string(), None, Some(5), Some(2), false));
+        assert_eq!(rows[1], ("assets/logo.png".to_string(), None, None, None, true));
+        assert_eq!(rows[2], ("new/name.rs".to_string(), Some("old/name.rs".to_string()), Some(10), Some(0), false));
+    }
+
+    #[test]
+    fn parses_name_status_with_rename() {
+        let sample = "M\0src/main.rs\0R100\0old/name.rs\0new/name.rs\0";
+        let rows = parse_name_status_z(sample);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0], ("src/main.rs".to_string(), None, "modified".to_string()));
+        assert_eq!(rows[1], ("new/name.rs".to_string(), Some("old/name.rs".to_string()), "renamed".to_string()));
+    }
+
+    #[test]
+    fn merges_file_changes_from_numstat_and_name_status() {
+        let numstat = "5\t2\tsrc/main.rs\0-\t-\tassets/logo.png\0";
+        let name_status = "M\0src/main.rs\0A\0assets/logo.png\0";
+        let files = merge_file_changes(numstat, name_status);
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].path, "src/main.rs");
+        assert_eq!(files[0].status, "modified");
+        assert_eq!(files[0].insertions, Some(5));
+        assert!(!files[0].binary);
+        assert_eq!(files[1].path, "assets/logo.png");
+        assert_eq!(files[1].status, "added");
+        assert_eq!(files[1].insertions, None);
+        assert!(files[1].binary);
+    }
+
+    #[test]
+    fn truncates_on_char_boundary() {
+        let s = "héllo world";
+        let truncated = truncate_to_char_boundary(s, 2);
+        assert!(s.is_char_boundary(truncated.len()));
+        assert!(truncated.len() <= 2);
+    }
+
+    #[test]
+    fn validates_git_ref_names() {
+        assert!(is_valid_git_ref("main"));
+        assert!(is_valid_git_ref("feature/x"));
+        assert!(is_valid_git_ref("a1b2c3d4"));
+        assert!(!is_valid_git_ref(""));
+        assert!(!is_valid_git_ref("--force"));
+        assert!(!is_valid_git_ref("main..feature"));
+        assert!(!is_valid_git_ref("branch with space"));
+        assert!(!is_valid_git_ref("HEAD~1"));
+        assert!(!is_valid_git_ref("HEAD^"));
+        assert!(!is_valid_git_ref("origin:main"));
+    }
+
+    #[test]
+    fn validates_remote_and_branch_names() {
+        assert!(is_valid_remote_or_branch("origin"));
+        assert!(is_valid_remote_or_branch("feature/x"));
+        assert!(is_valid_remote_or_branch("https://example.com/repo.git"));
+        assert!(!is_valid_remote_or_branch(""));
+        assert!(!is_valid_remote_or_branch("--upload-pack=touch /tmp/pwned"));
+        assert!(!is_valid_remote_or_branch("-o"));
+    }
+
+    #[test]
+    fn parses_dirty_files_from_porcelain_status() {
+        let sample = " M src/main.rs\n?? new_file.txt\nA  staged.txt\n";
+        let files = parse_dirty_files(sample);
+        assert_eq!(files, vec!["src/main.rs", "new_file.txt", "staged.txt"]);
+    }
+
+    #[test]
+    fn parses_progress_percent_from_git_output() {
+        assert_eq!(parse_progress_percent("Receiving objects:  45% (450/1000)"), Some(45.0));
+        assert_eq!(parse_progress_percent("Compressing objects: 100% (10/10), done."), Some(100.0));
+        assert_eq!(parse_progress_percent("Enumerating objects: 5, done."), None);
+    }
+
+    #[test]
+    fn detects_auth_failures() {
+        assert!(is_auth_failure("fatal: could not read Username for 'https://github.com'"));
+        assert!(is_auth_failure(
+            "git@github.com: Permission denied (publickey).\nfatal: Could not read from remote repository."
+        ));
+        assert!(is_auth_failure("remote: Terminal prompts disabled.\n"));
+        assert!(!is_auth_failure("Everything up-to-date\n"));
+    }
+
+    #[test]
+    fn parses_rejection_reason_from_push_output() {
+        let sample = concat!(
+            "To github.com:example/repo.git\n",
+            " ! [rejected]        main -> main (fetch first)\n",
+            "error: failed to push some refs to 'github.com:example/repo.git'\n",
+        );
+        let reason = parse_rejection_reason(sample);
+        assert!(reason.contains("failed to push"));
+    }
+
+    #[test]
+    fn parses_left_right_counts() {
+        assert_eq!(parse_left_right_count("2\t1\n"), (2, 1));
+        assert_eq!(parse_left_right_count("0\t0"), (0, 0));
+    }
+
+    #[test]
+    fn validates_git_date_filters() {
+        assert!(validate_git_date("2024-01-31").is_ok());
+        assert!(validate_git_date("2024-01-31T10:00:00Z").is_ok());
+        assert!(validate_git_date("2 weeks ago").is_ok());
+        assert!(validate_git_date("yesterday").is_ok());
+        assert!(validate_git_date("").is_err());
+        assert!(validate_git_date("not a date").is_err());
+        assert!(validate_git_date("next tuesday").is_err());
+    }
+
+    #[test]
+    fn parses_tag_list_with_annotated_and_lightweight_tags() {
+        let sample = concat!(
+            "v1.0.0\tabc123\ttag\tRelease 1.0.0\t2024-01-01T00:00:00+00:00\t2024-01-01T00:00:00+00:00\n",
+            "v0.9.0\tdef456\tcommit\t\t\t2023-06-01T00:00:00+00:00\n",
+        );
+        let tags = parse_tag_list(sample);
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[0].name, "v1.0.0");
+        assert_eq!(tags[0].annotation.as_deref(), Some("Release 1.0.0"));
+        assert_eq!(tags[0].date.as_deref(), Some("2024-01-01T00:00:00+00:00"));
+        assert_eq!(tags[1].name, "v0.9.0");
+        assert_eq!(tags[1].annotation, None);
+        assert_eq!(tags[1].date.as_deref(), Some("2023-06-01T00:00:00+00:00"));
+    }
+
+    #[test]
+    fn parses_stash_list_and_extracts_branch() {
+        let sample = concat!(
+            "stash@{0}\tWIP on main: 1234567 in-progress refactor\n",
+            "stash@{1}\tOn feature/x: quick save\n",
+        );
+        let entries = parse_stash_list(sample);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].index, 0);
+        assert_eq!(entries[0].branch.as_deref(), Some("main"));
+        assert_eq!(entries[1].index, 1);
+        assert_eq!(entries[1].branch.as_deref(), Some("feature/x"));
+    }
+
+    #[test]
+    fn parses_stash_conflict_files_from_apply_output() {
+        let sample = concat!(
+            "Auto-merging src/main.rs\n",
+            "CONFLICT (content): Merge conflict in src/main.rs\n",
+            "CONFLICT (content): Merge conflict in src/lib.rs\n",
+        );
+        let files = parse_stash_conflict_files(sample);
+        assert_eq!(files, vec!["src/main.rs", "src/lib.rs"]);
+    }
+
+    #[test]
+    fn summarizes_diff_hunks_and_line_counts() {
+        let sample = concat!(
+            "diff --git a/foo.txt b/foo.txt\n",
+            "index abc..def 100644\n",
+            "--- a/foo.txt\n",
+            "+++ b/foo.txt\n",
+            "@@ -1,2 +1,3 @@\n",
+            " unchanged\n",
+            "-removed line\n",
+            "+added line one\n",
+            "+added line two\n",
+        );
+        let (hunks, insertions, deletions) = summarize_diff(sample);
+        assert_eq!(hunks, 1);
+        assert_eq!(insertions, 2);
+        assert_eq!(deletions, 1);
+    }
 }
@@ -1,52 +1,260 @@
 pub mod config;
+pub mod env_file;
 pub mod events;
+pub mod follow_manager;
 pub mod git_provider;
 pub mod process_manager;
+pub mod recent_files;
 pub mod runner_manager;
 pub mod types;
 pub mod video_manager;
+pub mod watch_manager;
 pub mod workspace;
 
-use crate::controlroom::config::load_controlroom_config;
+use crate::controlroom::config::{load_controlroom_config, restore_redacted_secrets, save_controlroom_config};
+use crate::controlroom::events::{emit_config_reloaded, emit_shutdown};
+use crate::controlroom::follow_manager::FollowManager;
 use crate::controlroom::process_manager::ControlRoomProcessManager;
+use crate::controlroom::recent_files::RecentFilesManager;
 use crate::controlroom::runner_manager::RunnerManager;
-use crate::controlroom::types::ControlRoomConfig;
+use crate::controlroom::types::{ConfigIssue, ControlRoomConfig, ControlRoomShutdownEvent, ServiceConfig};
 use crate::controlroom::video_manager::VideoManager;
+use crate::controlroom::watch_manager::WatchManager;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::Duration;
+use tauri::AppHandle;
+use tokio::sync::{Mutex, RwLock};
+use tokio_util::sync::CancellationToken;
 
 #[derive(Debug)]
 pub struct ControlRoomState {
     config: Arc<RwLock<ControlRoomConfig>>,
+    config_issues: Arc<RwLock<Vec<ConfigIssue>>>,
     process_manager: Arc<ControlRoomProcessManager>,
     runner_manager: Arc<RunnerManager>,
     video_manager: Arc<VideoManager>,
+    watch_manager: Arc<WatchManager>,
+    follow_manager: Arc<FollowManager>,
+    recent_files: Arc<RecentFilesManager>,
+    /// Cancelled by `shutdown` so background watcher tasks (health checks, log
+    /// watchers) can observe it and exit instead of outliving the app.
+    shutdown_token: CancellationToken,
+    /// Set once the first `load_config` call succeeds, so late-opened windows can ask
+    /// `is_initialized` instead of racing the `controlroom://state-ready` event.
+    initialized: AtomicBool,
+    /// Set once `config` has been populated by a real `load_config`/`reload_config`
+    /// call, so `ensure_config` can skip re-reading the file on every command and
+    /// just clone the cached value instead.
+    config_loaded: AtomicBool,
+    /// Cancellation tokens for in-flight workspace checksum operations, keyed by the
+    /// `operation_id` the frontend supplied when starting one, so it can be cancelled
+    /// mid-stream (e.g. the frontend navigated away) without waiting for it to finish.
+    checksum_tokens: Mutex<HashMap<String, CancellationToken>>,
+    /// SHA-256 of the base config file's raw bytes as of the last successful
+    /// `load_config`/`reload_config`, so `save_config` can detect the file being
+    /// modified externally (e.g. by a text editor) in the meantime.
+    last_known_checksum: Mutex<Option<[u8; 32]>>,
 }
 
+const SHUTDOWN_STOP_TIMEOUT: Duration = Duration::from_secs(10);
+
 impl ControlRoomState {
     pub fn new() -> Self {
+        let video_manager = Arc::new(VideoManager::new());
+        video_manager.cleanup_snapshot_temp_files();
+
         Self {
             config: Arc::new(RwLock::new(ControlRoomConfig::default())),
+            config_issues: Arc::new(RwLock::new(Vec::new())),
             process_manager: Arc::new(ControlRoomProcessManager::new(5000)),
             runner_manager: Arc::new(RunnerManager::new()),
-            video_manager: Arc::new(VideoManager::new()),
+            video_manager,
+            watch_manager: Arc::new(WatchManager::new()),
+            follow_manager: Arc::new(FollowManager::new()),
+            recent_files: Arc::new(RecentFilesManager::new()),
+            shutdown_token: CancellationToken::new(),
+            initialized: AtomicBool::new(false),
+            config_loaded: AtomicBool::new(false),
+            checksum_tokens: Mutex::new(HashMap::new()),
+            last_known_checksum: Mutex::new(None),
         }
     }
 
+    pub fn is_initialized(&self) -> bool {
+        self.initialized.load(Ordering::SeqCst)
+    }
+
+    /// Marks the state as initialized, returning whether it was already initialized
+    /// before this call (so the caller only fires the ready event once).
+    pub fn mark_initialized(&self) -> bool {
+        self.initialized.swap(true, Ordering::SeqCst)
+    }
+
+    /// Whether `config` has been populated by a real `load_config`/`reload_config`
+    /// call yet. `ensure_config` uses this to skip redundant file reads.
+    pub fn is_config_loaded(&self) -> bool {
+        self.config_loaded.load(Ordering::SeqCst)
+    }
+
+    pub fn shutdown_token(&self) -> CancellationToken {
+        self.shutdown_token.clone()
+    }
+
+    /// Stops every service, cancels background watcher tasks, and emits a final
+    /// `controlroom://shutdown` event. Services that don't stop within
+    /// `SHUTDOWN_STOP_TIMEOUT` are killed forcibly rather than left orphaned.
+    pub async fn shutdown(&self, app: &AppHandle) {
+        let stop_result = tokio::time::timeout(
+            SHUTDOWN_STOP_TIMEOUT,
+            self.process_manager.stop_all_services(app),
+        )
+        .await;
+
+        let graceful = matches!(stop_result, Ok(Ok(_)));
+        if !graceful {
+            self.process_manager.kill_all_services().await;
+        }
+
+        self.shutdown_token.cancel();
+
+        emit_shutdown(
+            app,
+            &ControlRoomShutdownEvent {
+                graceful,
+                message: if graceful {
+                    "all services stopped".to_string()
+                } else {
+                    "services did not stop in time; forcibly killed".to_string()
+                },
+            },
+        );
+    }
+
     pub async fn load_config(&self) -> Result<ControlRoomConfig, String> {
-        let config = load_controlroom_config()?;
-        self.process_manager.set_services(config.services.clone()).await;
+        let (config, issues, checksum) = load_controlroom_config()?;
+        self.process_manager
+            .set_services(config.services.clone(), config.default_log_max_age_sec)
+            .await
+            .map_err(|errors| errors.join("; "))?;
+        {
+            let mut guard = self.config.write().await;
+            *guard = config.clone();
+        }
+        {
+            let mut guard = self.config_issues.write().await;
+            *guard = issues;
+        }
+        {
+            let mut guard = self.last_known_checksum.lock().await;
+            *guard = Some(checksum);
+        }
+        self.config_loaded.store(true, Ordering::SeqCst);
+        Ok(config)
+    }
+
+    /// Like `load_config`, but preserves the uptime of services whose `start` spec
+    /// hasn't changed: only services removed from the config are stopped, and only
+    /// services whose `start` spec changed are restarted. Everything else is left
+    /// running untouched.
+    pub async fn reload_config(&self, app: &AppHandle) -> Result<ControlRoomConfig, String> {
+        let old_config = self.get_config().await;
+        let old_workspace_ids: HashSet<String> =
+            old_config.workspaces.iter().map(|workspace| workspace.id.clone()).collect();
+        let old_services: HashMap<String, ServiceConfig> = old_config
+            .services
+            .into_iter()
+            .map(|service| (service.id.clone(), service))
+            .collect();
+
+        let (config, issues, checksum) = load_controlroom_config()?;
+
+        let new_ids: HashSet<&str> = config.services.iter().map(|service| service.id.as_str()).collect();
+        let removed_ids: Vec<String> = old_services
+            .keys()
+            .filter(|id| !new_ids.contains(id.as_str()))
+            .cloned()
+            .collect();
+        let changed_ids: Vec<String> = config
+            .services
+            .iter()
+            .filter(|service| {
+                old_services
+                    .get(&service.id)
+                    .is_some_and(|old| old.start != service.start)
+            })
+            .map(|service| service.id.clone())
+            .collect();
+
+        for service_id in &removed_ids {
+            let _ = self.process_manager.stop_service(app, service_id).await;
+        }
+
+        self.process_manager
+            .set_services(config.services.clone(), config.default_log_max_age_sec)
+            .await
+            .map_err(|errors| errors.join("; "))?;
+
+        for service_id in &changed_ids {
+            let _ = self.process_manager.stop_service(app, service_id).await;
+            let _ = self.process_manager.start_service(app, service_id).await;
+        }
+
+        let new_workspace_ids: HashSet<&str> =
+            config.workspaces.iter().map(|workspace| workspace.id.as_str()).collect();
+        for workspace_id in old_workspace_ids.iter().filter(|id| !new_workspace_ids.contains(id.as_str())) {
+            self.watch_manager.stop_workspace_watches(workspace_id).await;
+            self.follow_manager.stop_workspace_follows(workspace_id).await;
+        }
+
         {
             let mut guard = self.config.write().await;
             *guard = config.clone();
         }
+        {
+            let mut guard = self.config_issues.write().await;
+            *guard = issues;
+        }
+        {
+            let mut guard = self.last_known_checksum.lock().await;
+            *guard = Some(checksum);
+        }
+        self.config_loaded.store(true, Ordering::SeqCst);
+
+        emit_config_reloaded(app, &config);
         Ok(config)
     }
 
+    /// Persists `config` back to the base config file, refusing to overwrite it if it
+    /// was modified externally (e.g. by a text editor) since the last successful
+    /// `load_config`/`reload_config`. Callers should `reload_config` and retry on
+    /// failure rather than forcing the write.
+    ///
+    /// `config` is first passed through `restore_redacted_secrets` against the config
+    /// currently held in state: every read path the frontend can see hands back a
+    /// redacted copy with secrets replaced by `"***"`, so a settings-editor round trip
+    /// that loads, tweaks an unrelated field, and saves would otherwise permanently
+    /// overwrite real secrets on disk with that placeholder.
+    pub async fn save_config(&self, config: &ControlRoomConfig) -> Result<(), String> {
+        let mut config = config.clone();
+        restore_redacted_secrets(&mut config, &*self.config.read().await);
+
+        let expected = *self.last_known_checksum.lock().await;
+        let new_checksum = save_controlroom_config(&config, expected)?;
+        let mut guard = self.last_known_checksum.lock().await;
+        *guard = Some(new_checksum);
+        Ok(())
+    }
+
     pub async fn get_config(&self) -> ControlRoomConfig {
         self.config.read().await.clone()
     }
 
+    pub async fn config_issues(&self) -> Vec<ConfigIssue> {
+        self.config_issues.read().await.clone()
+    }
+
     pub fn process_manager(&self) -> Arc<ControlRoomProcessManager> {
         self.process_manager.clone()
     }
@@ -58,4 +266,44 @@ impl ControlRoomState {
     pub fn video_manager(&self) -> Arc<VideoManager> {
         self.video_manager.clone()
     }
+
+    pub fn watch_manager(&self) -> Arc<WatchManager> {
+        self.watch_manager.clone()
+    }
+
+    pub fn follow_manager(&self) -> Arc<FollowManager> {
+        self.follow_manager.clone()
+    }
+
+    pub fn recent_files(&self) -> Arc<RecentFilesManager> {
+        self.recent_files.clone()
+    }
+
+    /// Registers a fresh cancellation token for `operation_id`, overwriting any prior
+    /// token registered under the same id.
+    pub async fn register_checksum_token(&self, operation_id: &str) -> CancellationToken {
+        let token = CancellationToken::new();
+        let mut tokens = self.checksum_tokens.lock().await;
+        tokens.insert(operation_id.to_string(), token.clone());
+        token
+    }
+
+    /// Removes `operation_id`'s token once the operation it guarded has finished.
+    pub async fn unregister_checksum_token(&self, operation_id: &str) {
+        let mut tokens = self.checksum_tokens.lock().await;
+        tokens.remove(operation_id);
+    }
+
+    /// Cancels the in-flight checksum operation registered under `operation_id`, if any.
+    /// Returns whether a matching operation was found.
+    pub async fn cancel_checksum(&self, operation_id: &str) -> bool {
+        let tokens = self.checksum_tokens.lock().await;
+        match tokens.get(operation_id) {
+            Some(token) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
 }
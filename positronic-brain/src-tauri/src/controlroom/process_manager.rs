@@ -1,23 +1,62 @@
 use crate::controlroom::events::{emit_backend_error, emit_service_log, emit_service_state};
 use crate::controlroom::types::{
-    SafeCommandSpec, ServiceConfig, ServiceLogEvent, ServiceState, ServiceStatus,
+    HealthCheckResult, LogExportFormat, LogExportQuery, LogExportSummary, SafeCommandSpec,
+    ServiceConfig, ServiceHealthSpec, ServiceLogEvent, ServiceLogFilter, ServiceLogStats,
+    ServiceState, ServiceStatus, ServiceStatusSummary,
 };
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+#[cfg(unix)]
+use std::os::unix::process::ExitStatusExt;
 use std::path::PathBuf;
 use std::process::Stdio;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use sysinfo::{Pid, ProcessesToUpdate, System};
 use tauri::AppHandle;
 use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
 use tokio::process::{Child, Command};
 use tokio::sync::{Mutex, RwLock};
 
+const MAX_HEALTH_HISTORY_PER_SERVICE: usize = 60;
+
+/// Rejects configs that would otherwise silently misbehave once collapsed into the
+/// `id`-keyed service map: empty/duplicate ids, blank names, or a `start` command with
+/// no program to run. Collects every violation instead of stopping at the first so a
+/// bad config file can be fixed in one pass.
+fn validate_services(services: &[ServiceConfig]) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+    let mut seen_ids = HashSet::new();
+
+    for service in services {
+        if service.id.trim().is_empty() {
+            errors.push(format!("service {:?} has an empty id", service.name));
+            continue;
+        }
+        if !seen_ids.insert(service.id.as_str()) {
+            errors.push(format!("duplicate service id: {}", service.id));
+        }
+        if service.name.trim().is_empty() {
+            errors.push(format!("service {:?} has an empty name", service.id));
+        }
+        if service.start.program.trim().is_empty() {
+            errors.push(format!("service {:?} has an empty start.program", service.id));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 #[derive(Debug)]
 struct ServiceRuntime {
     status: ServiceStatus,
     child: Option<Arc<Mutex<Child>>>,
     started_at: Option<SystemTime>,
     logs: VecDeque<ServiceLogEvent>,
+    health_history: VecDeque<HealthCheckResult>,
 }
 
 impl ServiceRuntime {
@@ -27,6 +66,7 @@ impl ServiceRuntime {
             child: None,
             started_at: None,
             logs: VecDeque::new(),
+            health_history: VecDeque::new(),
         }
     }
 }
@@ -36,6 +76,7 @@ pub struct ControlRoomProcessManager {
     services: RwLock<HashMap<String, ServiceConfig>>,
     runtimes: Mutex<HashMap<String, ServiceRuntime>>,
     max_logs_per_service: usize,
+    default_log_max_age_sec: RwLock<Option<u64>>,
 }
 
 impl ControlRoomProcessManager {
@@ -44,10 +85,17 @@ impl ControlRoomProcessManager {
             services: RwLock::new(HashMap::new()),
             runtimes: Mutex::new(HashMap::new()),
             max_logs_per_service,
+            default_log_max_age_sec: RwLock::new(None),
         }
     }
 
-    pub async fn set_services(&self, services: Vec<ServiceConfig>) {
+    pub async fn set_services(
+        &self,
+        services: Vec<ServiceConfig>,
+        default_log_max_age_sec: Option<u64>,
+    ) -> Result<(), Vec<String>> {
+        validate_services(&services)?;
+
         let mut service_map = HashMap::new();
         for service in services {
             service_map.insert(service.id.clone(), service);
@@ -57,6 +105,10 @@ impl ControlRoomProcessManager {
             let mut guard = self.services.write().await;
             *guard = service_map.clone();
         }
+        {
+            let mut guard = self.default_log_max_age_sec.write().await;
+            *guard = default_log_max_age_sec;
+        }
 
         let mut runtimes = self.runtimes.lock().await;
         runtimes.retain(|service_id, _| service_map.contains_key(service_id));
@@ -65,6 +117,14 @@ impl ControlRoomProcessManager {
                 .entry(service_id.clone())
                 .or_insert_with(|| ServiceRuntime::new(service_id));
         }
+        Ok(())
+    }
+
+    async fn log_max_age_ms(&self, service: &ServiceConfig) -> Option<u64> {
+        let max_age_sec = service
+            .log_max_age_sec
+            .or(*self.default_log_max_age_sec.read().await);
+        max_age_sec.map(|sec| sec.saturating_mul(1000))
     }
 
     pub async fn get_services(&self) -> Vec<ServiceConfig> {
@@ -167,7 +227,28 @@ impl ControlRoomProcessManager {
             || lower.contains("all slots are idle")
     }
 
-    fn detect_level(line: &str, stream: &str) -> String {
+    #[cfg(unix)]
+    fn signal_name(status: &std::process::ExitStatus) -> Option<String> {
+        status.signal().map(|signal| match signal {
+            2 => "SIGINT".to_string(),
+            3 => "SIGQUIT".to_string(),
+            4 => "SIGILL".to_string(),
+            6 => "SIGABRT".to_string(),
+            8 => "SIGFPE".to_string(),
+            9 => "SIGKILL".to_string(),
+            11 => "SIGSEGV".to_string(),
+            13 => "SIGPIPE".to_string(),
+            15 => "SIGTERM".to_string(),
+            other => format!("SIG{other}"),
+        })
+    }
+
+    #[cfg(not(unix))]
+    fn signal_name(_status: &std::process::ExitStatus) -> Option<String> {
+        None
+    }
+
+    pub fn detect_level(line: &str, stream: &str) -> String {
         let lower = line.trim().to_lowercase();
         if lower.is_empty() {
             return "info".to_string();
@@ -195,6 +276,16 @@ impl ControlRoomProcessManager {
         "info".to_string()
     }
 
+    /// A line "looks like" a structured JSON log record when it parses as a JSON
+    /// object, which is the shape every JSON logging library we've seen emits per line.
+    fn looks_like_json_log(line: &str) -> bool {
+        let trimmed = line.trim();
+        trimmed.starts_with('{')
+            && serde_json::from_str::<serde_json::Value>(trimmed)
+                .map(|value| value.is_object())
+                .unwrap_or(false)
+    }
+
     fn resolve_cwd(service_cwd: Option<&str>, cmd_cwd: Option<&str>) -> Option<PathBuf> {
         if let Some(cwd) = cmd_cwd {
             return Some(PathBuf::from(cwd));
@@ -203,9 +294,19 @@ impl ControlRoomProcessManager {
     }
 
     fn build_command(spec: &SafeCommandSpec, service_cwd: Option<&str>) -> Result<Command, String> {
+        Self::build_command_with_env(spec, service_cwd, false, &[])
+    }
+
+    fn build_command_with_env(
+        spec: &SafeCommandSpec,
+        service_cwd: Option<&str>,
+        inherit_env: bool,
+        env_remove: &[String],
+    ) -> Result<Command, String> {
         if spec.program.trim().is_empty() {
             return Err("command program cannot be empty".to_string());
         }
+        spec.validate()?;
 
         let mut command = Command::new(&spec.program);
         command.args(&spec.args);
@@ -217,6 +318,16 @@ impl ControlRoomProcessManager {
             command.current_dir(cwd);
         }
 
+        // Tokio's Command already inherits the full parent environment by default;
+        // this call documents and pins that behavior so explicit overrides below win.
+        if inherit_env {
+            command.envs(std::env::vars());
+        }
+
+        for key in env_remove {
+            command.env_remove(key);
+        }
+
         if let Some(envs) = &spec.env {
             command.envs(envs);
         }
@@ -224,6 +335,61 @@ impl ControlRoomProcessManager {
         Ok(command)
     }
 
+    async fn run_http_probe(spec: &ServiceHealthSpec) -> Result<(), String> {
+        let url = spec
+            .http_url
+            .as_deref()
+            .ok_or_else(|| "http health check requires http_url".to_string())?;
+        let method = spec.http_method.as_deref().unwrap_or("GET");
+        let (min_status, max_status) = spec.http_expected_status.unwrap_or((200, 299));
+
+        let method = reqwest::Method::from_bytes(method.as_bytes())
+            .map_err(|e| format!("invalid http health check method {method}: {e}"))?;
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(2))
+            .build()
+            .map_err(|e| format!("failed to build http health check client: {e}"))?;
+
+        let mut request = client.request(method, url);
+        if let Some(headers) = &spec.http_headers {
+            for (key, value) in headers {
+                request = request.header(key, value);
+            }
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| format!("http health check to {url} failed: {e}"))?;
+
+        let status = response.status().as_u16();
+        if status >= min_status && status <= max_status {
+            Ok(())
+        } else {
+            Err(format!(
+                "http health check to {url} returned status {status}, expected {min_status}-{max_status}"
+            ))
+        }
+    }
+
+    async fn run_tcp_probe(spec: &ServiceHealthSpec) -> Result<(), String> {
+        let host = spec.tcp_host.as_deref().unwrap_or("127.0.0.1");
+        let port = spec
+            .tcp_port
+            .ok_or_else(|| "tcp health check requires tcp_port".to_string())?;
+
+        tokio::time::timeout(
+            Duration::from_secs(2),
+            tokio::net::TcpStream::connect((host, port)),
+        )
+        .await
+        .map_err(|_| format!("tcp health check to {host}:{port} timed out"))?
+        .map_err(|e| format!("tcp health check to {host}:{port} failed: {e}"))?;
+
+        Ok(())
+    }
+
     async fn run_oneshot_command(spec: &SafeCommandSpec, service_cwd: Option<&str>) -> Result<(), String> {
         let mut command = Self::build_command(spec, service_cwd)?;
         let output = command
@@ -243,16 +409,45 @@ impl ControlRoomProcessManager {
         }
     }
 
-    async fn append_log(&self, event: ServiceLogEvent) {
+    async fn append_log(&self, event: ServiceLogEvent, max_age_ms: Option<u64>) {
         let mut runtimes = self.runtimes.lock().await;
         if let Some(runtime) = runtimes.get_mut(&event.service_id) {
             runtime.logs.push_back(event);
             while runtime.logs.len() > self.max_logs_per_service {
                 runtime.logs.pop_front();
             }
+
+            if let Some(max_age_ms) = max_age_ms {
+                let now = Self::now_ms();
+                while runtime
+                    .logs
+                    .front()
+                    .is_some_and(|entry| now.saturating_sub(entry.ts) > max_age_ms)
+                {
+                    runtime.logs.pop_front();
+                }
+            }
         }
     }
 
+    async fn record_health_result(&self, service_id: &str, result: HealthCheckResult) {
+        let mut runtimes = self.runtimes.lock().await;
+        if let Some(runtime) = runtimes.get_mut(service_id) {
+            runtime.health_history.push_back(result);
+            while runtime.health_history.len() > MAX_HEALTH_HISTORY_PER_SERVICE {
+                runtime.health_history.pop_front();
+            }
+        }
+    }
+
+    pub async fn get_health_history(&self, service_id: &str) -> Result<Vec<HealthCheckResult>, String> {
+        let runtimes = self.runtimes.lock().await;
+        let runtime = runtimes
+            .get(service_id)
+            .ok_or_else(|| format!("unknown service: {service_id}"))?;
+        Ok(runtime.health_history.iter().cloned().collect())
+    }
+
     async fn mark_status(&self, app: &AppHandle, status: ServiceStatus) {
         {
             let mut runtimes = self.runtimes.lock().await;
@@ -260,7 +455,7 @@ impl ControlRoomProcessManager {
                 .entry(status.service_id.clone())
                 .or_insert_with(|| ServiceRuntime::new(&status.service_id));
             runtime.status = status.clone();
-            if runtime.status.state != ServiceState::Running {
+            if runtime.status.state != ServiceState::Running && runtime.status.state != ServiceState::Paused {
                 runtime.started_at = None;
                 runtime.status.uptime_sec = None;
             }
@@ -274,6 +469,7 @@ impl ControlRoomProcessManager {
         service_id: String,
         stream: &'static str,
         reader: R,
+        max_age_ms: Option<u64>,
     ) where
         R: AsyncRead + Unpin + Send + 'static,
     {
@@ -282,6 +478,7 @@ impl ControlRoomProcessManager {
             let correlation_id = format!("service:{service_id}");
             let mut lines = BufReader::new(reader).lines();
             while let Ok(Some(line)) = lines.next_line().await {
+                let raw = Self::looks_like_json_log(&line).then(|| line.clone());
                 let event = ServiceLogEvent {
                     service_id: service_id.clone(),
                     stream: stream.to_string(),
@@ -289,9 +486,10 @@ impl ControlRoomProcessManager {
                     level: Self::detect_level(&line, stream),
                     line,
                     correlation_id: Some(correlation_id.clone()),
+                    raw,
                 };
 
-                manager.append_log(event.clone()).await;
+                manager.append_log(event.clone(), max_age_ms).await;
                 emit_service_log(&app, &event);
             }
         });
@@ -308,13 +506,14 @@ impl ControlRoomProcessManager {
                     match guard.try_wait() {
                         Ok(status) => status,
                         Err(error) => {
-                            emit_backend_error(&app, "service-watcher", error.to_string());
+                            emit_backend_error(&app, "service-watcher", error.to_string(), Some(correlation_id.clone()));
                             None
                         }
                     }
                 };
 
                 if let Some(exit_status) = exit {
+                    let signal = Self::signal_name(&exit_status);
                     let next = ServiceStatus {
                         service_id: service_id.clone(),
                         state: if exit_status.success() {
@@ -326,10 +525,14 @@ impl ControlRoomProcessManager {
                         uptime_sec: None,
                         last_error: if exit_status.success() {
                             None
+                        } else if let Some(signal) = &signal {
+                            Some(format!("process terminated by signal {signal}"))
                         } else {
                             Some(format!("process exited with code {:?}", exit_status.code()))
                         },
                         correlation_id: Some(correlation_id.clone()),
+                        cpu_percent: None,
+                        memory_rss_bytes: None,
                     };
 
                     {
@@ -348,6 +551,62 @@ impl ControlRoomProcessManager {
         });
     }
 
+    /// Periodically samples CPU%/RSS for a running service every 5 seconds via
+    /// `refresh_process_metrics` and broadcasts the updated status, so a live resource
+    /// view stays fresh without the frontend having to poll. Also sweeps for zombie
+    /// processes on the same cadence via `reap_zombies`. Stops once the service's pid
+    /// changes (it was restarted) or it no longer has one (it stopped or exited).
+    fn spawn_metrics_poller(self: &Arc<Self>, app: AppHandle, service_id: String, pid: u32) {
+        let manager = self.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+
+                manager.reap_zombies(&app).await;
+
+                let current_pid = {
+                    let runtimes = manager.runtimes.lock().await;
+                    match runtimes.get(&service_id) {
+                        Some(runtime) => runtime.status.pid,
+                        None => break,
+                    }
+                };
+                if current_pid != Some(pid) {
+                    break;
+                }
+
+                match manager.refresh_process_metrics(&service_id).await {
+                    Some(status) => emit_service_state(&app, &status),
+                    None => break,
+                }
+            }
+        });
+    }
+
+    /// Samples CPU% and RSS for `service_id`'s current pid via `sysinfo`, refreshing only
+    /// that one process rather than the whole process table, and stores the result on its
+    /// `ServiceRuntime::status`. Returns `None` if the service is unknown or has no pid to
+    /// sample.
+    pub async fn refresh_process_metrics(&self, service_id: &str) -> Option<ServiceStatus> {
+        let pid = {
+            let runtimes = self.runtimes.lock().await;
+            runtimes.get(service_id)?.status.pid?
+        };
+
+        let sysinfo_pid = Pid::from_u32(pid);
+        let mut system = System::new();
+        system.refresh_processes(ProcessesToUpdate::Some(&[sysinfo_pid]), true);
+        let process = system.process(sysinfo_pid)?;
+        let cpu_percent = process.cpu_usage();
+        let memory_rss_bytes = process.memory();
+
+        let mut runtimes = self.runtimes.lock().await;
+        let runtime = runtimes.get_mut(service_id)?;
+        runtime.status.cpu_percent = Some(cpu_percent);
+        runtime.status.memory_rss_bytes = Some(memory_rss_bytes);
+        Some(runtime.status.clone())
+    }
+
     async fn refresh_status_if_needed(&self, service_id: &str) -> Option<ServiceStatus> {
         let mut runtimes = self.runtimes.lock().await;
         let runtime = runtimes.get_mut(service_id)?;
@@ -362,6 +621,7 @@ impl ControlRoomProcessManager {
 
             match wait_result {
                 Ok(Some(exit)) => {
+                    let signal = Self::signal_name(&exit);
                     runtime.child = None;
                     runtime.started_at = None;
                     runtime.status.state = if exit.success() {
@@ -373,6 +633,8 @@ impl ControlRoomProcessManager {
                     runtime.status.uptime_sec = None;
                     runtime.status.last_error = if exit.success() {
                         None
+                    } else if let Some(signal) = &signal {
+                        Some(format!("process terminated by signal {signal}"))
                     } else {
                         Some(format!("process exited with code {:?}", exit.code()))
                     };
@@ -411,11 +673,35 @@ impl ControlRoomProcessManager {
                 uptime_sec: None,
                 last_error: None,
                 correlation_id: Some(format!("service:{service_id}")),
+                cpu_percent: None,
+                memory_rss_bytes: None,
             },
         )
         .await;
 
-        let mut command = Self::build_command(&service.start, service.cwd.as_deref())?;
+        if let Some(pre_start) = &service.pre_start {
+            if let Err(error) = Self::run_oneshot_command(pre_start, service.cwd.as_deref()).await {
+                let failure = ServiceStatus {
+                    service_id: service_id.to_string(),
+                    state: ServiceState::Error,
+                    pid: None,
+                    uptime_sec: None,
+                    last_error: Some(format!("pre_start failed: {error}")),
+                    correlation_id: Some(format!("service:{service_id}")),
+                    cpu_percent: None,
+                    memory_rss_bytes: None,
+                };
+                self.mark_status(app, failure.clone()).await;
+                return Err(format!("pre_start failed for service {}: {error}", service.name));
+            }
+        }
+
+        let mut command = Self::build_command_with_env(
+            &service.start,
+            service.cwd.as_deref(),
+            service.inherit_env.unwrap_or(false),
+            service.env_remove.as_deref().unwrap_or(&[]),
+        )?;
         let mut child = command
             .spawn()
             .map_err(|e| format!("failed to spawn service {}: {e}", service.name))?;
@@ -425,6 +711,17 @@ impl ControlRoomProcessManager {
         let stderr = child.stderr.take();
         let child = Arc::new(Mutex::new(child));
 
+        let starting_status = ServiceStatus {
+            service_id: service_id.to_string(),
+            state: ServiceState::Starting,
+            pid,
+            uptime_sec: None,
+            last_error: None,
+            correlation_id: Some(format!("service:{service_id}")),
+            cpu_percent: None,
+            memory_rss_bytes: None,
+        };
+
         {
             let mut runtimes = self.runtimes.lock().await;
             let runtime = runtimes
@@ -432,24 +729,36 @@ impl ControlRoomProcessManager {
                 .or_insert_with(|| ServiceRuntime::new(service_id));
             runtime.child = Some(child.clone());
             runtime.started_at = Some(SystemTime::now());
-            runtime.status = ServiceStatus {
-                service_id: service_id.to_string(),
-                state: ServiceState::Running,
-                pid,
-                uptime_sec: Some(0),
-                last_error: None,
-                correlation_id: Some(format!("service:{service_id}")),
-            };
+            runtime.status = starting_status.clone();
         }
 
+        let log_max_age_ms = self.log_max_age_ms(&service).await;
+
         if let Some(stdout) = stdout {
-            self.spawn_log_reader(app.clone(), service_id.to_string(), "stdout", stdout);
+            self.spawn_log_reader(app.clone(), service_id.to_string(), "stdout", stdout, log_max_age_ms);
         }
         if let Some(stderr) = stderr {
-            self.spawn_log_reader(app.clone(), service_id.to_string(), "stderr", stderr);
+            self.spawn_log_reader(app.clone(), service_id.to_string(), "stderr", stderr, log_max_age_ms);
         }
 
-        self.spawn_exit_watcher(app.clone(), service_id.to_string(), child);
+        self.spawn_exit_watcher(app.clone(), service_id.to_string(), child.clone());
+
+        if let Some(pid) = pid {
+            self.spawn_metrics_poller(app.clone(), service_id.to_string(), pid);
+        }
+
+        if let Some(ready_probe) = service.ready_probe.clone() {
+            self.spawn_ready_probe(
+                app.clone(),
+                service_id.to_string(),
+                child,
+                ready_probe,
+                service.startup_timeout_ms.unwrap_or(30_000),
+                pid,
+            );
+            emit_service_state(app, &starting_status);
+            return Ok(starting_status);
+        }
 
         let status = ServiceStatus {
             service_id: service_id.to_string(),
@@ -458,11 +767,100 @@ impl ControlRoomProcessManager {
             uptime_sec: Some(0),
             last_error: None,
             correlation_id: Some(format!("service:{service_id}")),
+            cpu_percent: None,
+            memory_rss_bytes: None,
         };
-        emit_service_state(app, &status);
+        self.mark_status(app, status.clone()).await;
         Ok(status)
     }
 
+    fn spawn_ready_probe(
+        self: &Arc<Self>,
+        app: AppHandle,
+        service_id: String,
+        child: Arc<Mutex<Child>>,
+        ready_probe: ServiceHealthSpec,
+        startup_timeout_ms: u64,
+        pid: Option<u32>,
+    ) {
+        let manager = self.clone();
+        let interval = Duration::from_secs(ready_probe.interval_sec.unwrap_or(2));
+        let deadline = Duration::from_millis(startup_timeout_ms);
+
+        tokio::spawn(async move {
+            let started = tokio::time::Instant::now();
+            let correlation_id = format!("service:{service_id}");
+
+            loop {
+                let probe_started = tokio::time::Instant::now();
+                let probe_result = if ready_probe.check_type.as_deref() == Some("tcp") {
+                    Self::run_tcp_probe(&ready_probe).await
+                } else if ready_probe.check_type.as_deref() == Some("http") {
+                    Self::run_http_probe(&ready_probe).await
+                } else {
+                    let probe_spec = SafeCommandSpec {
+                        program: ready_probe.program.clone(),
+                        args: ready_probe.args.clone(),
+                        cwd: None,
+                        env: None,
+                    };
+                    Self::run_oneshot_command(&probe_spec, None).await
+                };
+                let healthy = probe_result.is_ok();
+
+                manager
+                    .record_health_result(
+                        &service_id,
+                        HealthCheckResult {
+                            ts: Self::now_ms(),
+                            ok: healthy,
+                            latency_ms: probe_started.elapsed().as_millis() as u64,
+                            message: probe_result.err(),
+                        },
+                    )
+                    .await;
+
+                if healthy {
+                    let status = ServiceStatus {
+                        service_id: service_id.clone(),
+                        state: ServiceState::Running,
+                        pid,
+                        uptime_sec: Some(0),
+                        last_error: None,
+                        correlation_id: Some(correlation_id.clone()),
+                        cpu_percent: None,
+                        memory_rss_bytes: None,
+                    };
+                    manager.mark_status(&app, status).await;
+                    return;
+                }
+
+                if started.elapsed() >= deadline {
+                    let error = format!("ready probe timed out after {startup_timeout_ms}ms");
+                    {
+                        let mut guard = child.lock().await;
+                        let _ = guard.start_kill();
+                        let _ = guard.wait().await;
+                    }
+                    let status = ServiceStatus {
+                        service_id: service_id.clone(),
+                        state: ServiceState::Error,
+                        pid: None,
+                        uptime_sec: None,
+                        last_error: Some(error),
+                        correlation_id: Some(correlation_id.clone()),
+                        cpu_percent: None,
+                        memory_rss_bytes: None,
+                    };
+                    manager.mark_status(&app, status).await;
+                    return;
+                }
+
+                tokio::time::sleep(interval).await;
+            }
+        });
+    }
+
     pub async fn stop_service(self: &Arc<Self>, app: &AppHandle, service_id: &str) -> Result<ServiceStatus, String> {
         let service = self.get_service(service_id).await?;
 
@@ -475,13 +873,15 @@ impl ControlRoomProcessManager {
                 uptime_sec: None,
                 last_error: None,
                 correlation_id: Some(format!("service:{service_id}")),
+                cpu_percent: None,
+                memory_rss_bytes: None,
             },
         )
         .await;
 
         if let Some(stop_spec) = &service.stop {
             if let Err(error) = Self::run_oneshot_command(stop_spec, service.cwd.as_deref()).await {
-                emit_backend_error(app, "service-stop-cmd", error);
+                emit_backend_error(app, "service-stop-cmd", error, Some(format!("service:{service_id}")));
             }
         }
 
@@ -498,6 +898,12 @@ impl ControlRoomProcessManager {
             let _ = tokio::time::timeout(Duration::from_secs(4), guard.wait()).await;
         }
 
+        if let Some(post_stop) = &service.post_stop {
+            if let Err(error) = Self::run_oneshot_command(post_stop, service.cwd.as_deref()).await {
+                emit_backend_error(app, "service-post-stop-cmd", error, Some(format!("service:{service_id}")));
+            }
+        }
+
         let status = ServiceStatus {
             service_id: service_id.to_string(),
             state: ServiceState::Stopped,
@@ -505,6 +911,8 @@ impl ControlRoomProcessManager {
             uptime_sec: None,
             last_error: None,
             correlation_id: Some(format!("service:{service_id}")),
+            cpu_percent: None,
+            memory_rss_bytes: None,
         };
 
         self.mark_status(app, status.clone()).await;
@@ -516,6 +924,154 @@ impl ControlRoomProcessManager {
         self.start_service(app, service_id).await
     }
 
+    /// Services belonging to `tier`, in the order `get_services` returned them
+    /// (sorted by name, case-insensitively), which is also the order
+    /// `rolling_restart_by_tier` chunks into concurrency-sized batches.
+    fn services_in_tier(services: Vec<ServiceConfig>, tier: &str) -> Vec<ServiceConfig> {
+        services.into_iter().filter(|service| service.tier.as_deref() == Some(tier)).collect()
+    }
+
+    /// Restarts every service in `tier`, batching them into groups of `concurrency` and
+    /// waiting for each batch to reach `Running` (or a fixed timeout) before starting the
+    /// next batch, so at most `concurrency` services in the tier are down at any moment.
+    /// Each service's outcome is reported independently rather than aborting the whole
+    /// rollout on the first failure, since one stuck service shouldn't block its siblings.
+    pub async fn rolling_restart_by_tier(
+        self: &Arc<Self>,
+        app: &AppHandle,
+        tier: &str,
+        concurrency: usize,
+    ) -> Vec<(String, Result<ServiceStatus, String>)> {
+        const READY_TIMEOUT_MS: u64 = 30_000;
+        const READY_POLL_INTERVAL_MS: u64 = 200;
+
+        let tier_services = Self::services_in_tier(self.get_services().await, tier);
+
+        let mut results = Vec::new();
+        for batch in tier_services.chunks(concurrency.max(1)) {
+            let handles = batch
+                .iter()
+                .map(|service| {
+                    let manager = self.clone();
+                    let app = app.clone();
+                    let service_id = service.id.clone();
+                    tokio::spawn(async move {
+                        match manager.restart_service(&app, &service_id).await {
+                            Ok(_) => {
+                                manager
+                                    .await_running(&app, &service_id, READY_TIMEOUT_MS, READY_POLL_INTERVAL_MS)
+                                    .await
+                            }
+                            Err(error) => Err(error),
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            for (service, handle) in batch.iter().zip(handles) {
+                let outcome = handle
+                    .await
+                    .unwrap_or_else(|e| Err(format!("restart task join error for {}: {e}", service.id)));
+                results.push((service.id.clone(), outcome));
+            }
+        }
+
+        results
+    }
+
+    /// Polls `service_status` until it reports `Running` or `timeout_ms` elapses,
+    /// whichever comes first. Lets `rolling_restart_by_tier` treat the readiness probe
+    /// (started asynchronously by `start_service`) as part of "restart finished".
+    async fn await_running(
+        self: &Arc<Self>,
+        app: &AppHandle,
+        service_id: &str,
+        timeout_ms: u64,
+        poll_interval_ms: u64,
+    ) -> Result<ServiceStatus, String> {
+        let deadline = tokio::time::Instant::now() + Duration::from_millis(timeout_ms);
+        loop {
+            let status = self.service_status(app, service_id).await?;
+            if status.state == ServiceState::Running {
+                return Ok(status);
+            }
+            if status.state == ServiceState::Error {
+                return Err(status
+                    .last_error
+                    .unwrap_or_else(|| format!("service {service_id} failed to start")));
+            }
+            if tokio::time::Instant::now() >= deadline {
+                return Err(format!("service {service_id} did not reach Running within {timeout_ms}ms"));
+            }
+            tokio::time::sleep(Duration::from_millis(poll_interval_ms)).await;
+        }
+    }
+
+    /// Freezes a running service's process with `SIGSTOP` without killing it, so a
+    /// resource-hungry service can be paused mid-debug and resumed exactly where it
+    /// left off. Unix only: Windows has no process-wide equivalent (`SuspendThread`/
+    /// `ResumeThread` operate per-thread, not per-process), so this is unsupported there.
+    #[cfg(unix)]
+    pub async fn pause_service(self: &Arc<Self>, app: &AppHandle, service_id: &str) -> Result<ServiceStatus, String> {
+        let mut status = {
+            let runtimes = self.runtimes.lock().await;
+            let runtime = runtimes.get(service_id).ok_or_else(|| format!("unknown service: {service_id}"))?;
+            if runtime.status.state != ServiceState::Running {
+                return Err(format!("service {service_id} is not running"));
+            }
+            runtime.status.clone()
+        };
+        let pid = status.pid.ok_or_else(|| format!("service {service_id} has no pid"))?;
+        Self::send_unix_signal(pid, "STOP").await?;
+
+        status.state = ServiceState::Paused;
+        self.mark_status(app, status.clone()).await;
+        Ok(status)
+    }
+
+    #[cfg(not(unix))]
+    pub async fn pause_service(self: &Arc<Self>, _app: &AppHandle, _service_id: &str) -> Result<ServiceStatus, String> {
+        Err("pausing a service is not supported on this platform".to_string())
+    }
+
+    /// Resumes a service previously paused with `pause_service` by sending `SIGCONT`.
+    #[cfg(unix)]
+    pub async fn resume_service(self: &Arc<Self>, app: &AppHandle, service_id: &str) -> Result<ServiceStatus, String> {
+        let mut status = {
+            let runtimes = self.runtimes.lock().await;
+            let runtime = runtimes.get(service_id).ok_or_else(|| format!("unknown service: {service_id}"))?;
+            if runtime.status.state != ServiceState::Paused {
+                return Err(format!("service {service_id} is not paused"));
+            }
+            runtime.status.clone()
+        };
+        let pid = status.pid.ok_or_else(|| format!("service {service_id} has no pid"))?;
+        Self::send_unix_signal(pid, "CONT").await?;
+
+        status.state = ServiceState::Running;
+        self.mark_status(app, status.clone()).await;
+        Ok(status)
+    }
+
+    #[cfg(not(unix))]
+    pub async fn resume_service(self: &Arc<Self>, _app: &AppHandle, _service_id: &str) -> Result<ServiceStatus, String> {
+        Err("resuming a service is not supported on this platform".to_string())
+    }
+
+    #[cfg(unix)]
+    async fn send_unix_signal(pid: u32, signal: &str) -> Result<(), String> {
+        let output = tokio::process::Command::new("kill")
+            .arg(format!("-{signal}"))
+            .arg(pid.to_string())
+            .output()
+            .await
+            .map_err(|e| format!("failed to send SIG{signal} to pid {pid}: {e}"))?;
+        if !output.status.success() {
+            return Err(format!("kill -{signal} {pid} failed: {}", String::from_utf8_lossy(&output.stderr)));
+        }
+        Ok(())
+    }
+
     pub async fn service_status(self: &Arc<Self>, app: &AppHandle, service_id: &str) -> Result<ServiceStatus, String> {
         self.get_service(service_id).await?;
         if let Some(status) = self.refresh_status_if_needed(service_id).await {
@@ -527,7 +1083,49 @@ impl ControlRoomProcessManager {
         Ok(status)
     }
 
+    /// `refresh_status_if_needed` marks a runtime `Error` when `try_wait` itself fails,
+    /// but leaves `child` in place since it can't tell whether the process actually
+    /// exited. This sweeps every such runtime, gives `try_wait` one more chance, and
+    /// clears `child` once the exit is confirmed so the zombie doesn't linger indefinitely.
+    pub async fn reap_zombies(&self, app: &AppHandle) {
+        let candidates: Vec<(String, Arc<Mutex<Child>>)> = {
+            let runtimes = self.runtimes.lock().await;
+            runtimes
+                .iter()
+                .filter(|(_, runtime)| runtime.status.state == ServiceState::Error && runtime.child.is_some())
+                .filter_map(|(service_id, runtime)| runtime.child.clone().map(|child| (service_id.clone(), child)))
+                .collect()
+        };
+
+        for (service_id, child) in candidates {
+            let exited = {
+                let mut guard = child.lock().await;
+                guard.try_wait().ok().flatten().is_some()
+            };
+            if !exited {
+                continue;
+            }
+
+            let status = {
+                let mut runtimes = self.runtimes.lock().await;
+                match runtimes.get_mut(&service_id) {
+                    Some(runtime) => {
+                        runtime.child = None;
+                        runtime.started_at = None;
+                        runtime.status.clone()
+                    }
+                    None => continue,
+                }
+            };
+
+            tracing::warn!("reaped zombie process for service {service_id}");
+            emit_service_state(app, &status);
+        }
+    }
+
     pub async fn service_status_all(self: &Arc<Self>, app: &AppHandle) -> Result<Vec<ServiceStatus>, String> {
+        self.reap_zombies(app).await;
+
         let services = self.get_services().await;
         let mut statuses = Vec::new();
         for service in services {
@@ -536,6 +1134,147 @@ impl ControlRoomProcessManager {
         Ok(statuses)
     }
 
+    pub async fn start_all_services(self: &Arc<Self>, app: &AppHandle) -> Result<Vec<ServiceStatus>, String> {
+        let services = self.get_services().await;
+
+        let mut groups: BTreeMap<u32, Vec<ServiceConfig>> = BTreeMap::new();
+        for service in services {
+            let tier_order = service.tier_order.unwrap_or(u32::MAX);
+            groups.entry(tier_order).or_default().push(service);
+        }
+
+        let mut statuses = Vec::new();
+        for (_, group) in groups {
+            statuses.extend(self.start_service_group(app, group).await?);
+        }
+        Ok(statuses)
+    }
+
+    pub async fn stop_all_services(self: &Arc<Self>, app: &AppHandle) -> Result<Vec<ServiceStatus>, String> {
+        let services = self.get_services().await;
+
+        let handles = services
+            .iter()
+            .map(|service| {
+                let manager = self.clone();
+                let app = app.clone();
+                let service_id = service.id.clone();
+                tokio::spawn(async move { manager.stop_service(&app, &service_id).await })
+            })
+            .collect::<Vec<_>>();
+
+        let mut statuses = Vec::new();
+        for (service, handle) in services.iter().zip(handles) {
+            let status = handle
+                .await
+                .map_err(|e| format!("stop task join error for {}: {e}", service.id))??;
+            statuses.push(status);
+        }
+        Ok(statuses)
+    }
+
+    /// Forcibly kills any service still holding a live child process, bypassing `stop`/
+    /// `post_stop` hooks. Used as a last resort when a graceful stop doesn't finish in time.
+    pub async fn kill_all_services(self: &Arc<Self>) {
+        let mut runtimes = self.runtimes.lock().await;
+        for runtime in runtimes.values_mut() {
+            if let Some(child) = runtime.child.take() {
+                let mut guard = child.lock().await;
+                let _ = guard.start_kill();
+            }
+        }
+    }
+
+    /// Splits `remaining` into services whose `depends_on` are all in `started_ids`
+    /// (ready to start now) and everything else (still blocked). A service with no
+    /// `depends_on` is always ready.
+    fn partition_ready(
+        remaining: Vec<ServiceConfig>,
+        started_ids: &HashSet<String>,
+    ) -> (Vec<ServiceConfig>, Vec<ServiceConfig>) {
+        remaining.into_iter().partition(|service| {
+            service
+                .depends_on
+                .as_ref()
+                .map(|deps| deps.iter().all(|dep| started_ids.contains(dep)))
+                .unwrap_or(true)
+        })
+    }
+
+    async fn start_service_group(
+        self: &Arc<Self>,
+        app: &AppHandle,
+        mut remaining: Vec<ServiceConfig>,
+    ) -> Result<Vec<ServiceStatus>, String> {
+        let mut started_ids: HashSet<String> = HashSet::new();
+        let mut statuses = Vec::new();
+
+        while !remaining.is_empty() {
+            let (ready, blocked) = Self::partition_ready(remaining, &started_ids);
+
+            if ready.is_empty() {
+                // Unsatisfiable or circular depends_on: start whatever is left rather than deadlock.
+                for service in &blocked {
+                    started_ids.insert(service.id.clone());
+                }
+                for service in blocked {
+                    statuses.push(self.start_service(app, &service.id).await?);
+                }
+                break;
+            }
+
+            let handles = ready
+                .iter()
+                .map(|service| {
+                    let manager = self.clone();
+                    let app = app.clone();
+                    let service_id = service.id.clone();
+                    tokio::spawn(async move { manager.start_service(&app, &service_id).await })
+                })
+                .collect::<Vec<_>>();
+
+            for (service, handle) in ready.iter().zip(handles) {
+                started_ids.insert(service.id.clone());
+                let status = handle
+                    .await
+                    .map_err(|e| format!("start task join error for {}: {e}", service.id))??;
+                statuses.push(status);
+            }
+
+            remaining = blocked;
+        }
+
+        Ok(statuses)
+    }
+
+    pub async fn service_status_summary(self: &Arc<Self>) -> ServiceStatusSummary {
+        let runtimes = self.runtimes.lock().await;
+        let mut summary = ServiceStatusSummary {
+            running: 0,
+            stopped: 0,
+            starting: 0,
+            stopping: 0,
+            error: 0,
+            restarting: 0,
+            paused: 0,
+            total: 0,
+        };
+
+        for runtime in runtimes.values() {
+            match runtime.status.state {
+                ServiceState::Running => summary.running += 1,
+                ServiceState::Stopped => summary.stopped += 1,
+                ServiceState::Starting => summary.starting += 1,
+                ServiceState::Stopping => summary.stopping += 1,
+                ServiceState::Error => summary.error += 1,
+                ServiceState::Paused => summary.paused += 1,
+            }
+            summary.total += 1;
+        }
+
+        summary
+    }
+
     pub async fn clear_logs(&self, service_id: &str) -> Result<bool, String> {
         let mut runtimes = self.runtimes.lock().await;
         let runtime = runtimes
@@ -560,6 +1299,94 @@ impl ControlRoomProcessManager {
         Ok(runtime.logs.iter().skip(start).cloned().collect())
     }
 
+    pub async fn service_log_stats(&self, service_id: &str) -> Result<ServiceLogStats, String> {
+        let runtimes = self.runtimes.lock().await;
+        let runtime = runtimes
+            .get(service_id)
+            .ok_or_else(|| format!("service runtime not found: {service_id}"))?;
+        Ok(Self::compute_log_stats(service_id, &runtime.logs))
+    }
+
+    pub async fn service_log_stats_all(&self) -> Vec<ServiceLogStats> {
+        let runtimes = self.runtimes.lock().await;
+        runtimes
+            .iter()
+            .map(|(service_id, runtime)| Self::compute_log_stats(service_id, &runtime.logs))
+            .collect()
+    }
+
+    fn compute_log_stats(service_id: &str, logs: &VecDeque<ServiceLogEvent>) -> ServiceLogStats {
+        let mut by_level: HashMap<String, usize> = HashMap::new();
+        let mut oldest_ts = None;
+        let mut newest_ts = None;
+
+        for entry in logs {
+            *by_level.entry(entry.level.clone()).or_insert(0) += 1;
+            oldest_ts = Some(oldest_ts.map_or(entry.ts, |ts: u64| ts.min(entry.ts)));
+            newest_ts = Some(newest_ts.map_or(entry.ts, |ts: u64| ts.max(entry.ts)));
+        }
+
+        ServiceLogStats {
+            service_id: service_id.to_string(),
+            total: logs.len(),
+            by_level,
+            oldest_ts,
+            newest_ts,
+        }
+    }
+
+    fn matches_log_filter(event: &ServiceLogEvent, filter: &ServiceLogFilter) -> bool {
+        if let Some(service_ids) = &filter.service_ids {
+            if !service_ids.iter().any(|id| id == &event.service_id) {
+                return false;
+            }
+        }
+        if let Some(level) = &filter.level {
+            if level != &event.level {
+                return false;
+            }
+        }
+        if let Some(stream) = &filter.stream {
+            if stream != &event.stream {
+                return false;
+            }
+        }
+        if let Some(since_ts) = filter.since_ts {
+            if event.ts < since_ts {
+                return false;
+            }
+        }
+        true
+    }
+
+    pub async fn service_logs_all(
+        &self,
+        filter: Option<ServiceLogFilter>,
+        limit: Option<usize>,
+    ) -> Vec<ServiceLogEvent> {
+        let mut merged = {
+            let runtimes = self.runtimes.lock().await;
+            runtimes
+                .values()
+                .flat_map(|runtime| runtime.logs.iter().cloned())
+                .collect::<Vec<_>>()
+        };
+
+        merged.sort_by_key(|event| event.ts);
+
+        if let Some(filter) = &filter {
+            merged.retain(|event| Self::matches_log_filter(event, filter));
+        }
+
+        if let Some(limit) = limit {
+            let len = merged.len();
+            let start = len.saturating_sub(limit);
+            merged = merged.split_off(start);
+        }
+
+        merged
+    }
+
     pub async fn export_logs(&self, service_id: &str, target_path: &str) -> Result<bool, String> {
         let lines = {
             let runtimes = self.runtimes.lock().await;
@@ -602,4 +1429,276 @@ impl ControlRoomProcessManager {
 
         Ok(true)
     }
+
+    pub async fn export_logs_query(
+        &self,
+        query: &LogExportQuery,
+        target_path: &str,
+    ) -> Result<LogExportSummary, String> {
+        let mut merged = {
+            let runtimes = self.runtimes.lock().await;
+            runtimes
+                .iter()
+                .filter(|(service_id, _)| {
+                    query
+                        .service_ids
+                        .as_ref()
+                        .map(|ids| ids.iter().any(|id| id == *service_id))
+                        .unwrap_or(true)
+                })
+                .flat_map(|(_, runtime)| runtime.logs.iter().cloned())
+                .collect::<Vec<_>>()
+        };
+
+        merged.retain(|event| {
+            query
+                .since_ts
+                .map(|since| event.ts >= since)
+                .unwrap_or(true)
+                && query
+                    .until_ts
+                    .map(|until| event.ts <= until)
+                    .unwrap_or(true)
+                && query
+                    .level
+                    .as_ref()
+                    .map(|level| level == &event.level)
+                    .unwrap_or(true)
+        });
+
+        merged.sort_by_key(|event| event.ts);
+
+        let services_included = merged
+            .iter()
+            .map(|event| event.service_id.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        let body = match query.format {
+            LogExportFormat::Text => merged
+                .iter()
+                .map(|entry| {
+                    format!(
+                        "[{}] {} {} {} {}",
+                        entry.ts,
+                        entry.service_id,
+                        entry.stream,
+                        entry.level.to_uppercase(),
+                        entry.line
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            LogExportFormat::Json => serde_json::to_string_pretty(&merged)
+                .map_err(|e| format!("failed to serialize logs as JSON: {e}"))?,
+        };
+
+        let target = PathBuf::from(target_path);
+        let resolved = if target.is_absolute() {
+            target
+        } else {
+            std::env::current_dir()
+                .map_err(|e| format!("failed to read cwd: {e}"))?
+                .join(target)
+        };
+
+        if let Some(parent) = resolved.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| format!("failed to create export parent {}: {e}", parent.display()))?;
+        }
+
+        tokio::fs::write(&resolved, &body)
+            .await
+            .map_err(|e| format!("failed writing logs to {}: {e}", resolved.display()))?;
+
+        Ok(LogExportSummary {
+            lines_written: merged.len() as u64,
+            services_included,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service(id: &str, tier: Option<&str>, depends_on: Option<Vec<&str>>) -> ServiceConfig {
+        ServiceConfig {
+            id: id.to_string(),
+            name: id.to_string(),
+            tier: tier.map(str::to_string),
+            tier_order: None,
+            depends_on: depends_on.map(|deps| deps.into_iter().map(str::to_string).collect()),
+            cwd: None,
+            pre_start: None,
+            start: SafeCommandSpec {
+                program: "true".to_string(),
+                args: Vec::new(),
+                cwd: None,
+                env: None,
+            },
+            stop: None,
+            post_stop: None,
+            restart: None,
+            health: None,
+            ready_probe: None,
+            startup_timeout_ms: None,
+            log_sources: None,
+            inherit_env: None,
+            env_remove: None,
+            log_max_age_sec: None,
+        }
+    }
+
+    #[test]
+    fn validate_services_rejects_empty_and_duplicate_ids() {
+        let services = vec![
+            service("", None, None),
+            service("dup", None, None),
+            service("dup", None, None),
+        ];
+        let errors = validate_services(&services).unwrap_err();
+        assert!(errors.iter().any(|e| e.contains("empty id")));
+        assert!(errors.iter().any(|e| e.contains("duplicate service id: dup")));
+    }
+
+    #[test]
+    fn validate_services_accepts_a_well_formed_config() {
+        let services = vec![service("a", Some("core"), None), service("b", Some("core"), Some(vec!["a"]))];
+        assert!(validate_services(&services).is_ok());
+    }
+
+    #[test]
+    fn partition_ready_holds_back_services_with_unmet_dependencies() {
+        let remaining = vec![
+            service("a", None, None),
+            service("b", None, Some(vec!["a"])),
+            service("c", None, Some(vec!["missing"])),
+        ];
+        let started: HashSet<String> = HashSet::new();
+
+        let (ready, blocked) = ControlRoomProcessManager::partition_ready(remaining, &started);
+
+        assert_eq!(ready.iter().map(|s| s.id.as_str()).collect::<Vec<_>>(), vec!["a"]);
+        assert_eq!(blocked.iter().map(|s| s.id.as_str()).collect::<Vec<_>>(), vec!["b", "c"]);
+    }
+
+    #[test]
+    fn partition_ready_releases_a_dependency_once_started() {
+        let remaining = vec![service("b", None, Some(vec!["a"]))];
+        let started: HashSet<String> = ["a".to_string()].into_iter().collect();
+
+        let (ready, blocked) = ControlRoomProcessManager::partition_ready(remaining, &started);
+
+        assert_eq!(ready.len(), 1);
+        assert!(blocked.is_empty());
+    }
+
+    #[test]
+    fn services_in_tier_filters_and_preserves_order() {
+        let services =
+            vec![service("a", Some("core"), None), service("b", Some("edge"), None), service("c", Some("core"), None)];
+
+        let core = ControlRoomProcessManager::services_in_tier(services, "core");
+
+        assert_eq!(core.iter().map(|s| s.id.as_str()).collect::<Vec<_>>(), vec!["a", "c"]);
+    }
+
+    #[test]
+    fn detect_level_prefers_an_embedded_level_over_heuristics() {
+        assert_eq!(ControlRoomProcessManager::detect_level("[app] error something broke", "stdout"), "error");
+        assert_eq!(ControlRoomProcessManager::detect_level("INFO: server ready", "stdout"), "info");
+    }
+
+    #[test]
+    fn detect_level_falls_back_to_heuristics() {
+        assert_eq!(ControlRoomProcessManager::detect_level("panic: index out of bounds", "stdout"), "error");
+        assert_eq!(ControlRoomProcessManager::detect_level("this is deprecated", "stdout"), "warn");
+    }
+
+    #[test]
+    fn detect_level_treats_unrecognized_stderr_as_a_warning() {
+        assert_eq!(ControlRoomProcessManager::detect_level("plain diagnostic line", "stderr"), "warn");
+        assert_eq!(ControlRoomProcessManager::detect_level("plain diagnostic line", "stdout"), "info");
+    }
+
+    #[test]
+    fn detect_level_treats_known_informational_stderr_as_info() {
+        assert_eq!(ControlRoomProcessManager::detect_level("main: model loaded", "stderr"), "info");
+    }
+
+    #[test]
+    fn detect_level_defaults_empty_lines_to_info() {
+        assert_eq!(ControlRoomProcessManager::detect_level("   ", "stderr"), "info");
+    }
+
+    fn log_event(service_id: &str, level: &str, stream: &str, ts: u64) -> ServiceLogEvent {
+        ServiceLogEvent {
+            service_id: service_id.to_string(),
+            stream: stream.to_string(),
+            ts,
+            level: level.to_string(),
+            line: "line".to_string(),
+            correlation_id: None,
+            raw: None,
+        }
+    }
+
+    #[test]
+    fn matches_log_filter_applies_every_criterion() {
+        let event = log_event("svc-a", "error", "stderr", 100);
+
+        let matches_service = ServiceLogFilter {
+            service_ids: Some(vec!["svc-a".to_string()]),
+            level: None,
+            stream: None,
+            since_ts: None,
+        };
+        assert!(ControlRoomProcessManager::matches_log_filter(&event, &matches_service));
+
+        let wrong_service = ServiceLogFilter {
+            service_ids: Some(vec!["svc-b".to_string()]),
+            level: None,
+            stream: None,
+            since_ts: None,
+        };
+        assert!(!ControlRoomProcessManager::matches_log_filter(&event, &wrong_service));
+
+        let too_recent = ServiceLogFilter {
+            service_ids: None,
+            level: None,
+            stream: None,
+            since_ts: Some(101),
+        };
+        assert!(!ControlRoomProcessManager::matches_log_filter(&event, &too_recent));
+    }
+
+    #[test]
+    fn compute_log_stats_tracks_counts_and_timestamp_range() {
+        let logs: VecDeque<ServiceLogEvent> = VecDeque::from(vec![
+            log_event("svc-a", "info", "stdout", 100),
+            log_event("svc-a", "error", "stderr", 300),
+            log_event("svc-a", "info", "stdout", 200),
+        ]);
+
+        let stats = ControlRoomProcessManager::compute_log_stats("svc-a", &logs);
+
+        assert_eq!(stats.total, 3);
+        assert_eq!(stats.by_level.get("info"), Some(&2));
+        assert_eq!(stats.by_level.get("error"), Some(&1));
+        assert_eq!(stats.oldest_ts, Some(100));
+        assert_eq!(stats.newest_ts, Some(300));
+    }
+
+    #[test]
+    fn resolve_cwd_prefers_the_command_specific_override() {
+        assert_eq!(
+            ControlRoomProcessManager::resolve_cwd(Some("/service"), Some("/override")),
+            Some(PathBuf::from("/override"))
+        );
+        assert_eq!(ControlRoomProcessManager::resolve_cwd(Some("/service"), None), Some(PathBuf::from("/service")));
+        assert_eq!(ControlRoomProcessManager::resolve_cwd(None, None), None);
+    }
 }
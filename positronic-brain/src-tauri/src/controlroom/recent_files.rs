@@ -0,0 +1,193 @@
+use crate::controlroom::config::resolve_config_path;
+use crate::controlroom::types::{ControlRoomConfig, RecentFileEntry, WorkspaceQuickOpenEntry};
+use crate::controlroom::workspace::{now_ms, search_workspace_names, secure_target_path, workspace_all_roots};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+use tokio::time::{sleep, Duration};
+
+/// Bound on how many files are remembered per workspace; the least recently opened is
+/// dropped once a new one would exceed it.
+const MAX_RECENT_PER_WORKSPACE: usize = 50;
+/// Writes to disk are coalesced so a burst of file opens (e.g. reopening a project)
+/// doesn't hammer the disk with one write per open.
+const SAVE_DEBOUNCE_MS: u64 = 2000;
+
+type RecentFilesData = HashMap<String, Vec<RecentFileEntry>>;
+
+/// Tracks `read_workspace_file` opens per workspace so the editor can offer a
+/// recent-files list and a quick-open search that ranks recently used files first.
+/// Persisted as `controlroom.recent-files.json` next to the controlroom config; writes
+/// are debounced so rapid opens don't hammer the disk.
+pub struct RecentFilesManager {
+    data: Arc<Mutex<RecentFilesData>>,
+    dirty_tx: mpsc::UnboundedSender<()>,
+}
+
+impl std::fmt::Debug for RecentFilesManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecentFilesManager").finish()
+    }
+}
+
+impl RecentFilesManager {
+    pub fn new() -> Self {
+        let data = Arc::new(Mutex::new(load_recent_files().unwrap_or_default()));
+        let (dirty_tx, dirty_rx) = mpsc::unbounded_channel();
+        spawn_save_debouncer(data.clone(), dirty_rx);
+        Self { data, dirty_tx }
+    }
+
+    /// Records a `read_workspace_file` open, bumping the entry's open count if it's
+    /// already tracked or adding a new one, then persisting once `SAVE_DEBOUNCE_MS`
+    /// passes without another open.
+    pub async fn record_open(&self, workspace_id: &str, root_id: &str, relative_path: &str) {
+        {
+            let mut data = self.data.lock().await;
+            let entries = data.entry(workspace_id.to_string()).or_default();
+            match entries.iter_mut().find(|entry| entry.root_id == root_id && entry.path == relative_path) {
+                Some(entry) => {
+                    entry.last_opened_ms = now_ms();
+                    entry.open_count += 1;
+                }
+                None => entries.push(RecentFileEntry {
+                    path: relative_path.to_string(),
+                    root_id: root_id.to_string(),
+                    last_opened_ms: now_ms(),
+                    open_count: 1,
+                }),
+            }
+            entries.sort_by(|a, b| b.last_opened_ms.cmp(&a.last_opened_ms));
+            entries.truncate(MAX_RECENT_PER_WORKSPACE);
+        }
+        let _ = self.dirty_tx.send(());
+    }
+
+    /// The `limit` most recently opened files still on disk, most recent first.
+    pub async fn recent(
+        &self,
+        config: &ControlRoomConfig,
+        workspace_id: &str,
+        limit: usize,
+    ) -> Result<Vec<RecentFileEntry>, String> {
+        let pruned = self.prune_workspace(config, workspace_id).await?;
+        Ok(pruned.into_iter().take(limit).collect())
+    }
+
+    /// Recent files matching `query` first (most recent first), then, if `limit` isn't
+    /// already reached, a plain name search over the rest of the workspace.
+    pub async fn quick_open(
+        &self,
+        config: &ControlRoomConfig,
+        workspace_id: &str,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<WorkspaceQuickOpenEntry>, String> {
+        let recent = self.prune_workspace(config, workspace_id).await?;
+        let query_lower = query.to_lowercase();
+
+        let mut results: Vec<WorkspaceQuickOpenEntry> = recent
+            .into_iter()
+            .filter(|entry| query.is_empty() || entry.path.to_lowercase().contains(&query_lower))
+            .take(limit)
+            .map(|entry| WorkspaceQuickOpenEntry {
+                path: entry.path,
+                root_id: entry.root_id,
+                last_opened_ms: Some(entry.last_opened_ms),
+                open_count: Some(entry.open_count),
+            })
+            .collect();
+
+        if results.len() < limit && !query.is_empty() {
+            let seen: HashSet<(String, String)> =
+                results.iter().map(|entry| (entry.root_id.clone(), entry.path.clone())).collect();
+            let remaining = limit - results.len();
+            let search = search_workspace_names(config, workspace_id, query, remaining + seen.len(), false, None, None)?;
+            for entry in search.entries {
+                if results.len() >= limit {
+                    break;
+                }
+                if entry.is_directory || seen.contains(&(entry.root_id.clone(), entry.path.clone())) {
+                    continue;
+                }
+                results.push(WorkspaceQuickOpenEntry {
+                    path: entry.path,
+                    root_id: entry.root_id,
+                    last_opened_ms: None,
+                    open_count: None,
+                });
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Drops recent entries whose file no longer exists, persisting the drop if
+    /// anything was pruned, and returns what's left sorted most-recent-first.
+    async fn prune_workspace(
+        &self,
+        config: &ControlRoomConfig,
+        workspace_id: &str,
+    ) -> Result<Vec<RecentFileEntry>, String> {
+        let roots = workspace_all_roots(config, workspace_id)?;
+        let mut data = self.data.lock().await;
+        let entries = data.entry(workspace_id.to_string()).or_default();
+        let before = entries.len();
+        entries.retain(|entry| {
+            roots
+                .iter()
+                .find(|(root_id, _)| *root_id == entry.root_id)
+                .and_then(|(_, base)| secure_target_path(base, &entry.path).ok())
+                .is_some_and(|target| target.is_file())
+        });
+        entries.sort_by(|a, b| b.last_opened_ms.cmp(&a.last_opened_ms));
+        let pruned = entries.clone();
+        let changed = entries.len() != before;
+        drop(data);
+        if changed {
+            let _ = self.dirty_tx.send(());
+        }
+        Ok(pruned)
+    }
+}
+
+/// Coalesces bursts of `record_open`/prune "dirty" pings into a single write, matching
+/// the debounce pattern `WatchManager` uses for filesystem-change events.
+fn spawn_save_debouncer(data: Arc<Mutex<RecentFilesData>>, mut dirty_rx: mpsc::UnboundedReceiver<()>) {
+    tokio::spawn(async move {
+        loop {
+            if dirty_rx.recv().await.is_none() {
+                return;
+            }
+
+            loop {
+                tokio::select! {
+                    more = dirty_rx.recv() => if more.is_none() { return; },
+                    _ = sleep(Duration::from_millis(SAVE_DEBOUNCE_MS)) => break,
+                }
+            }
+
+            let snapshot = data.lock().await.clone();
+            let _ = save_recent_files(&snapshot);
+        }
+    });
+}
+
+fn recent_files_path() -> Result<PathBuf, String> {
+    let config_path = resolve_config_path()?;
+    Ok(config_path.with_file_name("controlroom.recent-files.json"))
+}
+
+fn load_recent_files() -> Option<RecentFilesData> {
+    let path = recent_files_path().ok()?;
+    let raw = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&raw).ok()
+}
+
+fn save_recent_files(data: &RecentFilesData) -> Result<(), String> {
+    let path = recent_files_path()?;
+    let serialized =
+        serde_json::to_vec_pretty(data).map_err(|e| format!("failed to serialize recent files: {e}"))?;
+    std::fs::write(&path, serialized).map_err(|e| format!("failed writing {}: {e}", path.display()))
+}
@@ -1,34 +1,136 @@
-use crate::controlroom::events::{emit_backend_error, emit_runner_exit, emit_runner_output};
+use crate::controlroom::env_file;
+use crate::controlroom::events::{
+    emit_backend_error, emit_runner_exit, emit_runner_output, emit_runner_output_batch,
+};
+use crate::controlroom::process_manager::ControlRoomProcessManager;
 use crate::controlroom::types::{
-    ControlRoomConfig, RunnerCommandInput, RunnerExitEvent, RunnerOutputEvent, RunnerStartResponse,
+    ControlRoomConfig, RunnerCommandInput, RunnerExitEvent, RunnerExportFormat, RunnerExportSummary,
+    RunnerHistoryEntry, RunnerListResponse, RunnerOutputBatchEvent, RunnerOutputEvent, RunnerRunSummary,
+    RunnerStartResponse,
 };
-use std::collections::HashMap;
+use crate::controlroom::workspace::secure_target_path;
+use portable_pty::Child as PtyChildTrait;
+use portable_pty::{CommandBuilder, MasterPty, PtySize, native_pty_system};
+use std::collections::{HashMap, VecDeque};
+use std::io::{Read as StdRead, Write as StdWrite};
 use std::path::PathBuf;
 use std::process::Stdio;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex as StdMutex;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
+#[cfg(unix)]
+use std::os::unix::process::ExitStatusExt;
 use tauri::AppHandle;
-use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWriteExt, BufReader};
 use tokio::process::{Child, Command};
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, Semaphore};
+
+/// The child-process side of a PTY-mode run. `wait`/`try_wait`/`kill` are sync,
+/// so callers reach them via `tokio::task::spawn_blocking` or brief inline calls.
+type PtyChildHandle = Arc<StdMutex<Box<dyn portable_pty::Child + Send + Sync>>>;
+/// Dropping the last handle hangs up the PTY slave, delivering SIGHUP to the child.
+type PtyMasterHandle = Arc<StdMutex<Box<dyn MasterPty + Send>>>;
+type PtyWriterHandle = Arc<StdMutex<Box<dyn StdWrite + Send>>>;
 
-#[derive(Debug)]
 struct RunnerRuntime {
-    child: Arc<Mutex<Child>>,
+    child: Option<Arc<Mutex<Child>>>,
+    stdin: Option<Arc<Mutex<tokio::process::ChildStdin>>>,
+    pty_child: Option<PtyChildHandle>,
+    pty_master: Option<PtyMasterHandle>,
+    pty_writer: Option<PtyWriterHandle>,
+    program: String,
+    args: Vec<String>,
+    workspace_id: Option<String>,
+    /// The exact input this run was started with, kept around so `rerun` can replay it.
+    input: RunnerCommandInput,
+    correlation_id: String,
+    queued: bool,
+    started_at_ms: u64,
+    finished_at_ms: Option<u64>,
+    exit_code: Option<i32>,
+    signal: Option<String>,
+    timed_out: bool,
+    /// Set once the run's output line count hit `max_output_lines` and reading stopped.
+    truncated: bool,
+    /// The reason passed to `cancel`, stashed here so the exit watcher can include it
+    /// in the `RunnerExitEvent` once the killed process actually exits.
+    cancel_reason: Option<String>,
+    output: VecDeque<RunnerOutputEvent>,
+    next_seq: u64,
+}
+
+// `portable_pty`'s Child/MasterPty trait objects aren't `Debug`, so this can't be derived.
+impl std::fmt::Debug for RunnerRuntime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RunnerRuntime")
+            .field("program", &self.program)
+            .field("args", &self.args)
+            .field("workspace_id", &self.workspace_id)
+            .field("correlation_id", &self.correlation_id)
+            .field("queued", &self.queued)
+            .field("started_at_ms", &self.started_at_ms)
+            .field("finished_at_ms", &self.finished_at_ms)
+            .field("exit_code", &self.exit_code)
+            .field("signal", &self.signal)
+            .field("timed_out", &self.timed_out)
+            .field("is_pty", &self.pty_child.is_some())
+            .finish()
+    }
+}
+
+/// A run's command line, resolved and validated before it is (possibly) queued,
+/// so a dequeued run only needs to spawn — no chance of failing on stale config.
+struct PreparedRun {
+    run_id: String,
+    correlation_id: String,
+    input: RunnerCommandInput,
+    cwd: Option<PathBuf>,
+    workspace_root: Option<PathBuf>,
+    max_output_lines: usize,
 }
 
 #[derive(Debug)]
 pub struct RunnerManager {
     runs: Mutex<HashMap<String, RunnerRuntime>>,
+    /// Post-mortem record of completed runs, kept independently of `runs` (which is
+    /// pruned by age/count) so history survives longer than a live `RunnerRuntime` entry.
+    history: Mutex<VecDeque<RunnerHistoryEntry>>,
     seq: AtomicU64,
+    max_output_per_run: usize,
+    max_finished_runs: usize,
+    max_finished_age_sec: u64,
+    concurrency: Arc<Semaphore>,
+    max_concurrent_runs: usize,
+    /// Bound on in-flight lines buffered between an output reader and its batch consumer;
+    /// a chatty command that outruns this has its excess lines dropped, not queued forever.
+    output_backpressure_high_water: usize,
 }
 
+/// Cap on the number of completed runs kept in `RunnerManager::history`.
+const MAX_HISTORY_ENTRIES: usize = 100;
+
+/// Output lines are coalesced into a single event at most this often...
+const OUTPUT_BATCH_INTERVAL_MS: u64 = 50;
+/// ...or as soon as this many lines have accumulated, whichever comes first.
+const OUTPUT_BATCH_MAX_LINES: usize = 200;
+/// Fallback cap on output lines read from a single stream (stdout or stderr) of a run
+/// when neither the run nor `RunnerConfig` specifies `max_output_lines`.
+const DEFAULT_MAX_OUTPUT_LINES: usize = 20_000;
+
 impl RunnerManager {
     pub fn new() -> Self {
+        let max_concurrent_runs = 4;
         Self {
             runs: Mutex::new(HashMap::new()),
+            history: Mutex::new(VecDeque::new()),
             seq: AtomicU64::new(1),
+            max_output_per_run: 5000,
+            max_finished_runs: 50,
+            max_finished_age_sec: 30 * 60,
+            concurrency: Arc::new(Semaphore::new(max_concurrent_runs)),
+            max_concurrent_runs,
+            output_backpressure_high_water: 2000,
         }
     }
 
@@ -54,52 +156,493 @@ impl RunnerManager {
         })
     }
 
-    fn spawn_output_reader<R>(app: AppHandle, run_id: String, stream: &'static str, reader: R)
-    where
+    fn resolve_run_cwd(
+        config: &ControlRoomConfig,
+        workspace_id: Option<&str>,
+        cwd: Option<&str>,
+    ) -> Result<Option<PathBuf>, String> {
+        let workspace_base = Self::resolve_workspace_cwd(config, workspace_id);
+
+        match cwd {
+            None => Ok(workspace_base),
+            Some(cwd) => {
+                let base = workspace_base
+                    .ok_or_else(|| "cwd requires a valid workspace_id to validate against".to_string())?;
+                Ok(Some(secure_target_path(&base, cwd)?))
+            }
+        }
+    }
+
+    #[cfg(unix)]
+    fn signal_name(status: &std::process::ExitStatus) -> Option<String> {
+        status.signal().map(|signal| match signal {
+            2 => "SIGINT".to_string(),
+            3 => "SIGQUIT".to_string(),
+            4 => "SIGILL".to_string(),
+            6 => "SIGABRT".to_string(),
+            8 => "SIGFPE".to_string(),
+            9 => "SIGKILL".to_string(),
+            11 => "SIGSEGV".to_string(),
+            13 => "SIGPIPE".to_string(),
+            15 => "SIGTERM".to_string(),
+            other => format!("SIG{other}"),
+        })
+    }
+
+    #[cfg(not(unix))]
+    fn signal_name(_status: &std::process::ExitStatus) -> Option<String> {
+        None
+    }
+
+    /// The binary `build_shell_command` spawns to interpret a shell command line on this
+    /// platform. `check_program_allowed` checks this same name against the allowlist so
+    /// an operator gating shell mode via `allowedPrograms` is gating the binary that
+    /// actually runs, not always `"sh"`.
+    #[cfg(windows)]
+    const SHELL_PROGRAM: &'static str = "cmd";
+    #[cfg(not(windows))]
+    const SHELL_PROGRAM: &'static str = "sh";
+
+    fn build_shell_command(program: &str, args: &[String]) -> Command {
+        let mut command_line = program.to_string();
+        for arg in args {
+            command_line.push(' ');
+            command_line.push_str(arg);
+        }
+
+        #[cfg(windows)]
+        {
+            let mut command = Command::new(Self::SHELL_PROGRAM);
+            command.arg("/C").arg(command_line);
+            command
+        }
+
+        #[cfg(not(windows))]
+        {
+            let mut command = Command::new(Self::SHELL_PROGRAM);
+            command.arg("-c").arg(command_line);
+            command
+        }
+    }
+
+    /// Resolves `program` the same way a shell would: as-is if it names a path,
+    /// otherwise the first match on `PATH`. Falls back to the raw name if nothing
+    /// is found, so the allowlist check below still has something to compare.
+    fn resolve_program_path(program: &str) -> PathBuf {
+        let candidate = PathBuf::from(program);
+        if candidate.is_absolute() || program.contains(std::path::MAIN_SEPARATOR) {
+            return candidate;
+        }
+
+        if let Some(path_var) = std::env::var_os("PATH") {
+            for dir in std::env::split_paths(&path_var) {
+                let full = dir.join(program);
+                if full.is_file() {
+                    return full;
+                }
+            }
+        }
+
+        candidate
+    }
+
+    /// Single-`*` glob: `pattern` may contain at most one wildcard, matched against `value`.
+    fn glob_match(pattern: &str, value: &str) -> bool {
+        match pattern.split_once('*') {
+            None => pattern == value,
+            Some((prefix, suffix)) => {
+                value.len() >= prefix.len() + suffix.len()
+                    && value.starts_with(prefix)
+                    && value.ends_with(suffix)
+            }
+        }
+    }
+
+    fn check_program_allowed(config: &ControlRoomConfig, program: &str, shell: bool) -> Result<(), String> {
+        let Some(allowed) = &config.runner.allowed_programs else {
+            return Ok(());
+        };
+
+        if shell && !allowed.iter().any(|entry| Self::glob_match(entry, Self::SHELL_PROGRAM)) {
+            return Err(
+                "runner policy: shell mode is disabled while an allowedPrograms allowlist is active"
+                    .to_string(),
+            );
+        }
+
+        let resolved = Self::resolve_program_path(program);
+        let resolved = resolved.to_string_lossy();
+
+        let permitted = allowed
+            .iter()
+            .any(|entry| Self::glob_match(entry, program) || Self::glob_match(entry, &resolved));
+
+        if permitted {
+            Ok(())
+        } else {
+            Err(format!(
+                "runner policy: program {program:?} (resolved to {resolved:?}) is not on the allowedPrograms allowlist"
+            ))
+        }
+    }
+
+    async fn correlation_id(&self, run_id: &str) -> Option<String> {
+        let runs = self.runs.lock().await;
+        runs.get(run_id).map(|runtime| runtime.correlation_id.clone())
+    }
+
+    /// Whether the run's output was cut off after hitting `max_output_lines`.
+    async fn is_truncated(&self, run_id: &str) -> bool {
+        let runs = self.runs.lock().await;
+        runs.get(run_id).map(|runtime| runtime.truncated).unwrap_or(false)
+    }
+
+    async fn mark_truncated(&self, run_id: &str) {
+        let mut runs = self.runs.lock().await;
+        if let Some(runtime) = runs.get_mut(run_id) {
+            runtime.truncated = true;
+        }
+    }
+
+    /// The reason passed to `cancel` for this run, if any.
+    async fn cancel_reason(&self, run_id: &str) -> Option<String> {
+        let runs = self.runs.lock().await;
+        runs.get(run_id).and_then(|runtime| runtime.cancel_reason.clone())
+    }
+
+    async fn set_cancel_reason(&self, run_id: &str, reason: Option<String>) {
+        let mut runs = self.runs.lock().await;
+        if let Some(runtime) = runs.get_mut(run_id) {
+            runtime.cancel_reason = reason;
+        }
+    }
+
+    /// Returns `(started_ts, ended_ts, duration_ms)` for a run. Call after
+    /// `finish_run`/`cancel_queued` has set `finished_at_ms` so `ended_ts` is accurate.
+    async fn timing(&self, run_id: &str) -> (u64, u64, u64) {
+        let runs = self.runs.lock().await;
+        match runs.get(run_id) {
+            Some(runtime) => {
+                let started_ts = runtime.started_at_ms;
+                let ended_ts = runtime.finished_at_ms.unwrap_or_else(Self::now_ms);
+                (started_ts, ended_ts, ended_ts.saturating_sub(started_ts))
+            }
+            None => {
+                let now = Self::now_ms();
+                (now, now, 0)
+            }
+        }
+    }
+
+    async fn record_output(&self, run_id: &str, stream: &str, line: String) -> Option<RunnerOutputEvent> {
+        let mut runs = self.runs.lock().await;
+        let runtime = runs.get_mut(run_id)?;
+
+        let seq = runtime.next_seq;
+        runtime.next_seq += 1;
+
+        let level = ControlRoomProcessManager::detect_level(&line, stream);
+        let event = RunnerOutputEvent {
+            run_id: run_id.to_string(),
+            stream: stream.to_string(),
+            ts: Self::now_ms(),
+            seq,
+            line,
+            level,
+            correlation_id: Some(runtime.correlation_id.clone()),
+        };
+
+        runtime.output.push_back(event.clone());
+        while runtime.output.len() > self.max_output_per_run {
+            runtime.output.pop_front();
+        }
+
+        Some(event)
+    }
+
+    /// Drains a batch of raw output lines into run history, appending a backpressure
+    /// marker if the caller reports lines were dropped since the last drain. Kept
+    /// separate from emission so the batching/backpressure bookkeeping can be tested
+    /// without a live `AppHandle`.
+    async fn drain_output_batch(
+        &self,
+        run_id: &str,
+        stream: &str,
+        batch: &mut Vec<String>,
+        dropped: &AtomicU64,
+    ) -> Vec<RunnerOutputEvent> {
+        let dropped_count = dropped.swap(0, Ordering::Relaxed);
+        let mut events = Vec::with_capacity(batch.len() + 1);
+        for line in batch.drain(..) {
+            if let Some(event) = self.record_output(run_id, stream, line).await {
+                events.push(event);
+            }
+        }
+        if dropped_count > 0 {
+            if let Some(event) = self
+                .record_output(run_id, "system", format!("{dropped_count} lines dropped due to backpressure"))
+                .await
+            {
+                events.push(event);
+            }
+        }
+        events
+    }
+
+    /// Reads a chatty child's output and batches it: one `runner-output-batch` event per
+    /// 50ms tick or 200 lines, whichever comes first, instead of one Tauri emit per line.
+    /// If the reader outpaces the batch consumer past `output_backpressure_high_water`
+    /// buffered lines, the excess is dropped and recorded as a single marker line rather
+    /// than buffered without bound.
+    ///
+    /// `max_lines` bounds the total lines read from this stream; once hit, the producer
+    /// stops reading from the pipe (the child keeps running) and the run is marked
+    /// `truncated` after a `[truncated: output limit reached]` system line is recorded.
+    fn spawn_output_reader<R>(
+        self: &Arc<Self>,
+        app: AppHandle,
+        run_id: String,
+        stream: &'static str,
+        reader: R,
+        max_lines: usize,
+    ) where
         R: AsyncRead + Unpin + Send + 'static,
     {
+        let manager = self.clone();
+        let high_water_mark = self.output_backpressure_high_water;
         tokio::spawn(async move {
-            let mut lines = BufReader::new(reader).lines();
-            while let Ok(Some(line)) = lines.next_line().await {
-                let payload = RunnerOutputEvent {
-                    run_id: run_id.clone(),
-                    stream: stream.to_string(),
-                    ts: Self::now_ms(),
-                    line,
-                    correlation_id: Some(run_id.clone()),
-                };
-                emit_runner_output(&app, &payload);
+            let (tx, mut rx) = tokio::sync::mpsc::channel::<String>(high_water_mark);
+            let dropped = Arc::new(AtomicU64::new(0));
+            let truncated = Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+            let producer_dropped = dropped.clone();
+            let producer_truncated = truncated.clone();
+            let producer = tokio::spawn(async move {
+                let mut lines = BufReader::new(reader).lines();
+                let mut line_count = 0usize;
+                while let Ok(Some(line)) = lines.next_line().await {
+                    line_count += 1;
+                    if line_count > max_lines {
+                        producer_truncated.store(true, Ordering::Relaxed);
+                        break;
+                    }
+                    if tx.try_send(line).is_err() {
+                        producer_dropped.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            });
+
+            let mut interval = tokio::time::interval(Duration::from_millis(OUTPUT_BATCH_INTERVAL_MS));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            let mut batch = Vec::new();
+
+            loop {
+                tokio::select! {
+                    line = rx.recv() => {
+                        match line {
+                            Some(line) => {
+                                batch.push(line);
+                                if batch.len() >= OUTPUT_BATCH_MAX_LINES {
+                                    let events = manager.drain_output_batch(&run_id, stream, &mut batch, &dropped).await;
+                                    if !events.is_empty() {
+                                        emit_runner_output_batch(&app, &RunnerOutputBatchEvent { run_id: run_id.clone(), events });
+                                    }
+                                }
+                            }
+                            None => {
+                                let events = manager.drain_output_batch(&run_id, stream, &mut batch, &dropped).await;
+                                if !events.is_empty() {
+                                    emit_runner_output_batch(&app, &RunnerOutputBatchEvent { run_id: run_id.clone(), events });
+                                }
+                                break;
+                            }
+                        }
+                    }
+                    _ = interval.tick() => {
+                        let events = manager.drain_output_batch(&run_id, stream, &mut batch, &dropped).await;
+                        if !events.is_empty() {
+                            emit_runner_output_batch(&app, &RunnerOutputBatchEvent { run_id: run_id.clone(), events });
+                        }
+                    }
+                }
+            }
+
+            let _ = producer.await;
+
+            if truncated.load(Ordering::Relaxed) {
+                manager.mark_truncated(&run_id).await;
+                if let Some(event) = manager
+                    .record_output(&run_id, "system", "[truncated: output limit reached]".to_string())
+                    .await
+                {
+                    emit_runner_output(&app, &event);
+                }
+            }
+        });
+    }
+
+    async fn finish_run(
+        self: &Arc<Self>,
+        run_id: &str,
+        exit_code: Option<i32>,
+        signal: Option<String>,
+        timed_out: bool,
+    ) {
+        let finished_at_ms = Self::now_ms();
+        let history_entry = {
+            let mut runs = self.runs.lock().await;
+            let entry = runs.get_mut(run_id).map(|runtime| {
+                runtime.child = None;
+                runtime.stdin = None;
+                runtime.pty_child = None;
+                runtime.pty_master = None;
+                runtime.pty_writer = None;
+                runtime.finished_at_ms = Some(finished_at_ms);
+                runtime.exit_code = exit_code;
+                runtime.signal = signal;
+                runtime.timed_out = timed_out;
+
+                RunnerHistoryEntry {
+                    run_id: run_id.to_string(),
+                    program: runtime.program.clone(),
+                    args: runtime.args.clone(),
+                    workspace_id: runtime.workspace_id.clone(),
+                    started_at_ms: runtime.started_at_ms,
+                    ended_at_ms: finished_at_ms,
+                    exit_code: runtime.exit_code,
+                    timed_out: runtime.timed_out,
+                }
+            });
+            Self::prune_finished(&mut runs, self.max_finished_runs, self.max_finished_age_sec);
+            entry
+        };
+
+        if let Some(entry) = history_entry {
+            let mut history = self.history.lock().await;
+            history.push_back(entry);
+            while history.len() > MAX_HISTORY_ENTRIES {
+                history.pop_front();
             }
+        }
+    }
+
+    pub async fn list_run_history(&self) -> Vec<RunnerHistoryEntry> {
+        self.history.lock().await.iter().cloned().collect()
+    }
+
+    fn prune_finished(
+        runs: &mut HashMap<String, RunnerRuntime>,
+        max_finished_runs: usize,
+        max_finished_age_sec: u64,
+    ) {
+        let now = Self::now_ms();
+        let max_age_ms = max_finished_age_sec.saturating_mul(1000);
+
+        runs.retain(|_, runtime| match runtime.finished_at_ms {
+            None => true,
+            Some(finished_at) => now.saturating_sub(finished_at) < max_age_ms,
         });
+
+        let mut finished: Vec<(String, u64)> = runs
+            .iter()
+            .filter_map(|(run_id, runtime)| runtime.finished_at_ms.map(|ts| (run_id.clone(), ts)))
+            .collect();
+
+        if finished.len() > max_finished_runs {
+            finished.sort_by_key(|(_, ts)| *ts);
+            let excess = finished.len() - max_finished_runs;
+            for (run_id, _) in finished.into_iter().take(excess) {
+                runs.remove(&run_id);
+            }
+        }
     }
 
-    fn spawn_exit_watcher(self: &Arc<Self>, app: AppHandle, run_id: String, child: Arc<Mutex<Child>>) {
+    fn spawn_exit_watcher(
+        self: &Arc<Self>,
+        app: AppHandle,
+        run_id: String,
+        child: Arc<Mutex<Child>>,
+        timeout: Option<Duration>,
+        permit: tokio::sync::OwnedSemaphorePermit,
+    ) {
         let manager = self.clone();
         tokio::spawn(async move {
+            // Held for the run's lifetime so a finished run frees its concurrency slot
+            // for the next queued run; dropped implicitly when this task ends.
+            let _permit = permit;
+            let started = tokio::time::Instant::now();
             loop {
                 tokio::time::sleep(Duration::from_millis(500)).await;
+
+                if let Some(timeout) = timeout {
+                    if started.elapsed() >= timeout {
+                        {
+                            let mut guard = child.lock().await;
+                            let _ = guard.start_kill();
+                            let _ = guard.wait().await;
+                        }
+
+                        let signal = if cfg!(unix) { Some("SIGKILL".to_string()) } else { None };
+                        manager.finish_run(&run_id, None, signal.clone(), true).await;
+
+                        if let Some(event) = manager
+                            .record_output(
+                                &run_id,
+                                "system",
+                                format!("run timed out after {}s and was killed", timeout.as_secs()),
+                            )
+                            .await
+                        {
+                            emit_runner_output(&app, &event);
+                        }
+
+                        let (started_ts, ended_ts, duration_ms) = manager.timing(&run_id).await;
+                        let event = RunnerExitEvent {
+                            run_id: run_id.clone(),
+                            code: None,
+                            signal,
+                            timed_out: true,
+                            cancelled_before_start: false,
+                            started_ts,
+                            ended_ts,
+                            duration_ms,
+                            correlation_id: manager.correlation_id(&run_id).await,
+                            truncated: manager.is_truncated(&run_id).await,
+                            cancel_reason: None,
+                        };
+                        emit_runner_exit(&app, &event);
+                        break;
+                    }
+                }
+
                 let exit = {
                     let mut guard = child.lock().await;
                     match guard.try_wait() {
                         Ok(status) => status,
                         Err(error) => {
-                            emit_backend_error(&app, "runner-watcher", error.to_string());
+                            emit_backend_error(&app, "runner-watcher", error.to_string(), Some(format!("run:{run_id}")));
                             None
                         }
                     }
                 };
 
                 if let Some(status) = exit {
-                    {
-                        let mut runs = manager.runs.lock().await;
-                        runs.remove(&run_id);
-                    }
+                    let signal = Self::signal_name(&status);
+                    manager.finish_run(&run_id, status.code(), signal.clone(), false).await;
 
+                    let (started_ts, ended_ts, duration_ms) = manager.timing(&run_id).await;
                     let event = RunnerExitEvent {
                         run_id: run_id.clone(),
                         code: status.code(),
-                        signal: None,
-                        correlation_id: Some(run_id.clone()),
+                        signal,
+                        timed_out: false,
+                        cancelled_before_start: false,
+                        started_ts,
+                        ended_ts,
+                        duration_ms,
+                        correlation_id: manager.correlation_id(&run_id).await,
+                        truncated: manager.is_truncated(&run_id).await,
+                        cancel_reason: manager.cancel_reason(&run_id).await,
                     };
                     emit_runner_exit(&app, &event);
                     break;
@@ -113,61 +656,1325 @@ impl RunnerManager {
         app: &AppHandle,
         input: &RunnerCommandInput,
         config: &ControlRoomConfig,
+    ) -> Result<RunnerStartResponse, String> {
+        self.execute_with_correlation(app, input, config, None, None).await
+    }
+
+    /// Re-executes a previous run's exact input, so long as its history entry hasn't
+    /// been pruned and its original workspace still exists.
+    pub async fn rerun(
+        self: &Arc<Self>,
+        app: &AppHandle,
+        run_id: &str,
+        config: &ControlRoomConfig,
+    ) -> Result<RunnerStartResponse, String> {
+        let original_input = {
+            let runs = self.runs.lock().await;
+            runs.get(run_id)
+                .map(|runtime| runtime.input.clone())
+                .ok_or_else(|| format!("run not found in history: {run_id}"))?
+        };
+
+        if let Some(workspace_id) = original_input.workspace_id.as_deref() {
+            if !config.workspaces.iter().any(|workspace| workspace.id == workspace_id) {
+                return Err(format!("cannot rerun {run_id}: workspace no longer exists: {workspace_id}"));
+            }
+        }
+
+        self.execute_with_correlation(
+            app,
+            &original_input,
+            config,
+            Some(format!("rerun:{run_id}")),
+            Some(run_id.to_string()),
+        )
+        .await
+    }
+
+    pub async fn execute_preset(
+        self: &Arc<Self>,
+        app: &AppHandle,
+        preset_id: &str,
+        extra_args: Option<Vec<String>>,
+        config: &ControlRoomConfig,
+    ) -> Result<RunnerStartResponse, String> {
+        let preset = config
+            .runner_presets
+            .iter()
+            .find(|preset| preset.id == preset_id)
+            .ok_or_else(|| format!("unknown runner preset: {preset_id}"))?;
+
+        let mut args = preset.args.clone();
+        args.extend(extra_args.unwrap_or_default());
+
+        let input = RunnerCommandInput {
+            workspace_id: preset.workspace_id.clone(),
+            program: preset.program.clone(),
+            args,
+            cwd: preset.cwd.clone(),
+            env: preset.env.clone(),
+            shell: None,
+            timeout_sec: None,
+            interactive: None,
+            pty: None,
+            stdin_data: None,
+            inherit_env: None,
+            max_output_lines: None,
+        };
+
+        self.execute_with_correlation(app, &input, config, Some(format!("preset:{preset_id}")), None)
+            .await
+    }
+
+    async fn execute_with_correlation(
+        self: &Arc<Self>,
+        app: &AppHandle,
+        input: &RunnerCommandInput,
+        config: &ControlRoomConfig,
+        correlation_prefix: Option<String>,
+        rerun_of: Option<String>,
     ) -> Result<RunnerStartResponse, String> {
         if input.program.trim().is_empty() {
             return Err("runner program cannot be empty".to_string());
         }
+        Self::check_program_allowed(config, &input.program, input.shell.unwrap_or(false))?;
 
         let run_id = self.next_run_id();
+        let correlation_id = match correlation_prefix {
+            Some(prefix) => format!("{prefix}:{run_id}"),
+            None => run_id.clone(),
+        };
 
-        let mut command = Command::new(&input.program);
-        command.args(&input.args);
-        command.stdin(Stdio::null());
+        let cwd = Self::resolve_run_cwd(config, input.workspace_id.as_deref(), input.cwd.as_deref())?;
+        let workspace_root = Self::resolve_workspace_cwd(config, input.workspace_id.as_deref());
+        let max_output_lines = input
+            .max_output_lines
+            .or(config.runner.max_output_lines)
+            .unwrap_or(DEFAULT_MAX_OUTPUT_LINES);
+        let prepared = PreparedRun {
+            run_id: run_id.clone(),
+            correlation_id: correlation_id.clone(),
+            input: input.clone(),
+            cwd,
+            workspace_root,
+            max_output_lines,
+        };
+
+        match Arc::clone(&self.concurrency).try_acquire_owned() {
+            Ok(permit) => {
+                self.spawn_now(app, prepared, permit).await?;
+                Ok(RunnerStartResponse { run_id, queued: false, rerun_of })
+            }
+            Err(_) => {
+                {
+                    let mut runs = self.runs.lock().await;
+                    runs.insert(
+                        run_id.clone(),
+                        RunnerRuntime {
+                            child: None,
+                            stdin: None,
+                            pty_child: None,
+                            pty_master: None,
+                            pty_writer: None,
+                            program: input.program.clone(),
+                            args: input.args.clone(),
+                            workspace_id: input.workspace_id.clone(),
+                            input: input.clone(),
+                            correlation_id,
+                            queued: true,
+                            started_at_ms: Self::now_ms(),
+                            finished_at_ms: None,
+                            exit_code: None,
+                            signal: None,
+                            timed_out: false,
+                            truncated: false,
+                            cancel_reason: None,
+                            output: VecDeque::new(),
+                            next_seq: 0,
+                        },
+                    );
+                }
+
+                if let Some(event) = self
+                    .record_output(&run_id, "system", format!("run queued: {} already running", self.max_concurrent_runs))
+                    .await
+                {
+                    emit_runner_output(app, &event);
+                }
+
+                let manager = self.clone();
+                let app = app.clone();
+                tokio::spawn(async move {
+                    let permit = match Arc::clone(&manager.concurrency).acquire_owned().await {
+                        Ok(permit) => permit,
+                        Err(_) => return,
+                    };
+
+                    let already_finished = {
+                        let runs = manager.runs.lock().await;
+                        runs.get(&prepared.run_id)
+                            .map(|runtime| runtime.finished_at_ms.is_some())
+                            .unwrap_or(true)
+                    };
+                    if already_finished {
+                        return;
+                    }
+
+                    if let Some(event) = manager
+                        .record_output(&prepared.run_id, "system", "run left queue and is starting".to_string())
+                        .await
+                    {
+                        emit_runner_output(&app, &event);
+                    }
+
+                    let run_id_for_error = prepared.run_id.clone();
+                    let correlation_for_error = prepared.correlation_id.clone();
+                    if let Err(error) = manager.spawn_now(&app, prepared, permit).await {
+                        manager.finish_run(&run_id_for_error, None, None, false).await;
+                        if let Some(event) = manager
+                            .record_output(&run_id_for_error, "system", error)
+                            .await
+                        {
+                            emit_runner_output(&app, &event);
+                        }
+                        let (started_ts, ended_ts, duration_ms) = manager.timing(&run_id_for_error).await;
+                        let exit_event = RunnerExitEvent {
+                            run_id: run_id_for_error,
+                            code: None,
+                            signal: None,
+                            timed_out: false,
+                            cancelled_before_start: false,
+                            started_ts,
+                            ended_ts,
+                            duration_ms,
+                            correlation_id: Some(correlation_for_error),
+                            truncated: false,
+                            cancel_reason: None,
+                        };
+                        emit_runner_exit(&app, &exit_event);
+                    }
+                });
+
+                Ok(RunnerStartResponse { run_id, queued: true, rerun_of })
+            }
+        }
+    }
+
+    /// Spawns a validated, already-queued-or-not run. Used both for runs that start
+    /// immediately (a concurrency permit was free) and for queued runs once their
+    /// permit is finally acquired, so both paths share the exact same process setup.
+    async fn spawn_now(
+        self: &Arc<Self>,
+        app: &AppHandle,
+        prepared: PreparedRun,
+        permit: tokio::sync::OwnedSemaphorePermit,
+    ) -> Result<(), String> {
+        if prepared.input.pty.unwrap_or(false) {
+            return self.spawn_now_pty(app, prepared, permit).await;
+        }
+
+        let PreparedRun { run_id, correlation_id, input, cwd, workspace_root, max_output_lines } = prepared;
+
+        let mut command = if input.shell.unwrap_or(false) {
+            Self::build_shell_command(&input.program, &input.args)
+        } else {
+            let mut command = Command::new(&input.program);
+            command.args(&input.args);
+            command
+        };
+        let interactive = input.interactive.unwrap_or(false);
+        let stdin_data = input.stdin_data.clone();
+        command.stdin(if interactive || stdin_data.is_some() { Stdio::piped() } else { Stdio::null() });
         command.stdout(Stdio::piped());
         command.stderr(Stdio::piped());
 
-        if let Some(cwd) = Self::resolve_workspace_cwd(config, input.workspace_id.as_deref()) {
+        if let Some(cwd) = &cwd {
             command.current_dir(cwd);
         }
 
+        if !input.inherit_env.unwrap_or(true) {
+            command.env_clear();
+        }
+        if let Some(root) = &workspace_root {
+            command.envs(env_file::load_env_file(root));
+        }
+        if let Some(envs) = &input.env {
+            command.envs(envs);
+        }
+
+        // Give the run its own process group so `cancel` can signal descendants too.
+        #[cfg(unix)]
+        command.process_group(0);
+
         let mut child = command
             .spawn()
             .map_err(|e| format!("runner spawn failed: {e}"))?;
 
+        let mut raw_stdin = child.stdin.take();
+        if let Some(data) = stdin_data {
+            if let Some(mut handle) = raw_stdin.take() {
+                // One-shot stdin: write then let `handle` drop to close it (EOF), unlike
+                // `interactive` stdin which stays open in `runtime.stdin` for later writes.
+                tokio::spawn(async move {
+                    let _ = handle.write_all(data.as_bytes()).await;
+                });
+            }
+        }
+        let stdin = raw_stdin.map(|stdin| Arc::new(Mutex::new(stdin)));
         let stdout = child.stdout.take();
         let stderr = child.stderr.take();
         let child = Arc::new(Mutex::new(child));
 
         {
             let mut runs = self.runs.lock().await;
-            runs.insert(run_id.clone(), RunnerRuntime { child: child.clone() });
+            match runs.get_mut(&run_id) {
+                Some(runtime) => {
+                    runtime.child = Some(child.clone());
+                    runtime.stdin = stdin.clone();
+                    runtime.queued = false;
+                }
+                None => {
+                    runs.insert(
+                        run_id.clone(),
+                        RunnerRuntime {
+                            child: Some(child.clone()),
+                            stdin: stdin.clone(),
+                            pty_child: None,
+                            pty_master: None,
+                            pty_writer: None,
+                            program: input.program.clone(),
+                            args: input.args.clone(),
+                            workspace_id: input.workspace_id.clone(),
+                            input: input.clone(),
+                            correlation_id,
+                            queued: false,
+                            started_at_ms: Self::now_ms(),
+                            finished_at_ms: None,
+                            exit_code: None,
+                            signal: None,
+                            timed_out: false,
+                            truncated: false,
+                            cancel_reason: None,
+                            output: VecDeque::new(),
+                            next_seq: 0,
+                        },
+                    );
+                }
+            }
+        }
+
+        let mut command_line = vec![input.program.clone()];
+        command_line.extend(input.args.iter().cloned());
+        let cwd_display = cwd
+            .as_ref()
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|| "(default)".to_string());
+        let start_line = format!(
+            "$ {} (cwd: {cwd_display}, started at {})",
+            command_line.join(" "),
+            Self::now_ms()
+        );
+        if let Some(event) = self.record_output(&run_id, "system", start_line).await {
+            emit_runner_output(app, &event);
         }
 
         if let Some(stdout) = stdout {
-            Self::spawn_output_reader(app.clone(), run_id.clone(), "stdout", stdout);
+            self.spawn_output_reader(app.clone(), run_id.clone(), "stdout", stdout, max_output_lines);
         }
         if let Some(stderr) = stderr {
-            Self::spawn_output_reader(app.clone(), run_id.clone(), "stderr", stderr);
+            self.spawn_output_reader(app.clone(), run_id.clone(), "stderr", stderr, max_output_lines);
         }
 
-        self.spawn_exit_watcher(app.clone(), run_id.clone(), child);
+        let timeout = input.timeout_sec.map(Duration::from_secs);
+        self.spawn_exit_watcher(app.clone(), run_id, child, timeout, permit);
 
-        Ok(RunnerStartResponse { run_id })
+        Ok(())
     }
 
-    pub async fn cancel(&self, run_id: &str) -> Result<bool, String> {
-        let child = {
+    /// PTY counterpart of `spawn_now`: same run bookkeeping, but the child is attached to a
+    /// pseudo-terminal so tools like cargo/npm/pip see a TTY and emit progress bars and color.
+    async fn spawn_now_pty(
+        self: &Arc<Self>,
+        app: &AppHandle,
+        prepared: PreparedRun,
+        permit: tokio::sync::OwnedSemaphorePermit,
+    ) -> Result<(), String> {
+        let PreparedRun { run_id, correlation_id, input, cwd, workspace_root, max_output_lines } = prepared;
+
+        let pty_system = native_pty_system();
+        let pair = pty_system
+            .openpty(PtySize { rows: 24, cols: 80, pixel_width: 0, pixel_height: 0 })
+            .map_err(|e| format!("failed to allocate pty: {e}"))?;
+
+        let mut command = CommandBuilder::new(&input.program);
+        command.args(&input.args);
+        if let Some(cwd) = &cwd {
+            command.cwd(cwd);
+        }
+        if !input.inherit_env.unwrap_or(true) {
+            command.env_clear();
+        }
+        if let Some(root) = &workspace_root {
+            for (key, value) in env_file::load_env_file(root) {
+                command.env(key, value);
+            }
+        }
+        if let Some(envs) = &input.env {
+            for (key, value) in envs {
+                command.env(key, value);
+            }
+        }
+
+        let child = pair
+            .slave
+            .spawn_command(command)
+            .map_err(|e| format!("pty spawn failed: {e}"))?;
+        // Drop our copy of the slave once the child holds its own; keeping it open would
+        // prevent the master from ever observing EOF/hangup.
+        drop(pair.slave);
+
+        let writer = pair
+            .master
+            .take_writer()
+            .map_err(|e| format!("failed to open pty writer: {e}"))?;
+        let reader = pair
+            .master
+            .try_clone_reader()
+            .map_err(|e| format!("failed to open pty reader: {e}"))?;
+
+        let pty_child: PtyChildHandle = Arc::new(StdMutex::new(child));
+        let pty_master: PtyMasterHandle = Arc::new(StdMutex::new(pair.master));
+        let pty_writer: PtyWriterHandle = Arc::new(StdMutex::new(writer));
+
+        {
+            let mut runs = self.runs.lock().await;
+            match runs.get_mut(&run_id) {
+                Some(runtime) => {
+                    runtime.pty_child = Some(pty_child.clone());
+                    runtime.pty_master = Some(pty_master.clone());
+                    runtime.pty_writer = Some(pty_writer.clone());
+                    runtime.queued = false;
+                }
+                None => {
+                    runs.insert(
+                        run_id.clone(),
+                        RunnerRuntime {
+                            child: None,
+                            stdin: None,
+                            pty_child: Some(pty_child.clone()),
+                            pty_master: Some(pty_master.clone()),
+                            pty_writer: Some(pty_writer.clone()),
+                            program: input.program.clone(),
+                            args: input.args.clone(),
+                            workspace_id: input.workspace_id.clone(),
+                            input: input.clone(),
+                            correlation_id,
+                            queued: false,
+                            started_at_ms: Self::now_ms(),
+                            finished_at_ms: None,
+                            exit_code: None,
+                            signal: None,
+                            timed_out: false,
+                            truncated: false,
+                            cancel_reason: None,
+                            output: VecDeque::new(),
+                            next_seq: 0,
+                        },
+                    );
+                }
+            }
+        }
+
+        let mut command_line = vec![input.program.clone()];
+        command_line.extend(input.args.iter().cloned());
+        let cwd_display = cwd
+            .as_ref()
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|| "(default)".to_string());
+        let start_line = format!(
+            "$ {} (pty, cwd: {cwd_display}, started at {})",
+            command_line.join(" "),
+            Self::now_ms()
+        );
+        if let Some(event) = self.record_output(&run_id, "system", start_line).await {
+            emit_runner_output(app, &event);
+        }
+
+        self.spawn_pty_output_reader(app.clone(), run_id.clone(), reader, max_output_lines);
+
+        let timeout = input.timeout_sec.map(Duration::from_secs);
+        self.spawn_pty_exit_watcher(app.clone(), run_id, pty_child, timeout, permit);
+
+        Ok(())
+    }
+
+    /// Reads raw PTY bytes on a blocking thread (portable-pty's reader is sync) and forwards
+    /// them line-by-line through the usual runner-output channel, ANSI escapes intact.
+    ///
+    /// `max_lines` bounds the total lines read; once hit, the blocking reader stops
+    /// pulling from the pty (the child keeps running) and the run is marked `truncated`.
+    fn spawn_pty_output_reader(
+        self: &Arc<Self>,
+        app: AppHandle,
+        run_id: String,
+        mut reader: Box<dyn StdRead + Send>,
+        max_lines: usize,
+    ) {
+        let manager = self.clone();
+        tokio::spawn(async move {
+            let (tx, mut rx) = tokio::sync::mpsc::channel::<String>(256);
+            let truncated = Arc::new(std::sync::atomic::AtomicBool::new(false));
+            let producer_truncated = truncated.clone();
+
+            tokio::task::spawn_blocking(move || {
+                let mut buf = [0u8; 4096];
+                let mut leftover = String::new();
+                let mut line_count = 0usize;
+                loop {
+                    match reader.read(&mut buf) {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => {
+                            leftover.push_str(&String::from_utf8_lossy(&buf[..n]));
+                            while let Some(pos) = leftover.find('\n') {
+                                let line = leftover[..pos].trim_end_matches('\r').to_string();
+                                leftover.drain(..=pos);
+                                line_count += 1;
+                                if line_count > max_lines {
+                                    producer_truncated.store(true, Ordering::Relaxed);
+                                    return;
+                                }
+                                if tx.blocking_send(line).is_err() {
+                                    return;
+                                }
+                            }
+                        }
+                    }
+                }
+                if !leftover.is_empty() {
+                    let _ = tx.blocking_send(leftover);
+                }
+            });
+
+            while let Some(line) = rx.recv().await {
+                if let Some(event) = manager.record_output(&run_id, "pty", line).await {
+                    emit_runner_output(&app, &event);
+                }
+            }
+
+            if truncated.load(Ordering::Relaxed) {
+                manager.mark_truncated(&run_id).await;
+                if let Some(event) = manager
+                    .record_output(&run_id, "system", "[truncated: output limit reached]".to_string())
+                    .await
+                {
+                    emit_runner_output(&app, &event);
+                }
+            }
+        });
+    }
+
+    fn spawn_pty_exit_watcher(
+        self: &Arc<Self>,
+        app: AppHandle,
+        run_id: String,
+        pty_child: PtyChildHandle,
+        timeout: Option<Duration>,
+        permit: tokio::sync::OwnedSemaphorePermit,
+    ) {
+        let manager = self.clone();
+        tokio::spawn(async move {
+            let _permit = permit;
+            let started = tokio::time::Instant::now();
+            loop {
+                tokio::time::sleep(Duration::from_millis(500)).await;
+
+                if let Some(timeout) = timeout {
+                    if started.elapsed() >= timeout {
+                        {
+                            let mut guard = pty_child.lock().unwrap();
+                            let _ = guard.kill();
+                            let _ = guard.wait();
+                        }
+
+                        let signal = Some("SIGHUP".to_string());
+                        manager.finish_run(&run_id, None, signal.clone(), true).await;
+
+                        if let Some(event) = manager
+                            .record_output(
+                                &run_id,
+                                "system",
+                                format!("run timed out after {}s and was killed", timeout.as_secs()),
+                            )
+                            .await
+                        {
+                            emit_runner_output(&app, &event);
+                        }
+
+                        let (started_ts, ended_ts, duration_ms) = manager.timing(&run_id).await;
+                        let event = RunnerExitEvent {
+                            run_id: run_id.clone(),
+                            code: None,
+                            signal,
+                            timed_out: true,
+                            cancelled_before_start: false,
+                            started_ts,
+                            ended_ts,
+                            duration_ms,
+                            correlation_id: manager.correlation_id(&run_id).await,
+                            truncated: manager.is_truncated(&run_id).await,
+                            cancel_reason: None,
+                        };
+                        emit_runner_exit(&app, &event);
+                        break;
+                    }
+                }
+
+                let exit = {
+                    let mut guard = pty_child.lock().unwrap();
+                    guard.try_wait().unwrap_or(None)
+                };
+
+                if let Some(status) = exit {
+                    let code = Some(status.exit_code() as i32);
+                    manager.finish_run(&run_id, code, None, false).await;
+
+                    let (started_ts, ended_ts, duration_ms) = manager.timing(&run_id).await;
+                    let event = RunnerExitEvent {
+                        run_id: run_id.clone(),
+                        code,
+                        signal: None,
+                        timed_out: false,
+                        cancelled_before_start: false,
+                        started_ts,
+                        ended_ts,
+                        duration_ms,
+                        correlation_id: manager.correlation_id(&run_id).await,
+                        truncated: manager.is_truncated(&run_id).await,
+                        cancel_reason: manager.cancel_reason(&run_id).await,
+                    };
+                    emit_runner_exit(&app, &event);
+                    break;
+                }
+            }
+        });
+    }
+
+    /// Grace period `cancel` waits for SIGINT to end the run before escalating to SIGKILL.
+    const GRACEFUL_CANCEL_GRACE_PERIOD: Duration = Duration::from_secs(3);
+
+    pub async fn cancel(
+        &self,
+        app: &AppHandle,
+        run_id: &str,
+        force: bool,
+        reason: Option<String>,
+    ) -> Result<bool, String> {
+        let (child, pty_child, pty_master) = {
+            let mut runs = self.runs.lock().await;
+            match runs.get_mut(run_id) {
+                Some(runtime) if runtime.pty_child.is_some() => {
+                    (None, runtime.pty_child.clone(), runtime.pty_master.take())
+                }
+                Some(runtime) => (runtime.child.clone(), None, None),
+                None => (None, None, None),
+            }
+        };
+
+        let Some(child) = child else {
+            if let Some(pty_child) = pty_child {
+                self.set_cancel_reason(run_id, reason).await;
+                return self.cancel_pty(pty_child, pty_master, force).await;
+            }
+            return self.cancel_queued(app, run_id, reason).await;
+        };
+
+        self.set_cancel_reason(run_id, reason).await;
+
+        if !force {
+            let pid = { child.lock().await.id() };
+            if let Some(pid) = pid {
+                Self::send_signal(pid, "-INT").await;
+                let mut guard = child.lock().await;
+                if tokio::time::timeout(Self::GRACEFUL_CANCEL_GRACE_PERIOD, guard.wait())
+                    .await
+                    .is_ok()
+                {
+                    return Ok(true);
+                }
+            }
+        }
+
+        let pid = { child.lock().await.id() };
+        if let Some(pid) = pid {
+            Self::send_signal(pid, "-KILL").await;
+        }
+
+        let mut guard = child.lock().await;
+        let _ = guard.start_kill();
+        let _ = tokio::time::timeout(Duration::from_secs(3), guard.wait()).await;
+        Ok(true)
+    }
+
+    /// Cancels a PTY-mode run. The master handle was already taken out of the run's
+    /// storage by the caller, so dropping it here closes the PTY and hangs up the
+    /// child cleanly (SIGHUP); `force` additionally sends a direct kill.
+    async fn cancel_pty(
+        &self,
+        pty_child: PtyChildHandle,
+        pty_master: Option<PtyMasterHandle>,
+        force: bool,
+    ) -> Result<bool, String> {
+        tokio::task::spawn_blocking(move || {
+            drop(pty_master);
+            if force {
+                let _ = pty_child.lock().unwrap().kill();
+            }
+            let _ = pty_child.lock().unwrap().wait();
+        })
+        .await
+        .map_err(|e| format!("pty cancel task failed: {e}"))?;
+        Ok(true)
+    }
+
+    /// Cancels a run that is still waiting for a concurrency slot. The deferred task
+    /// spawned by `execute_with_correlation` checks `finished_at_ms` once it acquires
+    /// its permit and skips spawning if this has already marked the run finished.
+    async fn cancel_queued(
+        &self,
+        app: &AppHandle,
+        run_id: &str,
+        reason: Option<String>,
+    ) -> Result<bool, String> {
+        let cancelled = {
+            let mut runs = self.runs.lock().await;
+            match runs.get_mut(run_id) {
+                Some(runtime) if runtime.queued && runtime.finished_at_ms.is_none() => {
+                    runtime.finished_at_ms = Some(Self::now_ms());
+                    runtime.queued = false;
+                    Some(runtime.correlation_id.clone())
+                }
+                _ => None,
+            }
+        };
+
+        let Some(correlation_id) = cancelled else {
+            return Ok(false);
+        };
+
+        let (started_ts, ended_ts, duration_ms) = self.timing(run_id).await;
+        let event = RunnerExitEvent {
+            run_id: run_id.to_string(),
+            code: None,
+            signal: None,
+            timed_out: false,
+            cancelled_before_start: true,
+            started_ts,
+            ended_ts,
+            duration_ms,
+            correlation_id: Some(correlation_id),
+            truncated: false,
+            cancel_reason: reason,
+        };
+        emit_runner_exit(app, &event);
+        Ok(true)
+    }
+
+    #[cfg(unix)]
+    async fn send_signal(pid: u32, signal: &str) {
+        // Negative pid targets the whole process group `execute` places the run's
+        // leader into, so descendants (e.g. a shell's child process) are reached too.
+        let _ = Command::new("kill")
+            .arg(signal)
+            .arg(format!("-{pid}"))
+            .status()
+            .await;
+    }
+
+    #[cfg(windows)]
+    async fn send_signal(_pid: u32, _signal: &str) {
+        // No portable way to deliver CTRL_C/CTRL_BREAK to an arbitrary child from here;
+        // callers fall back to Child::start_kill for the immediate process.
+    }
+
+    async fn running_or_queued_run_ids(&self, workspace_id: Option<&str>) -> Vec<String> {
+        let runs = self.runs.lock().await;
+        runs.iter()
+            .filter(|(_, runtime)| runtime.child.is_some() || runtime.pty_child.is_some() || runtime.queued)
+            .filter(|(_, runtime)| {
+                workspace_id
+                    .map(|id| runtime.workspace_id.as_deref() == Some(id))
+                    .unwrap_or(true)
+            })
+            .map(|(run_id, _)| run_id.clone())
+            .collect()
+    }
+
+    pub async fn cancel_all(&self, app: &AppHandle, force: bool) -> Vec<String> {
+        let run_ids = self.running_or_queued_run_ids(None).await;
+        self.cancel_many(app, run_ids, force).await
+    }
+
+    pub async fn cancel_for_workspace(&self, app: &AppHandle, workspace_id: &str, force: bool) -> Vec<String> {
+        let run_ids = self.running_or_queued_run_ids(Some(workspace_id)).await;
+        self.cancel_many(app, run_ids, force).await
+    }
+
+    async fn cancel_many(&self, app: &AppHandle, run_ids: Vec<String>, force: bool) -> Vec<String> {
+        let results = futures::future::join_all(run_ids.into_iter().map(|run_id| async move {
+            (run_id.clone(), self.cancel(app, &run_id, force, None).await)
+        }))
+        .await;
+
+        results
+            .into_iter()
+            .filter_map(|(run_id, result)| match result {
+                Ok(true) => Some(run_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub async fn list_runs(&self) -> RunnerListResponse {
+        let runs = self.runs.lock().await;
+        let mut summaries = runs
+            .iter()
+            .map(|(run_id, runtime)| RunnerRunSummary {
+                run_id: run_id.clone(),
+                program: runtime.program.clone(),
+                args: runtime.args.clone(),
+                workspace_id: runtime.workspace_id.clone(),
+                started_at_ms: runtime.started_at_ms,
+                finished_at_ms: runtime.finished_at_ms,
+                duration_ms: runtime
+                    .finished_at_ms
+                    .map(|finished| finished.saturating_sub(runtime.started_at_ms)),
+                running: runtime.child.is_some() || runtime.pty_child.is_some(),
+                queued: runtime.queued,
+                exit_code: runtime.exit_code,
+                signal: runtime.signal.clone(),
+                timed_out: runtime.timed_out,
+                truncated: runtime.truncated,
+            })
+            .collect::<Vec<_>>();
+
+        summaries.sort_by(|a, b| b.started_at_ms.cmp(&a.started_at_ms));
+        let queue_length = summaries.iter().filter(|summary| summary.queued).count() as u32;
+        RunnerListResponse { runs: summaries, queue_length }
+    }
+
+    async fn interactive_stdin(&self, run_id: &str) -> Result<Arc<Mutex<tokio::process::ChildStdin>>, String> {
+        let runs = self.runs.lock().await;
+        let runtime = runs
+            .get(run_id)
+            .ok_or_else(|| format!("run not found: {run_id}"))?;
+
+        if runtime.finished_at_ms.is_some() {
+            return Err(format!("run {run_id} has already finished"));
+        }
+
+        runtime
+            .stdin
+            .clone()
+            .ok_or_else(|| format!("run {run_id} is not interactive"))
+    }
+
+    pub async fn write_stdin(&self, run_id: &str, data: &str, append_newline: bool) -> Result<(), String> {
+        let pty_writer = {
+            let runs = self.runs.lock().await;
+            let runtime = runs
+                .get(run_id)
+                .ok_or_else(|| format!("run not found: {run_id}"))?;
+            if runtime.finished_at_ms.is_some() {
+                return Err(format!("run {run_id} has already finished"));
+            }
+            runtime.pty_writer.clone()
+        };
+
+        if let Some(writer) = pty_writer {
+            let mut payload = data.to_string();
+            if append_newline {
+                payload.push('\n');
+            }
+            let run_id = run_id.to_string();
+            return tokio::task::spawn_blocking(move || {
+                let mut guard = writer.lock().unwrap();
+                guard.write_all(payload.as_bytes()).and_then(|_| guard.flush())
+            })
+            .await
+            .map_err(|e| format!("pty write task failed: {e}"))?
+            .map_err(|e| format!("failed writing to run {run_id} pty: {e}"));
+        }
+
+        let stdin = self.interactive_stdin(run_id).await?;
+        let mut guard = stdin.lock().await;
+        guard
+            .write_all(data.as_bytes())
+            .await
+            .map_err(|e| format!("failed writing to run {run_id} stdin: {e}"))?;
+        if append_newline {
+            guard
+                .write_all(b"\n")
+                .await
+                .map_err(|e| format!("failed writing to run {run_id} stdin: {e}"))?;
+        }
+        Ok(())
+    }
+
+    pub async fn close_stdin(&self, run_id: &str) -> Result<(), String> {
+        let stdin = self.interactive_stdin(run_id).await?;
+        let mut guard = stdin.lock().await;
+        guard
+            .shutdown()
+            .await
+            .map_err(|e| format!("failed closing run {run_id} stdin: {e}"))?;
+
+        let mut runs = self.runs.lock().await;
+        if let Some(runtime) = runs.get_mut(run_id) {
+            runtime.stdin = None;
+        }
+        Ok(())
+    }
+
+    pub async fn resize(&self, run_id: &str, cols: u16, rows: u16) -> Result<(), String> {
+        let pty_master = {
             let runs = self.runs.lock().await;
-            runs.get(run_id).map(|runtime| runtime.child.clone())
+            runs.get(run_id)
+                .and_then(|runtime| runtime.pty_master.clone())
+                .ok_or_else(|| format!("run {run_id} is not a pty run"))?
         };
 
-        if let Some(child) = child {
-            let mut guard = child.lock().await;
-            let _ = guard.start_kill();
-            let _ = tokio::time::timeout(Duration::from_secs(3), guard.wait()).await;
-            Ok(true)
+        let run_id = run_id.to_string();
+        tokio::task::spawn_blocking(move || {
+            pty_master
+                .lock()
+                .unwrap()
+                .resize(PtySize { rows, cols, pixel_width: 0, pixel_height: 0 })
+        })
+        .await
+        .map_err(|e| format!("pty resize task failed: {e}"))?
+        .map_err(|e| format!("failed resizing pty for run {run_id}: {e}"))
+    }
+
+    pub async fn run_output(
+        &self,
+        run_id: &str,
+        after_seq: Option<u64>,
+        limit: Option<usize>,
+    ) -> Result<Vec<RunnerOutputEvent>, String> {
+        let runs = self.runs.lock().await;
+        let runtime = runs
+            .get(run_id)
+            .ok_or_else(|| format!("run not found: {run_id}"))?;
+
+        let mut events = runtime
+            .output
+            .iter()
+            .filter(|event| after_seq.map(|after| event.seq > after).unwrap_or(true))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if let Some(limit) = limit {
+            let len = events.len();
+            let start = len.saturating_sub(limit);
+            events = events.split_off(start);
+        }
+
+        Ok(events)
+    }
+
+    pub async fn export_run(
+        &self,
+        run_id: &str,
+        target_path: &str,
+        format: RunnerExportFormat,
+    ) -> Result<RunnerExportSummary, String> {
+        let (header, events, still_running) = {
+            let runs = self.runs.lock().await;
+            let runtime = runs
+                .get(run_id)
+                .ok_or_else(|| format!("run not found: {run_id}"))?;
+
+            let still_running = runtime.child.is_some() || runtime.pty_child.is_some() || runtime.queued;
+            let mut command_line = vec![runtime.program.clone()];
+            command_line.extend(runtime.args.iter().cloned());
+
+            let header = format!(
+                "command: {}\nworkspace: {}\nexit_code: {}\nsignal: {}\nduration_ms: {}\nstatus: {}",
+                command_line.join(" "),
+                runtime.workspace_id.as_deref().unwrap_or("(none)"),
+                runtime
+                    .exit_code
+                    .map(|code| code.to_string())
+                    .unwrap_or_else(|| "(none)".to_string()),
+                runtime.signal.as_deref().unwrap_or("(none)"),
+                runtime
+                    .finished_at_ms
+                    .map(|finished| finished.saturating_sub(runtime.started_at_ms).to_string())
+                    .unwrap_or_else(|| "(still running)".to_string()),
+                if still_running { "running" } else { "finished" },
+            );
+
+            (header, runtime.output.iter().cloned().collect::<Vec<_>>(), still_running)
+        };
+
+        let body = match format {
+            RunnerExportFormat::Text => events
+                .iter()
+                .map(|event| format!("[{}] {} {}", event.ts, event.stream, event.line))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            RunnerExportFormat::Ndjson => events
+                .iter()
+                .map(serde_json::to_string)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("failed to serialize run output as NDJSON: {e}"))?
+                .join("\n"),
+        };
+
+        let mut content = header;
+        content.push('\n');
+        if still_running {
+            content.push_str("note: run is still active; output exported so far\n");
+        }
+        content.push('\n');
+        content.push_str(&body);
+
+        let target = PathBuf::from(target_path);
+        let resolved = if target.is_absolute() {
+            target
         } else {
-            Ok(false)
+            std::env::current_dir()
+                .map_err(|e| format!("failed to read cwd: {e}"))?
+                .join(target)
+        };
+
+        if let Some(parent) = resolved.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| format!("failed to create export parent {}: {e}", parent.display()))?;
+        }
+
+        tokio::fs::write(&resolved, content)
+            .await
+            .map_err(|e| format!("failed writing run export to {}: {e}", resolved.display()))?;
+
+        Ok(RunnerExportSummary {
+            lines_written: events.len() as u64,
+            still_running,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controlroom::types::WorkspaceConfig;
+
+    fn config_with_workspace(path: &str) -> ControlRoomConfig {
+        let mut config = ControlRoomConfig::default();
+        config.workspaces.push(WorkspaceConfig {
+            id: "ws".to_string(),
+            name: "ws".to_string(),
+            path: path.to_string(),
+            ignore: None,
+            hide_patterns: None,
+            extra_paths: None,
+            read_only: None,
+        });
+        config
+    }
+
+    #[test]
+    fn resolve_run_cwd_rejects_traversal_outside_workspace() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-runner-test-ws");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let result = RunnerManager::resolve_run_cwd(&config, Some("ws"), Some("../../etc"));
+        assert!(result.is_err());
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn resolve_run_cwd_accepts_subdirectory() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-runner-test-ws-sub");
+        let sub_dir = workspace_dir.join("nested");
+        std::fs::create_dir_all(&sub_dir).unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let result = RunnerManager::resolve_run_cwd(&config, Some("ws"), Some("nested")).unwrap();
+        assert!(result.unwrap().ends_with("nested"));
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn execute_propagates_env_vars() {
+        let mut env = HashMap::new();
+        env.insert("CONTROLROOM_TEST_VAR".to_string(), "hello".to_string());
+
+        let input = RunnerCommandInput {
+            workspace_id: None,
+            program: "true".to_string(),
+            args: Vec::new(),
+            cwd: None,
+            env: Some(env),
+            shell: None,
+            timeout_sec: None,
+            interactive: None,
+            pty: None,
+            stdin_data: None,
+            inherit_env: None,
+            max_output_lines: None,
+        };
+
+        let mut command = Command::new(&input.program);
+        command.args(&input.args);
+        if let Some(envs) = &input.env {
+            command.envs(envs);
+        }
+
+        let configured = command
+            .as_std()
+            .get_envs()
+            .find(|(key, _)| *key == "CONTROLROOM_TEST_VAR")
+            .and_then(|(_, value)| value)
+            .map(|value| value.to_string_lossy().to_string());
+
+        assert_eq!(configured.as_deref(), Some("hello"));
+    }
+
+    #[tokio::test]
+    async fn output_batching_drops_and_marks_lines_under_backpressure() {
+        let manager = RunnerManager::new();
+        let run_id = "stress-run".to_string();
+        {
+            let mut runs = manager.runs.lock().await;
+            runs.insert(
+                run_id.clone(),
+                RunnerRuntime {
+                    child: None,
+                    stdin: None,
+                    pty_child: None,
+                    pty_master: None,
+                    pty_writer: None,
+                    program: "seq".to_string(),
+                    args: Vec::new(),
+                    workspace_id: None,
+                    input: RunnerCommandInput {
+                        workspace_id: None,
+                        program: "seq".to_string(),
+                        args: Vec::new(),
+                        cwd: None,
+                        env: None,
+                        shell: None,
+                        timeout_sec: None,
+                        interactive: None,
+                        pty: None,
+                        stdin_data: None,
+                        inherit_env: None,
+                        max_output_lines: None,
+                    },
+                    correlation_id: run_id.clone(),
+                    queued: false,
+                    started_at_ms: 0,
+                    finished_at_ms: None,
+                    exit_code: None,
+                    signal: None,
+                    timed_out: false,
+                    truncated: false,
+                    cancel_reason: None,
+                    output: VecDeque::new(),
+                    next_seq: 0,
+                },
+            );
+        }
+
+        // Mirrors spawn_output_reader's bounded channel: a producer far outrunning a
+        // small buffer, exactly like a `yes`-style command emitting 100k lines quickly.
+        let high_water_mark = 64usize;
+        let (tx, mut rx) = tokio::sync::mpsc::channel::<String>(high_water_mark);
+        let dropped = AtomicU64::new(0);
+        for i in 0..100_000u32 {
+            if tx.try_send(i.to_string()).is_err() {
+                dropped.fetch_add(1, Ordering::Relaxed);
+            }
         }
+        drop(tx);
+
+        let mut batch = Vec::new();
+        let mut total_events = 0usize;
+        let mut saw_backpressure_marker = false;
+        let mut observed_drop = false;
+        while let Some(line) = rx.recv().await {
+            batch.push(line);
+            if batch.len() >= OUTPUT_BATCH_MAX_LINES {
+                observed_drop |= dropped.load(Ordering::Relaxed) > 0;
+                let events = manager.drain_output_batch(&run_id, "stdout", &mut batch, &dropped).await;
+                total_events += events.len();
+                saw_backpressure_marker |= events
+                    .iter()
+                    .any(|event| event.stream == "system" && event.line.contains("dropped due to backpressure"));
+            }
+        }
+        observed_drop |= dropped.load(Ordering::Relaxed) > 0;
+        let events = manager.drain_output_batch(&run_id, "stdout", &mut batch, &dropped).await;
+        total_events += events.len();
+        saw_backpressure_marker |= events
+            .iter()
+            .any(|event| event.stream == "system" && event.line.contains("dropped due to backpressure"));
+
+        assert!(observed_drop, "expected the bounded channel to drop some of the 100k lines");
+        assert!(saw_backpressure_marker, "expected a backpressure marker event in run history");
+        assert!(total_events > 0);
+
+        let runs = manager.runs.lock().await;
+        let runtime = runs.get(&run_id).unwrap();
+        assert!(runtime.output.len() <= manager.max_output_per_run);
+    }
+
+    #[tokio::test]
+    async fn finish_run_records_a_history_entry() {
+        let manager = Arc::new(RunnerManager::new());
+        let run_id = "history-run".to_string();
+        {
+            let mut runs = manager.runs.lock().await;
+            runs.insert(
+                run_id.clone(),
+                RunnerRuntime {
+                    child: None,
+                    stdin: None,
+                    pty_child: None,
+                    pty_master: None,
+                    pty_writer: None,
+                    program: "echo".to_string(),
+                    args: vec!["hi".to_string()],
+                    workspace_id: None,
+                    input: RunnerCommandInput {
+                        workspace_id: None,
+                        program: "echo".to_string(),
+                        args: vec!["hi".to_string()],
+                        cwd: None,
+                        env: None,
+                        shell: None,
+                        timeout_sec: None,
+                        interactive: None,
+                        pty: None,
+                        stdin_data: None,
+                        inherit_env: None,
+                        max_output_lines: None,
+                    },
+                    correlation_id: run_id.clone(),
+                    queued: false,
+                    started_at_ms: 1_000,
+                    finished_at_ms: None,
+                    exit_code: None,
+                    signal: None,
+                    timed_out: false,
+                    truncated: false,
+                    cancel_reason: None,
+                    output: VecDeque::new(),
+                    next_seq: 0,
+                },
+            );
+        }
+
+        manager.finish_run(&run_id, Some(0), None, false).await;
+
+        let history = manager.list_run_history().await;
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].run_id, run_id);
+        assert_eq!(history[0].program, "echo");
+        assert_eq!(history[0].exit_code, Some(0));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn signal_name_reports_sigterm_for_killed_process() {
+        let mut child = Command::new("sleep").arg("30").spawn().unwrap();
+        let pid = child.id().unwrap().to_string();
+
+        std::process::Command::new("kill")
+            .arg("-TERM")
+            .arg(&pid)
+            .status()
+            .unwrap();
+
+        let status = child.wait().await.unwrap();
+        assert_eq!(RunnerManager::signal_name(&status).as_deref(), Some("SIGTERM"));
+    }
+
+    #[test]
+    fn runner_exit_event_serializes_timing_fields_as_camel_case() {
+        let event = RunnerExitEvent {
+            run_id: "run-1".to_string(),
+            code: Some(0),
+            signal: None,
+            timed_out: false,
+            cancelled_before_start: false,
+            started_ts: 1_000,
+            ended_ts: 4_500,
+            duration_ms: 3_500,
+            correlation_id: Some("run-1".to_string()),
+            truncated: false,
+            cancel_reason: None,
+        };
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["startedTs"], 1_000);
+        assert_eq!(json["endedTs"], 4_500);
+        assert_eq!(json["durationMs"], 3_500);
+        assert_eq!(json["cancelledBeforeStart"], false);
+
+        let round_tripped: RunnerExitEvent = serde_json::from_value(json).unwrap();
+        assert_eq!(round_tripped.duration_ms, 3_500);
+    }
+
+    fn config_with_allowed_programs(allowed: Vec<&str>) -> ControlRoomConfig {
+        let mut config = ControlRoomConfig::default();
+        config.runner.allowed_programs = Some(allowed.into_iter().map(str::to_string).collect());
+        config
+    }
+
+    #[test]
+    fn glob_match_supports_a_single_wildcard() {
+        assert!(RunnerManager::glob_match("sh", "sh"));
+        assert!(RunnerManager::glob_match("/usr/bin/*", "/usr/bin/sh"));
+        assert!(!RunnerManager::glob_match("/usr/bin/*", "/bin/sh"));
+        assert!(!RunnerManager::glob_match("sh", "bash"));
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn check_program_allowed_gates_shell_mode_on_sh() {
+        let allowed_sh = config_with_allowed_programs(vec!["sh"]);
+        assert!(RunnerManager::check_program_allowed(&allowed_sh, "sh", true).is_ok());
+
+        let allowed_cmd_only = config_with_allowed_programs(vec!["cmd"]);
+        assert!(RunnerManager::check_program_allowed(&allowed_cmd_only, "sh", true).is_err());
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn check_program_allowed_gates_shell_mode_on_cmd() {
+        let allowed_cmd = config_with_allowed_programs(vec!["cmd"]);
+        assert!(RunnerManager::check_program_allowed(&allowed_cmd, "cmd", true).is_ok());
+
+        let allowed_sh_only = config_with_allowed_programs(vec!["sh"]);
+        assert!(RunnerManager::check_program_allowed(&allowed_sh_only, "cmd", true).is_err());
+    }
+
+    #[test]
+    fn check_program_allowed_ignores_shell_binary_when_not_in_shell_mode() {
+        let config = config_with_allowed_programs(vec!["true"]);
+        assert!(RunnerManager::check_program_allowed(&config, "true", false).is_ok());
+        assert!(RunnerManager::check_program_allowed(&config, "false", false).is_err());
+    }
+
+    #[test]
+    fn check_program_allowed_permits_everything_without_an_allowlist() {
+        let config = ControlRoomConfig::default();
+        assert!(RunnerManager::check_program_allowed(&config, "anything", true).is_ok());
     }
 }
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct SafeCommandSpec {
     pub program: String,
@@ -10,12 +10,46 @@ pub struct SafeCommandSpec {
     pub env: Option<HashMap<String, String>>,
 }
 
+/// Shell metacharacters that indicate `program` is actually a shell command line rather
+/// than a single executable, since `Command::new` never invokes a shell to interpret them.
+const SHELL_METACHARACTERS: &[char] = &[' ', ';', '|', '>', '<'];
+
+impl SafeCommandSpec {
+    /// Rejects `program` values that look like a full shell command line (e.g.
+    /// `"bash -c 'something'"` or `"a && b"`) instead of a single executable, since
+    /// `Command::new(&spec.program)` never invokes a shell and would otherwise either fail
+    /// with a confusing "file not found" or, if a same-named binary exists, run something
+    /// other than what the user intended.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.program.contains("&&") || self.program.contains("||") {
+            return Err(format!(
+                "command program {:?} looks like a shell command line; put each argument in `args` instead",
+                self.program
+            ));
+        }
+        if let Some(c) = self.program.chars().find(|c| SHELL_METACHARACTERS.contains(c)) {
+            return Err(format!(
+                "command program {:?} contains {:?}, which is not interpreted by a shell here; put each argument in `args` instead",
+                self.program, c
+            ));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ServiceHealthSpec {
     pub program: String,
     pub args: Vec<String>,
     pub interval_sec: Option<u64>,
+    pub check_type: Option<String>,
+    pub tcp_host: Option<String>,
+    pub tcp_port: Option<u16>,
+    pub http_url: Option<String>,
+    pub http_method: Option<String>,
+    pub http_expected_status: Option<(u16, u16)>,
+    pub http_headers: Option<HashMap<String, String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,20 +58,60 @@ pub struct ServiceConfig {
     pub id: String,
     pub name: String,
     pub tier: Option<String>,
+    pub tier_order: Option<u32>,
+    pub depends_on: Option<Vec<String>>,
     pub cwd: Option<String>,
+    pub pre_start: Option<SafeCommandSpec>,
     pub start: SafeCommandSpec,
     pub stop: Option<SafeCommandSpec>,
+    pub post_stop: Option<SafeCommandSpec>,
     pub restart: Option<SafeCommandSpec>,
     pub health: Option<ServiceHealthSpec>,
+    pub ready_probe: Option<ServiceHealthSpec>,
+    pub startup_timeout_ms: Option<u64>,
     pub log_sources: Option<Vec<String>>,
+    pub inherit_env: Option<bool>,
+    pub env_remove: Option<Vec<String>>,
+    pub log_max_age_sec: Option<u64>,
 }
 
+/// An additional root folder attached to a `WorkspaceConfig`, for projects that span
+/// more than one directory (e.g. a code dir plus a separate data dir). Its `id` is
+/// what `WorkspaceEntry::root_id` reports for entries found under it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceRoot {
+    pub id: String,
+    pub path: String,
+}
+
+/// `root_id` used for `WorkspaceConfig::path` itself, so entries there and entries
+/// under an `extraPaths` root can be told apart in listings/search results.
+pub const PRIMARY_WORKSPACE_ROOT_ID: &str = "primary";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WorkspaceConfig {
     pub id: String,
     pub name: String,
     pub path: String,
+    #[serde(default)]
+    pub ignore: Option<Vec<String>>,
+    /// Glob patterns for entries hidden from listings/trees by default (e.g. `.DS_Store`,
+    /// `*.pyc`, editor swap files). Direct reads/writes by path still work; only listing
+    /// output is filtered.
+    #[serde(default)]
+    pub hide_patterns: Option<Vec<String>>,
+    /// Additional roots merged into this workspace's listings/reads/search alongside
+    /// `path` (which always keeps root id `"primary"`). Roots must not overlap each
+    /// other or `path`; overlapping roots are dropped at config load with a
+    /// `ConfigIssue`.
+    #[serde(default)]
+    pub extra_paths: Option<Vec<WorkspaceRoot>>,
+    /// When set, every write/create/delete/move operation against this workspace is
+    /// rejected with a policy error, regardless of which root it targets.
+    #[serde(default)]
+    pub read_only: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -68,30 +142,195 @@ pub struct UiConfig {
     pub layout: UiLayout,
 }
 
+/// Result of a `checkout` call. Modeled as a tagged enum rather than an error string so
+/// the UI can render the specific blocking files as its own affordance (e.g. "stash and
+/// switch anyway") instead of parsing them back out of an error message.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum GitCheckoutResult {
+    Switched { branch: Option<String>, commit: String, detached: bool },
+    DirtyTreeBlocked { files: Vec<String> },
+}
+
+/// Result of `sync_state`. `NotApplicable` covers both "not a git repository" and "no
+/// upstream configured" — neither is an error, just a state the UI should render as
+/// "nothing to sync" rather than a failure.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum GitSyncState {
+    NotApplicable { workspace_id: String, reason: String },
+    Tracking {
+        workspace_id: String,
+        branch: String,
+        upstream: String,
+        ahead: u32,
+        behind: u32,
+        dirty: bool,
+        /// Milliseconds since the epoch that `.git/FETCH_HEAD` was last written, or
+        /// `None` if a fetch has never run.
+        last_fetch_ms: Option<u64>,
+    },
+}
+
+/// Payload for `controlroom://git-progress`, emitted once per progress line git prints
+/// to stderr while `fetch`/`pull`/`push` run. Git overwrites progress lines with `\r`
+/// rather than terminating them with `\n`, so the emitter splits on either.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitProgressEvent {
+    pub workspace_id: String,
+    pub operation: String,
+    pub message: String,
+    pub percent: Option<f32>,
+}
+
+/// Result of `fetch`. `Rejected` carries git's own reported reason rather than a raw
+/// stderr dump.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum GitFetchResult {
+    UpToDate,
+    Updated { commits_transferred: u32 },
+    Rejected { reason: String },
+}
+
+/// Result of `pull`. `FastForward` and `Merged` distinguish the two ways new commits can
+/// land locally, since a UI would want to explain a surprise merge commit differently
+/// from a clean fast-forward.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum GitPullResult {
+    UpToDate,
+    FastForward { commits_transferred: u32 },
+    Merged { commits_transferred: u32 },
+    Rejected { reason: String },
+}
+
+/// Result of `push`. `commits_transferred` is the number of commits the local branch
+/// was ahead of its remote-tracking ref immediately before the push ran.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum GitPushResult {
+    UpToDate,
+    Accepted { commits_transferred: u32 },
+    Rejected { reason: String },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitTag {
+    pub name: String,
+    pub target_hash: String,
+    /// `Some` for an annotated tag's subject line, `None` for a lightweight tag.
+    pub annotation: Option<String>,
+    /// ISO-8601 date; the tag's own date for annotated tags, the target commit's date
+    /// for lightweight ones (git has no creation date for those).
+    pub date: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitStashEntry {
+    pub index: u32,
+    pub message: String,
+    pub branch: Option<String>,
+}
+
+/// Result of `stash_apply`. Modeled as a tagged enum, matching `GitCheckoutResult`, so a
+/// conflicted apply can hand back the specific conflicting files instead of an error
+/// string the UI would have to parse.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum GitStashApplyResult {
+    Applied,
+    Conflicted { files: Vec<String> },
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GitConfig {
     pub enabled: bool,
     pub max_commits: u32,
+    /// Gates every git command that mutates the workspace (stage/unstage/commit,
+    /// checkout, fetch/pull/push, stash push/apply). Defaults to `false` so read-only
+    /// deployments keep today's inspect-only behavior after upgrading.
+    #[serde(default)]
+    pub allow_write: bool,
+}
+
+/// A single condition under which a video feed should auto-pause. Modeled as a tagged
+/// enum (rather than more `Option<bool>` fields on `VideoAutoPauseConfig`) so a future
+/// policy that isn't a plain on/off switch — like `WhenHighLoad`'s thresholds — has
+/// somewhere to carry its own data.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum VideoAutoPausePolicy {
+    WhenModeNotMultimedia,
+    WhenPanelHidden,
+    WhenAppHidden,
+    WhenHighLoad { latency_ms: u64, consecutive_samples: u32 },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VideoAutoPauseConfig {
+    #[serde(default)]
+    pub policies: Vec<VideoAutoPausePolicy>,
+    /// Deprecated flat booleans, superseded by `policies`; still read directly (not
+    /// nested) so existing config files keep working during the deprecation window.
+    #[serde(default)]
     pub when_mode_not_multimedia: Option<bool>,
+    #[serde(default)]
     pub when_panel_hidden: Option<bool>,
+    #[serde(default)]
     pub when_app_hidden: Option<bool>,
+    #[serde(default)]
     pub when_high_load: Option<bool>,
+    #[serde(default)]
     pub high_load_latency_ms: Option<u64>,
+    #[serde(default)]
     pub high_load_consecutive_samples: Option<u32>,
 }
 
+impl VideoAutoPauseConfig {
+    /// Merges the typed `policies` list with any legacy boolean fields still set, so
+    /// callers only need to look in one place regardless of which style a config file
+    /// used. `WhenHighLoad` from a legacy config defaults its thresholds to 0 if the
+    /// paired latency/sample-count fields weren't also set.
+    pub fn effective_policies(&self) -> Vec<VideoAutoPausePolicy> {
+        let mut policies = self.policies.clone();
+
+        if self.when_mode_not_multimedia == Some(true)
+            && !policies.contains(&VideoAutoPausePolicy::WhenModeNotMultimedia)
+        {
+            policies.push(VideoAutoPausePolicy::WhenModeNotMultimedia);
+        }
+        if self.when_panel_hidden == Some(true) && !policies.contains(&VideoAutoPausePolicy::WhenPanelHidden) {
+            policies.push(VideoAutoPausePolicy::WhenPanelHidden);
+        }
+        if self.when_app_hidden == Some(true) && !policies.contains(&VideoAutoPausePolicy::WhenAppHidden) {
+            policies.push(VideoAutoPausePolicy::WhenAppHidden);
+        }
+        if self.when_high_load == Some(true)
+            && !policies.iter().any(|policy| matches!(policy, VideoAutoPausePolicy::WhenHighLoad { .. }))
+        {
+            policies.push(VideoAutoPausePolicy::WhenHighLoad {
+                latency_ms: self.high_load_latency_ms.unwrap_or(0),
+                consecutive_samples: self.high_load_consecutive_samples.unwrap_or(0),
+            });
+        }
+
+        policies
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VideoNativeLauncherConfig {
     pub id: String,
     pub name: String,
     pub command: SafeCommandSpec,
+    pub allowed_url_schemes: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -100,6 +339,15 @@ pub struct VideoSnapshotConfig {
     pub enabled: Option<bool>,
     pub timeout_ms: Option<u64>,
     pub analyzer_command: Option<SafeCommandSpec>,
+    pub output_format: Option<String>,
+    pub jpeg_quality: Option<u8>,
+    pub max_dimension: Option<u32>,
+    pub min_dimension: Option<u32>,
+    pub cache_ttl_ms: Option<u64>,
+    /// When set, `VideoManager::start_snapshot_scheduler` periodically emits a
+    /// `controlroom://snapshot-request` event at this interval instead of waiting for the
+    /// frontend to drive each request.
+    pub schedule_interval_ms: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -112,6 +360,18 @@ pub struct VideoWallConfig {
     pub snapshot: Option<VideoSnapshotConfig>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunnerPreset {
+    pub id: String,
+    pub name: String,
+    pub workspace_id: Option<String>,
+    pub program: String,
+    pub args: Vec<String>,
+    pub env: Option<HashMap<String, String>>,
+    pub cwd: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ControlRoomConfig {
@@ -121,6 +381,11 @@ pub struct ControlRoomConfig {
     pub workspaces: Vec<WorkspaceConfig>,
     pub git: GitConfig,
     pub video_wall: Option<VideoWallConfig>,
+    #[serde(default)]
+    pub runner_presets: Vec<RunnerPreset>,
+    #[serde(default)]
+    pub runner: RunnerConfig,
+    pub default_log_max_age_sec: Option<u64>,
 }
 
 impl Default for ControlRoomConfig {
@@ -145,12 +410,30 @@ impl Default for ControlRoomConfig {
             git: GitConfig {
                 enabled: true,
                 max_commits: 30,
+                allow_write: false,
             },
             video_wall: None,
+            runner_presets: Vec::new(),
+            runner: RunnerConfig::default(),
+            default_log_max_age_sec: None,
         }
     }
 }
 
+/// Locked-down deployments set `allowedPrograms` to restrict what
+/// `controlroom_runner_execute` will spawn; left unset, behavior is unrestricted.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RunnerConfig {
+    pub allowed_programs: Option<Vec<String>>,
+    /// Default cap on output lines read from each output stream of a run (stdout and
+    /// stderr are capped separately); a run's own
+    /// `max_output_lines` overrides this. `None` here falls back to `RunnerManager`'s
+    /// built-in default.
+    #[serde(default)]
+    pub max_output_lines: Option<usize>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum ServiceState {
@@ -159,6 +442,9 @@ pub enum ServiceState {
     Error,
     Starting,
     Stopping,
+    /// Frozen via `SIGSTOP` (Unix only) — the process still exists but is not
+    /// scheduled to run until resumed with `SIGCONT`.
+    Paused,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -170,6 +456,11 @@ pub struct ServiceStatus {
     pub uptime_sec: Option<u64>,
     pub last_error: Option<String>,
     pub correlation_id: Option<String>,
+    /// `None` until `refresh_process_metrics` has sampled this PID at least once.
+    #[serde(default)]
+    pub cpu_percent: Option<f32>,
+    #[serde(default)]
+    pub memory_rss_bytes: Option<u64>,
 }
 
 impl ServiceStatus {
@@ -181,10 +472,25 @@ impl ServiceStatus {
             uptime_sec: None,
             last_error: None,
             correlation_id: None,
+            cpu_percent: None,
+            memory_rss_bytes: None,
         }
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceStatusSummary {
+    pub running: u32,
+    pub stopped: u32,
+    pub starting: u32,
+    pub stopping: u32,
+    pub error: u32,
+    pub restarting: u32,
+    pub paused: u32,
+    pub total: u32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ServiceLogEvent {
@@ -194,6 +500,29 @@ pub struct ServiceLogEvent {
     pub level: String,
     pub line: String,
     pub correlation_id: Option<String>,
+    /// The untouched source line, populated only when `line` looks like a structured
+    /// JSON log record, so the frontend can offer a raw view alongside the rendered one.
+    #[serde(default)]
+    pub raw: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceLogFilter {
+    pub service_ids: Option<Vec<String>>,
+    pub level: Option<String>,
+    pub stream: Option<String>,
+    pub since_ts: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceLogStats {
+    pub service_id: String,
+    pub total: usize,
+    pub by_level: HashMap<String, usize>,
+    pub oldest_ts: Option<u64>,
+    pub newest_ts: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -202,12 +531,30 @@ pub struct RunnerCommandInput {
     pub workspace_id: Option<String>,
     pub program: String,
     pub args: Vec<String>,
+    pub cwd: Option<String>,
+    pub env: Option<HashMap<String, String>>,
+    pub shell: Option<bool>,
+    pub timeout_sec: Option<u64>,
+    pub interactive: Option<bool>,
+    pub pty: Option<bool>,
+    /// One-shot stdin: written to the child then the handle is dropped to signal EOF.
+    /// Distinct from `interactive`, which keeps stdin open for later writes.
+    pub stdin_data: Option<String>,
+    /// Whether the child inherits the parent process's environment. Defaults to `true`
+    /// when unset. `.env` file values and explicit `env` entries are layered on top
+    /// either way.
+    pub inherit_env: Option<bool>,
+    /// Caps output lines read from each output stream of this run; overrides
+    /// `RunnerConfig::max_output_lines`.
+    pub max_output_lines: Option<usize>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RunnerStartResponse {
     pub run_id: String,
+    pub queued: bool,
+    pub rerun_of: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -216,17 +563,75 @@ pub struct RunnerOutputEvent {
     pub run_id: String,
     pub stream: String,
     pub ts: u64,
+    pub seq: u64,
     pub line: String,
+    pub level: String,
     pub correlation_id: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunnerOutputBatchEvent {
+    pub run_id: String,
+    pub events: Vec<RunnerOutputEvent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunnerRunSummary {
+    pub run_id: String,
+    pub program: String,
+    pub args: Vec<String>,
+    pub workspace_id: Option<String>,
+    pub started_at_ms: u64,
+    pub finished_at_ms: Option<u64>,
+    pub duration_ms: Option<u64>,
+    pub running: bool,
+    pub queued: bool,
+    pub exit_code: Option<i32>,
+    pub signal: Option<String>,
+    pub timed_out: bool,
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunnerListResponse {
+    pub runs: Vec<RunnerRunSummary>,
+    pub queue_length: u32,
+}
+
+/// A post-mortem record of a completed run, kept around after its live `RunnerRuntime`
+/// entry has been pruned so the frontend can still show what ran.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunnerHistoryEntry {
+    pub run_id: String,
+    pub program: String,
+    pub args: Vec<String>,
+    pub workspace_id: Option<String>,
+    pub started_at_ms: u64,
+    pub ended_at_ms: u64,
+    pub exit_code: Option<i32>,
+    pub timed_out: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RunnerExitEvent {
     pub run_id: String,
     pub code: Option<i32>,
     pub signal: Option<String>,
+    pub timed_out: bool,
+    pub cancelled_before_start: bool,
+    pub started_ts: u64,
+    pub ended_ts: u64,
+    pub duration_ms: u64,
     pub correlation_id: Option<String>,
+    /// Set when the run's output was cut off after hitting `max_output_lines`.
+    pub truncated: bool,
+    /// The reason passed to `runner_cancel`, if this run ended via an explicit cancel.
+    pub cancel_reason: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -234,9 +639,357 @@ pub struct RunnerExitEvent {
 pub struct WorkspaceEntry {
     pub name: String,
     pub path: String,
+    /// Which workspace root this entry was found under (`"primary"` for
+    /// `WorkspaceConfig::path`, or an `extraPaths` root's `id`).
+    pub root_id: String,
     pub is_directory: bool,
     pub size: Option<u64>,
     pub modified_ms: Option<u64>,
+    pub is_symlink: bool,
+    /// The link's target, reported as-is for display but never dereferenced outside
+    /// the workspace base (a link pointing outside is still reported, just not walked).
+    pub symlink_target: Option<String>,
+    pub readonly: bool,
+    /// Unix permission bits (e.g. `0o755`), `None` on platforms without a notion of
+    /// one (Windows only has the `readonly` attribute above).
+    pub mode: Option<u32>,
+    /// Cheap content fingerprint for change detection, only populated when the list
+    /// request set `WorkspaceListOptions::include_hashes`; always `None` for
+    /// directories and for files over 1 MB (hashing them would be too slow to list).
+    #[serde(default)]
+    pub content_hash: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkspaceEntrySortBy {
+    Name,
+    Size,
+    Modified,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceListOptions {
+    pub offset: Option<u32>,
+    pub limit: Option<u32>,
+    pub sort_by: Option<WorkspaceEntrySortBy>,
+    pub filter: Option<String>,
+    /// Show dotfiles/dot-directories. Defaults to `false`.
+    pub include_hidden: Option<bool>,
+    /// Whitelist of file extensions (without the leading dot, case-insensitive).
+    /// Directories are always shown regardless of this filter.
+    pub extensions: Option<Vec<String>>,
+    /// Restrict the listing to one workspace root. Omitted, or `None`, lists every
+    /// root (`"primary"` plus any `extraPaths`) and merges the results.
+    #[serde(default)]
+    pub root_id: Option<String>,
+    /// Compute `WorkspaceEntry::content_hash` for files. Defaults to `false`, since
+    /// hashing means reading every listed file; files over 1 MB are skipped even when
+    /// this is set.
+    #[serde(default)]
+    pub include_hashes: Option<bool>,
+}
+
+/// A page of `list_workspace_entries`. `total` counts every entry matching `filter`
+/// before pagination, so the frontend can compute page count without a second call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceListResult {
+    pub entries: Vec<WorkspaceEntry>,
+    pub total: u64,
+    pub offset: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkspaceEntryKind {
+    File,
+    Directory,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceSearchResult {
+    pub entries: Vec<WorkspaceEntry>,
+    pub truncated: bool,
+}
+
+/// One file remembered by `RecentFilesManager`, persisted per workspace so the editor
+/// can offer a recent-files list across sessions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentFileEntry {
+    pub path: String,
+    pub root_id: String,
+    pub last_opened_ms: u64,
+    pub open_count: u32,
+}
+
+/// One result of `controlroom_workspace_quick_open`: either a recently opened file
+/// (`last_opened_ms`/`open_count` set) or a name-search match with no open history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceQuickOpenEntry {
+    pub path: String,
+    pub root_id: String,
+    pub last_opened_ms: Option<u64>,
+    pub open_count: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceTreeNode {
+    pub entry: WorkspaceEntry,
+    pub children: Vec<WorkspaceTreeNode>,
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceBinaryFile {
+    pub base64: String,
+    pub mime: String,
+    pub size: u64,
+    pub is_truncated: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceDiskUsageChild {
+    pub name: String,
+    pub path: String,
+    pub is_directory: bool,
+    pub total_bytes: u64,
+    pub file_count: u64,
+    pub dir_count: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceLargeFile {
+    pub path: String,
+    pub size: u64,
+}
+
+/// A disk-usage summary for a directory: cumulative size per immediate child plus the
+/// largest files found anywhere in the walked subtree. `completed` is `false` when the
+/// walk's timeout was hit before the whole subtree could be scanned, in which case the
+/// totals are a lower bound rather than exact.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceDiskUsage {
+    pub path: String,
+    pub total_bytes: u64,
+    pub file_count: u64,
+    pub dir_count: u64,
+    pub children: Vec<WorkspaceDiskUsageChild>,
+    pub top_files: Vec<WorkspaceLargeFile>,
+    pub completed: bool,
+}
+
+/// A text file's content alongside the on-disk state it was read at, so a later
+/// `write_workspace_file` call can detect whether the file changed underneath it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceFileContent {
+    pub content: String,
+    pub modified_ms: Option<u64>,
+    pub hash: String,
+    /// The encoding label (e.g. `"UTF-8"`, `"UTF-16LE"`, `"windows-1252"`) the file was
+    /// decoded from. Suffixed with `" (lossy)"` when no encoding could be reliably
+    /// detected and the content was decoded as UTF-8 with replacement characters.
+    pub encoding: String,
+    pub has_bom: bool,
+}
+
+/// Returned instead of a plain error when a write's `expected_modified_ms`/`expected_hash`
+/// no longer match the file on disk, so the frontend can offer a diff/merge instead of
+/// just surfacing "write failed".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceWriteConflict {
+    pub current_hash: String,
+    pub current_modified_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceWriteResult {
+    pub written: bool,
+    pub conflict: Option<WorkspaceWriteConflict>,
+}
+
+/// A byte range of a (possibly much larger) file, as returned by a ranged read or a
+/// tail. `start`/`end` describe the actual bytes covered by `content` after trimming
+/// to a valid UTF-8 boundary, which may differ slightly from the requested range.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceFileRange {
+    pub content: String,
+    pub start: u64,
+    pub end: u64,
+    pub total_size: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkspaceChecksumAlgorithm {
+    Sha256,
+    Blake3,
+}
+
+/// For a directory target, `hex` is the hash of a canonical manifest (sorted relative
+/// paths paired with their own per-file hashes), not the concatenated file bytes, so
+/// two trees with the same files in different orders on disk still compare equal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceChecksumResult {
+    pub algorithm: WorkspaceChecksumAlgorithm,
+    pub hex: String,
+    pub bytes_hashed: u64,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkspaceArchiveFormat {
+    Zip,
+    TarGz,
+}
+
+/// Result of `controlroom_workspace_archive`: the archive's final location and size,
+/// once every entry has been streamed to disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceArchiveResult {
+    pub path: String,
+    pub bytes_written: u64,
+    pub file_count: u64,
+}
+
+/// Payload for `controlroom://workspace-archive-progress`, emitted after each file is
+/// written while `controlroom_workspace_archive` streams a directory to disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceArchiveProgressEvent {
+    pub workspace_id: String,
+    pub files_done: u64,
+    pub files_total: u64,
+    pub bytes_done: u64,
+}
+
+/// Result of `controlroom_workspace_import`: the destination's workspace-relative path
+/// and how many bytes were copied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceImportResult {
+    pub path: String,
+    pub bytes_copied: u64,
+}
+
+/// Payload for `controlroom://workspace-import-progress`, emitted while
+/// `controlroom_workspace_import` streams a source file into the workspace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceImportProgressEvent {
+    pub workspace_id: String,
+    pub bytes_done: u64,
+    pub bytes_total: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkspaceChangeKind {
+    Created,
+    Modified,
+    Removed,
+    Renamed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceChangeEvent {
+    pub workspace_id: String,
+    pub path: String,
+    pub kind: WorkspaceChangeKind,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceWatchHandle {
+    pub watch_id: String,
+}
+
+/// A batch of lines appended to a followed file since the last poll, from
+/// `controlroom_workspace_follow`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceFileDataEvent {
+    pub follow_id: String,
+    pub workspace_id: String,
+    pub path: String,
+    pub lines: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkspaceFollowStatus {
+    Stopped,
+    Deleted,
+    Error,
+}
+
+/// Emitted once a follow started by `controlroom_workspace_follow` stops, whether from
+/// an explicit `controlroom_workspace_unfollow`, the file being deleted, or an I/O error.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceFollowStatusEvent {
+    pub follow_id: String,
+    pub workspace_id: String,
+    pub path: String,
+    pub status: WorkspaceFollowStatus,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceGrepOptions {
+    pub regex: Option<bool>,
+    pub case_sensitive: Option<bool>,
+    pub include_globs: Option<Vec<String>>,
+    pub exclude_globs: Option<Vec<String>>,
+    pub max_matches: Option<u32>,
+    pub max_file_size_bytes: Option<u64>,
+    pub respect_gitignore: Option<bool>,
+    pub timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceGrepMatch {
+    pub path: String,
+    pub line_number: u64,
+    pub line_text: String,
+    pub match_ranges: Vec<MatchRange>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceGrepResult {
+    pub matches: Vec<WorkspaceGrepMatch>,
+    pub files_searched: u64,
+    pub files_skipped_binary: u64,
+    pub files_skipped_too_large: u64,
+    pub truncated: bool,
+    pub timed_out: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -249,6 +1002,181 @@ pub struct GitCommit {
     pub message: String,
 }
 
+/// Optional filters for `get_commits`, mapped onto the matching `git log` flags. All
+/// fields compose (git ANDs them together); `since`/`until` accept anything `git log`
+/// itself understands (ISO dates, "2 weeks ago", etc.), and are rejected up front with a
+/// friendly error if git can't parse them, rather than surfacing a raw git failure.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitCommitFilter {
+    pub author: Option<String>,
+    /// Workspace-relative path; validated against the workspace root before use.
+    pub path: Option<String>,
+    pub since: Option<String>,
+    pub until: Option<String>,
+    /// Matched against the commit message (`git log --grep`).
+    pub grep: Option<String>,
+}
+
+/// A page of commits plus whether more exist beyond `limit`, so the caller can offer
+/// "load more" without an extra round trip to find out there's nothing left.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitCommitPage {
+    pub commits: Vec<GitCommit>,
+    pub has_more: bool,
+}
+
+/// One file touched by a commit, merging `git show --numstat` line counts with the
+/// change kind reported by `git show --name-status` (added/modified/deleted/renamed/...).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitCommitFileChange {
+    pub path: String,
+    pub previous_path: Option<String>,
+    pub status: String,
+    /// `None` for binary files, where `git show --numstat` reports `-` instead of a count.
+    pub insertions: Option<u32>,
+    pub deletions: Option<u32>,
+    pub binary: bool,
+}
+
+/// Full detail for a single commit: header fields, parent hashes, per-file changes, and
+/// optionally the unified diff text for one requested file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitCommitDetail {
+    pub hash: String,
+    pub short_hash: String,
+    pub author: String,
+    pub author_email: String,
+    pub author_date: String,
+    pub committer: String,
+    pub committer_email: String,
+    pub committer_date: String,
+    pub parent_hashes: Vec<String>,
+    pub subject: String,
+    pub body: String,
+    pub files: Vec<GitCommitFileChange>,
+    /// Unified diff for the file requested via `diff_file_path`, `None` if none was requested.
+    pub diff: Option<String>,
+    pub diff_truncated: bool,
+}
+
+/// One line of `git blame --porcelain` output. `date` is the raw `<author-time> <tz>`
+/// git blame reports in porcelain mode (always epoch seconds regardless of `--date`),
+/// left unformatted rather than hand-rolling calendar math without a date library.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitBlameLine {
+    pub line_number: u32,
+    pub hash: String,
+    pub author: String,
+    pub date: String,
+    pub summary: String,
+    pub line_text: String,
+}
+
+/// Unified diff of one file (working tree against the index, or index against HEAD
+/// when `staged`), with a
+/// hunk/line-count summary so the frontend can render a compact badge before the
+/// user expands the full diff.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitFileDiff {
+    pub path: String,
+    pub staged: bool,
+    pub binary: bool,
+    pub diff: String,
+    pub truncated: bool,
+    pub hunks: u32,
+    pub insertions: u32,
+    pub deletions: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitBranch {
+    pub name: String,
+    pub is_current: bool,
+    pub upstream: Option<String>,
+    pub ahead: u32,
+    pub behind: u32,
+    pub last_commit_hash: String,
+}
+
+/// Current-branch summary from `git status --porcelain=v2 --branch`, giving the file
+/// browser and dashboard a cheap "is this workspace clean" check without a full diff.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitStatus {
+    /// `None` for a repo with no commits yet (`git status` reports `(initial)`).
+    pub branch: Option<String>,
+    pub detached: bool,
+    pub staged: u32,
+    pub unstaged: u32,
+    pub untracked: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthCheckResult {
+    pub ts: u64,
+    pub ok: bool,
+    pub latency_ms: u64,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LogExportFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogExportQuery {
+    pub service_ids: Option<Vec<String>>,
+    pub since_ts: Option<u64>,
+    pub until_ts: Option<u64>,
+    pub level: Option<String>,
+    pub format: LogExportFormat,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogExportSummary {
+    pub lines_written: u64,
+    pub services_included: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunnerExportFormat {
+    Text,
+    Ndjson,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunnerExportSummary {
+    pub lines_written: u64,
+    pub still_running: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigIssue {
+    pub path: String,
+    pub message: String,
+    /// A short, actionable fix for the issue (e.g. "create the directory or update the
+    /// path"), shown alongside `message` in the UI. `None` when there's nothing more
+    /// specific to suggest than the message itself.
+    #[serde(default)]
+    pub suggestion: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ControlRoomBackendError {
@@ -257,6 +1185,19 @@ pub struct ControlRoomBackendError {
     pub correlation_id: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ControlRoomInitErrorEvent {
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ControlRoomShutdownEvent {
+    pub graceful: bool,
+    pub message: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VideoLaunchNativeInput {
@@ -271,6 +1212,9 @@ pub struct VideoLaunchNativeInput {
 pub struct VideoLaunchNativeResult {
     pub ok: bool,
     pub message: String,
+    /// The native player's OS process id, if the platform exposed one for the spawned
+    /// child. `None` on the rare platform where a PID isn't available.
+    pub pid: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -287,6 +1231,7 @@ pub struct VideoSnapshotAnalyzeResult {
     pub ok: bool,
     pub summary: String,
     pub message: Option<String>,
+    pub analysis: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -298,6 +1243,31 @@ pub struct VideoEventPayload {
     pub message: String,
     pub feed_id: Option<String>,
     pub kind: Option<String>,
-    pub details: Option<String>,
+    /// Structured payload specific to `kind` (e.g. snapshot dimensions, launcher exit
+    /// code) rather than a pre-serialized string, so the frontend can read fields
+    /// directly instead of parsing JSON-in-JSON.
+    pub details: Option<serde_json::Value>,
     pub correlation_id: Option<String>,
 }
+
+/// Payload for `controlroom://snapshot-request`, asking the frontend to capture the
+/// current frame for `feed_id` and submit it via the normal `snapshot_analyze` IPC path.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoSnapshotRequestEvent {
+    pub feed_id: String,
+    pub ts: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum VideoExportFormat {
+    Text,
+    Ndjson,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoExportSummary {
+    pub lines_written: u64,
+}
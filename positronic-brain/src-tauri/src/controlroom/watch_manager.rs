@@ -0,0 +1,173 @@
+use crate::controlroom::events::emit_workspace_changed;
+use crate::controlroom::types::{ControlRoomConfig, WorkspaceChangeEvent, WorkspaceChangeKind};
+use crate::controlroom::workspace::secure_target_path;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use tauri::AppHandle;
+use tokio::sync::{mpsc, Mutex};
+use tokio::time::{sleep, Duration};
+
+/// Bound on live watches per workspace, so a runaway frontend can't leak an unbounded
+/// number of OS file-descriptor watches.
+const MAX_WATCHES_PER_WORKSPACE: usize = 20;
+/// Filesystem events for the same path within this window are coalesced into one emit.
+const DEBOUNCE_MS: u64 = 300;
+
+struct WatchEntry {
+    workspace_id: String,
+    /// Kept alive only to keep the underlying OS watch alive; dropping it stops watching.
+    _watcher: RecommendedWatcher,
+}
+
+/// Watches workspace paths for filesystem changes and emits debounced
+/// `controlroom://workspace-changed` events. Each watch runs its own `notify` watcher
+/// plus a background task that coalesces bursts of events per path before emitting.
+pub struct WatchManager {
+    watches: Mutex<HashMap<String, WatchEntry>>,
+    next_id: AtomicU64,
+}
+
+impl std::fmt::Debug for WatchManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WatchManager").finish()
+    }
+}
+
+fn classify_event_kind(kind: &notify::EventKind) -> WorkspaceChangeKind {
+    use notify::EventKind;
+    match kind {
+        EventKind::Create(_) => WorkspaceChangeKind::Created,
+        EventKind::Modify(notify::event::ModifyKind::Name(_)) => WorkspaceChangeKind::Renamed,
+        EventKind::Modify(_) => WorkspaceChangeKind::Modified,
+        EventKind::Remove(_) => WorkspaceChangeKind::Removed,
+        _ => WorkspaceChangeKind::Modified,
+    }
+}
+
+impl WatchManager {
+    pub fn new() -> Self {
+        Self {
+            watches: Mutex::new(HashMap::new()),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    fn next_watch_id(&self) -> String {
+        let seq = self.next_id.fetch_add(1, Ordering::SeqCst);
+        format!("watch-{seq}")
+    }
+
+    pub async fn watch(
+        &self,
+        app: &AppHandle,
+        config: &ControlRoomConfig,
+        workspace_id: &str,
+        rel_or_abs: &str,
+        recursive: bool,
+    ) -> Result<String, String> {
+        let base = config
+            .workspaces
+            .iter()
+            .find(|workspace| workspace.id == workspace_id)
+            .map(|workspace| PathBuf::from(&workspace.path))
+            .ok_or_else(|| format!("workspace not found: {workspace_id}"))?;
+        let target = secure_target_path(&base, rel_or_abs)?;
+        let canonical_base = base
+            .canonicalize()
+            .map_err(|e| format!("workspace canonicalize failed: {e}"))?;
+
+        {
+            let watches = self.watches.lock().await;
+            let existing = watches.values().filter(|entry| entry.workspace_id == workspace_id).count();
+            if existing >= MAX_WATCHES_PER_WORKSPACE {
+                return Err(format!(
+                    "workspace {workspace_id} already has the maximum of {MAX_WATCHES_PER_WORKSPACE} watches"
+                ));
+            }
+        }
+
+        let (tx, rx) = mpsc::unbounded_channel::<notify::Event>();
+        let mut watcher: RecommendedWatcher = notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+            if let Ok(event) = result {
+                let _ = tx.send(event);
+            }
+        })
+        .map_err(|e| format!("failed to create watcher: {e}"))?;
+
+        let mode = if recursive { RecursiveMode::Recursive } else { RecursiveMode::NonRecursive };
+        watcher
+            .watch(&target, mode)
+            .map_err(|e| format!("failed to watch {}: {e}", target.display()))?;
+
+        let watch_id = self.next_watch_id();
+        spawn_debounced_forwarder(app.clone(), workspace_id.to_string(), canonical_base, rx);
+
+        let mut watches = self.watches.lock().await;
+        watches.insert(watch_id.clone(), WatchEntry { workspace_id: workspace_id.to_string(), _watcher: watcher });
+        Ok(watch_id)
+    }
+
+    pub async fn unwatch(&self, watch_id: &str) -> bool {
+        let mut watches = self.watches.lock().await;
+        watches.remove(watch_id).is_some()
+    }
+
+    /// Drops every watch registered for `workspace_id`, e.g. when a config reload
+    /// removes that workspace.
+    pub async fn stop_workspace_watches(&self, workspace_id: &str) {
+        let mut watches = self.watches.lock().await;
+        watches.retain(|_, entry| entry.workspace_id != workspace_id);
+    }
+}
+
+/// Drains raw `notify` events for one watch, coalescing repeats of the same path within
+/// `DEBOUNCE_MS` into a single emit instead of one event per filesystem write.
+fn spawn_debounced_forwarder(
+    app: AppHandle,
+    workspace_id: String,
+    canonical_base: PathBuf,
+    mut rx: mpsc::UnboundedReceiver<notify::Event>,
+) {
+    tokio::spawn(async move {
+        let mut pending: HashMap<String, WorkspaceChangeKind> = HashMap::new();
+
+        loop {
+            let first = match rx.recv().await {
+                Some(event) => event,
+                None => break,
+            };
+            record_pending(&mut pending, &canonical_base, &first);
+
+            loop {
+                tokio::select! {
+                    more = rx.recv() => match more {
+                        Some(event) => record_pending(&mut pending, &canonical_base, &event),
+                        None => break,
+                    },
+                    _ = sleep(Duration::from_millis(DEBOUNCE_MS)) => break,
+                }
+            }
+
+            for (path, kind) in pending.drain() {
+                emit_workspace_changed(
+                    &app,
+                    &WorkspaceChangeEvent { workspace_id: workspace_id.clone(), path, kind },
+                );
+            }
+        }
+    });
+}
+
+fn record_pending(pending: &mut HashMap<String, WorkspaceChangeKind>, canonical_base: &Path, event: &notify::Event) {
+    let kind = classify_event_kind(&event.kind);
+    for path in &event.paths {
+        let relative = path
+            .strip_prefix(canonical_base)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string();
+        pending.insert(relative, kind.clone());
+    }
+}
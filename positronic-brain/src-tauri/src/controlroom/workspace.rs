@@ -1,5 +1,77 @@
-use crate::controlroom::types::{ControlRoomConfig, WorkspaceEntry};
+use crate::controlroom::types::{
+    ControlRoomConfig, MatchRange, WorkspaceArchiveFormat, WorkspaceArchiveResult,
+    WorkspaceBinaryFile, WorkspaceChecksumAlgorithm, WorkspaceChecksumResult, WorkspaceDiskUsage,
+    WorkspaceDiskUsageChild, WorkspaceEntry, WorkspaceEntryKind, WorkspaceEntrySortBy,
+    WorkspaceFileContent, WorkspaceFileRange, WorkspaceGrepMatch, WorkspaceGrepOptions,
+    WorkspaceGrepResult, WorkspaceImportResult, WorkspaceLargeFile, WorkspaceListOptions,
+    WorkspaceListResult, WorkspaceSearchResult, WorkspaceTreeNode, WorkspaceWriteConflict,
+    WorkspaceWriteResult, PRIMARY_WORKSPACE_ROOT_ID,
+};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use sha2::{Digest, Sha256};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::io::{Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+use tauri_plugin_opener::OpenerExt;
+use tokio_util::sync::CancellationToken;
+
+/// Hard cap on how many lines `tail_workspace_file` will ever return, regardless of
+/// what the caller asks for.
+const TAIL_MAX_LINES: usize = 10_000;
+/// Chunk size read backwards from the end of the file while searching for newlines.
+const TAIL_CHUNK_BYTES: u64 = 64 * 1024;
+/// Worst-case bytes scanned looking for `last_n_lines`, so a file with almost no
+/// newlines (e.g. one giant JSON blob) can't force reading the whole multi-GB file.
+const TAIL_MAX_SCAN_BYTES: u64 = 8 * 1024 * 1024;
+/// Largest file `list_entries_in_root` will hash when `include_hashes` is set; larger
+/// files are left with `content_hash: None` rather than stalling a directory listing.
+const MAX_HASHED_LIST_FILE_BYTES: u64 = 1024 * 1024;
+
+/// Trims `buf` to the nearest valid UTF-8 boundaries, dropping a partial codepoint at
+/// either edge (from a range read that landed mid-character). Returns the trimmed
+/// slice and how many bytes were dropped from the front.
+fn trim_to_utf8_boundary(buf: &[u8]) -> (&[u8], usize) {
+    let mut start = 0;
+    while start < buf.len() && (buf[start] & 0xC0) == 0x80 {
+        start += 1;
+    }
+    let mut end = buf.len();
+    while end > start && std::str::from_utf8(&buf[start..end]).is_err() {
+        end -= 1;
+    }
+    (&buf[start..end], start)
+}
+
+/// Extension-based MIME fallback for the common types `infer`'s magic-byte sniffing
+/// can't identify (plain-text formats have no distinguishing header bytes).
+fn mime_from_extension(path: &Path) -> Option<String> {
+    let ext = path.extension()?.to_str()?.to_lowercase();
+    let mime = match ext.as_str() {
+        "txt" | "md" | "log" => "text/plain",
+        "json" => "application/json",
+        "html" | "htm" => "text/html",
+        "css" => "text/css",
+        "js" | "mjs" => "text/javascript",
+        "svg" => "image/svg+xml",
+        "csv" => "text/csv",
+        "xml" => "application/xml",
+        "pdf" => "application/pdf",
+        "wasm" => "application/wasm",
+        _ => return None,
+    };
+    Some(mime.to_string())
+}
+
+pub(crate) fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or(0)
+}
 
 fn now_modified_ms(meta: &std::fs::Metadata) -> Option<u64> {
     let modified = meta.modified().ok()?;
@@ -7,7 +79,82 @@ fn now_modified_ms(meta: &std::fs::Metadata) -> Option<u64> {
     Some(since_epoch.as_millis() as u64)
 }
 
-fn secure_target_path(base: &Path, rel_or_abs: &str) -> Result<PathBuf, String> {
+/// Cheap content fingerprint for optimistic-concurrency checks on save; not
+/// cryptographic, just enough to detect "this file changed since I read it".
+fn content_hash(bytes: &[u8]) -> String {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Decodes `raw` file bytes into text, returning the content, the encoding label used,
+/// and whether a byte-order mark was present. Tries, in order: a BOM (authoritative when
+/// present), plain UTF-8, then a `chardetng` guess; if even that guess doesn't decode
+/// cleanly the content falls back to lossy UTF-8 with the encoding flagged as such.
+fn decode_text(raw: &[u8]) -> (String, String, bool) {
+    if let Some((encoding, bom_len)) = encoding_rs::Encoding::for_bom(raw) {
+        let (decoded, _, _) = encoding.decode(&raw[bom_len..]);
+        return (decoded.into_owned(), encoding.name().to_string(), true);
+    }
+
+    if let Ok(text) = std::str::from_utf8(raw) {
+        return (text.to_string(), encoding_rs::UTF_8.name().to_string(), false);
+    }
+
+    let mut detector = chardetng::EncodingDetector::new();
+    detector.feed(raw, true);
+    let guessed = detector.guess(None, true);
+    let (decoded, _, had_errors) = guessed.decode(raw);
+    if had_errors {
+        (String::from_utf8_lossy(raw).into_owned(), format!("{} (lossy)", encoding_rs::UTF_8.name()), false)
+    } else {
+        (decoded.into_owned(), guessed.name().to_string(), false)
+    }
+}
+
+/// Encodes `content` back to bytes for `write_workspace_file`. `encoding` selects a
+/// target encoding by its standard label (e.g. `"UTF-16LE"`); `None` keeps the encoding
+/// named by the replaced file's byte-order mark and otherwise writes plain UTF-8, so a
+/// BOM-less file in another encoding is saved as UTF-8 unless `encoding` names it. A byte-order mark
+/// is written for UTF-16 variants (required to identify them) and preserved for UTF-8
+/// when the file being replaced already had one.
+fn encode_text(
+    content: &str,
+    encoding: Option<&str>,
+    existing_bom: Option<&'static encoding_rs::Encoding>,
+) -> Result<Vec<u8>, String> {
+    let target = match encoding {
+        Some(label) => encoding_rs::Encoding::for_label(label.as_bytes())
+            .ok_or_else(|| format!("unknown encoding: {label:?}"))?,
+        None => existing_bom.unwrap_or(encoding_rs::UTF_8),
+    };
+
+    // `Encoding::encode` emits UTF-8 for the UTF-16 encodings, so those are encoded here.
+    let encoded: Vec<u8> = if target == encoding_rs::UTF_16LE {
+        content.encode_utf16().flat_map(|unit| unit.to_le_bytes()).collect()
+    } else if target == encoding_rs::UTF_16BE {
+        content.encode_utf16().flat_map(|unit| unit.to_be_bytes()).collect()
+    } else {
+        let (encoded, _, had_unmappable) = target.encode(content);
+        if had_unmappable {
+            return Err(format!("content contains characters that cannot be represented in {}", target.name()));
+        }
+        encoded.into_owned()
+    };
+
+    let mut bytes = Vec::new();
+    if target == encoding_rs::UTF_16LE {
+        bytes.extend_from_slice(&[0xFF, 0xFE]);
+    } else if target == encoding_rs::UTF_16BE {
+        bytes.extend_from_slice(&[0xFE, 0xFF]);
+    } else if target == encoding_rs::UTF_8 && existing_bom == Some(encoding_rs::UTF_8) {
+        bytes.extend_from_slice(&[0xEF, 0xBB, 0xBF]);
+    }
+    bytes.extend_from_slice(&encoded);
+    Ok(bytes)
+}
+
+pub(crate) fn secure_target_path(base: &Path, rel_or_abs: &str) -> Result<PathBuf, String> {
     let target = if rel_or_abs.trim().is_empty() {
         base.to_path_buf()
     } else {
@@ -33,27 +180,747 @@ fn secure_target_path(base: &Path, rel_or_abs: &str) -> Result<PathBuf, String>
     Ok(canonical_target)
 }
 
-fn workspace_base_path(config: &ControlRoomConfig, workspace_id: &str) -> Result<PathBuf, String> {
-    let workspace = config
+/// Like `secure_target_path`, but for a target that doesn't exist yet. Rather than
+/// creating the lexical parent outright (which would follow a symlink partway down a
+/// still-nonexistent chain and create real directories outside the workspace before
+/// anything gets checked), this walks up to the *deepest existing* ancestor first,
+/// canonicalizes and validates just that ancestor against the workspace base, and only
+/// then creates the missing suffix underneath the now-verified-safe ancestor.
+fn secure_target_path_for_create(base: &Path, rel_or_abs: &str, recursive: bool) -> Result<PathBuf, String> {
+    if rel_or_abs.trim().is_empty() {
+        return Err("target path cannot be empty".to_string());
+    }
+
+    let candidate = PathBuf::from(rel_or_abs);
+    let raw_target = if candidate.is_absolute() {
+        candidate
+    } else {
+        base.join(candidate)
+    };
+
+    // A literal ".." can't be ruled out by canonicalizing a path that doesn't exist yet,
+    // so reject it outright rather than trying to reason about a partial canonicalization.
+    if raw_target.components().any(|c| matches!(c, std::path::Component::ParentDir)) {
+        return Err("path traversal blocked".to_string());
+    }
+
+    let canonical_base = base
+        .canonicalize()
+        .map_err(|e| format!("workspace canonicalize failed: {e}"))?;
+
+    let mut existing_ancestor = raw_target.as_path();
+    let mut missing: Vec<&std::ffi::OsStr> = Vec::new();
+    while !existing_ancestor.exists() {
+        let name = existing_ancestor
+            .file_name()
+            .ok_or_else(|| "target path escapes the workspace".to_string())?;
+        missing.push(name);
+        existing_ancestor = existing_ancestor
+            .parent()
+            .ok_or_else(|| "target path escapes the workspace".to_string())?;
+    }
+    missing.reverse();
+
+    let canonical_ancestor = existing_ancestor
+        .canonicalize()
+        .map_err(|e| format!("ancestor canonicalize failed: {e}"))?;
+    if !canonical_ancestor.starts_with(&canonical_base) {
+        return Err("path traversal blocked".to_string());
+    }
+
+    // The target already exists (e.g. an overwrite-in-place); nothing left to create.
+    let Some((file_name, missing_dirs)) = missing.split_last() else {
+        return Ok(canonical_ancestor);
+    };
+
+    if !missing_dirs.is_empty() && !recursive {
+        let missing_path: PathBuf = missing_dirs.iter().collect();
+        return Err(format!(
+            "parent directory does not exist: {}",
+            existing_ancestor.join(missing_path).display()
+        ));
+    }
+
+    let mut built = canonical_ancestor;
+    for dir_name in missing_dirs {
+        built.push(*dir_name);
+        std::fs::create_dir(&built)
+            .map_err(|e| format!("failed to create directory {}: {e}", built.display()))?;
+    }
+
+    Ok(built.join(*file_name))
+}
+
+#[cfg(test)]
+mod secure_path_for_create_tests {
+    use super::*;
+
+    fn fixture_base(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn allows_new_file_directly_in_base() {
+        let base = fixture_base("controlroom-secure-create-base-file");
+
+        let target = secure_target_path_for_create(&base, "new.txt", false).unwrap();
+        assert_eq!(target, base.canonicalize().unwrap().join("new.txt"));
+
+        let _ = std::fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn allows_new_file_in_new_subdirectory() {
+        let base = fixture_base("controlroom-secure-create-new-subdir");
+
+        let target = secure_target_path_for_create(&base, "a/b/new.txt", true).unwrap();
+        assert_eq!(target, base.canonicalize().unwrap().join("a").join("b").join("new.txt"));
+        assert!(base.join("a").join("b").is_dir());
+
+        let _ = std::fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn rejects_parent_dir_escape_in_suffix() {
+        let base = fixture_base("controlroom-secure-create-escape");
+
+        let result = secure_target_path_for_create(&base, "../escaped.txt", true);
+        assert!(result.is_err());
+
+        let _ = std::fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn rejects_symlinked_ancestor_pointing_outside_base() {
+        let root = fixture_base("controlroom-secure-create-symlink-root");
+        let base = root.join("workspace");
+        let outside = root.join("outside");
+        std::fs::create_dir_all(&base).unwrap();
+        std::fs::create_dir_all(&outside).unwrap();
+        std::os::unix::fs::symlink(&outside, base.join("escape")).unwrap();
+
+        let result = secure_target_path_for_create(&base, "escape/new-subdir/new.txt", true);
+        assert!(result.is_err());
+        assert!(!outside.join("new-subdir").exists());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
+
+fn find_workspace<'a>(
+    config: &'a ControlRoomConfig,
+    workspace_id: &str,
+) -> Result<&'a crate::controlroom::types::WorkspaceConfig, String> {
+    config
         .workspaces
         .iter()
         .find(|workspace| workspace.id == workspace_id)
-        .ok_or_else(|| format!("workspace not found: {workspace_id}"))?;
+        .ok_or_else(|| format!("workspace not found: {workspace_id}"))
+}
+
+fn workspace_base_path(config: &ControlRoomConfig, workspace_id: &str) -> Result<PathBuf, String> {
+    let workspace = find_workspace(config, workspace_id)?;
     Ok(PathBuf::from(&workspace.path))
 }
 
-pub fn list_workspace_entries(
+/// Resolves a specific root of a workspace: `root_id` of `None` or
+/// `PRIMARY_WORKSPACE_ROOT_ID` means `WorkspaceConfig::path`, anything else is looked
+/// up in `extraPaths`. Returns the resolved root's id alongside its base path so
+/// callers that default to primary can still report which root they used.
+fn resolve_workspace_root(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    root_id: Option<&str>,
+) -> Result<(String, PathBuf), String> {
+    let workspace = find_workspace(config, workspace_id)?;
+    match root_id {
+        None | Some(PRIMARY_WORKSPACE_ROOT_ID) => {
+            Ok((PRIMARY_WORKSPACE_ROOT_ID.to_string(), PathBuf::from(&workspace.path)))
+        }
+        Some(id) => workspace
+            .extra_paths
+            .as_ref()
+            .and_then(|roots| roots.iter().find(|root| root.id == id))
+            .map(|root| (root.id.clone(), PathBuf::from(&root.path)))
+            .ok_or_else(|| format!("workspace {workspace_id} has no root {id:?}")),
+    }
+}
+
+/// Every root of a workspace, primary first: `WorkspaceConfig::path` (id
+/// `"primary"`) followed by each `extraPaths` entry in declaration order.
+pub(crate) fn workspace_all_roots(config: &ControlRoomConfig, workspace_id: &str) -> Result<Vec<(String, PathBuf)>, String> {
+    let workspace = find_workspace(config, workspace_id)?;
+    let mut roots = vec![(PRIMARY_WORKSPACE_ROOT_ID.to_string(), PathBuf::from(&workspace.path))];
+    if let Some(extra) = &workspace.extra_paths {
+        roots.extend(extra.iter().map(|root| (root.id.clone(), PathBuf::from(&root.path))));
+    }
+    Ok(roots)
+}
+
+/// Rejects an operation against a workspace flagged `readOnly`, regardless of which
+/// root it targets.
+fn ensure_workspace_writable(config: &ControlRoomConfig, workspace_id: &str) -> Result<(), String> {
+    let workspace = find_workspace(config, workspace_id)?;
+    if workspace.read_only.unwrap_or(false) {
+        return Err(format!("workspace {workspace_id} is read-only"));
+    }
+    Ok(())
+}
+
+const DEFAULT_TREE_IGNORE: [&str; 3] = [".git", "node_modules", "target"];
+
+/// Applied to listings/trees whenever a workspace doesn't configure its own
+/// `hidePatterns`, so the common crash-artifact/editor-swap-file clutter is hidden by
+/// default without every workspace needing to opt in.
+const DEFAULT_HIDE_PATTERNS: [&str; 4] = [".DS_Store", "*.pyc", "*.swp", "*~"];
+
+/// Decides whether a listing/tree entry should be shown. Filtering happens here, before
+/// any sorting or pagination, so page counts stay consistent with what's actually
+/// visible. Direct reads/writes by path are unaffected — this only gates listing output.
+fn is_entry_visible(
+    name: &str,
+    relative_path: &str,
+    is_directory: bool,
+    include_hidden: bool,
+    extensions: Option<&[String]>,
+    hide_patterns: &[String],
+) -> bool {
+    if !include_hidden && name.starts_with('.') {
+        return false;
+    }
+
+    if !is_directory {
+        if let Some(extensions) = extensions {
+            let matches_extension = Path::new(name)
+                .extension()
+                .map(|ext| ext.to_string_lossy().to_lowercase())
+                .map(|ext| extensions.iter().any(|allowed| allowed.trim_start_matches('.').eq_ignore_ascii_case(&ext)))
+                .unwrap_or(false);
+            if !matches_extension {
+                return false;
+            }
+        }
+    }
+
+    if !hide_patterns.is_empty() && path_matches_any(relative_path, hide_patterns) {
+        return false;
+    }
+
+    true
+}
+
+fn workspace_hide_patterns(config: &ControlRoomConfig, workspace_id: &str) -> Result<Vec<String>, String> {
+    Ok(find_workspace(config, workspace_id)?
+        .hide_patterns
+        .clone()
+        .unwrap_or_else(|| DEFAULT_HIDE_PATTERNS.iter().map(|s| s.to_string()).collect()))
+}
+
+/// Bundles the visibility rules (hidden dotfiles, extension whitelist, `hidePatterns`)
+/// shared by `build_tree_node` and `list_workspace_entries` so neither has to thread
+/// three separate parameters through their recursion/loops.
+struct EntryVisibility<'a> {
+    include_hidden: bool,
+    extensions: Option<&'a [String]>,
+    hide_patterns: &'a [String],
+}
+
+/// Builds one level of the workspace tree at `dir`, recursing up to `max_depth` and
+/// capping each directory's children at `max_entries` (marking that node `truncated`
+/// if more existed). Symlinks that resolve outside `canonical_base` are skipped rather
+/// than followed.
+fn build_tree_node(
+    canonical_base: &Path,
+    root_id: &str,
+    dir: &Path,
+    depth: usize,
+    max_depth: usize,
+    max_entries: usize,
+    ignore: &[String],
+    visibility: &EntryVisibility,
+) -> Result<WorkspaceTreeNode, String> {
+    let meta = std::fs::metadata(dir).map_err(|e| format!("metadata failed for {}: {e}", dir.display()))?;
+    let relative = dir
+        .strip_prefix(canonical_base)
+        .ok()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(String::new);
+    let name = dir
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| relative.clone());
+
+    let (is_symlink, symlink_target, readonly, mode) = entry_permission_fields(dir, &meta);
+    let entry = WorkspaceEntry {
+        name,
+        path: relative,
+        root_id: root_id.to_string(),
+        is_directory: meta.is_dir(),
+        size: if meta.is_file() { Some(meta.len()) } else { None },
+        modified_ms: now_modified_ms(&meta),
+        is_symlink,
+        symlink_target,
+        readonly,
+        mode,
+        content_hash: None,
+    };
+
+    let mut children = Vec::new();
+    let mut truncated = false;
+
+    if meta.is_dir() && depth < max_depth {
+        let read_dir = std::fs::read_dir(dir).map_err(|e| format!("read_dir failed for {}: {e}", dir.display()))?;
+
+        let mut items = Vec::new();
+        for item in read_dir {
+            let Ok(item) = item else { continue };
+            let item_path = item.path();
+            let Ok(item_meta) = item.metadata() else { continue };
+            let item_name = item.file_name().to_string_lossy().to_string();
+
+            if item_meta.is_dir() && ignore.iter().any(|excluded| excluded.eq_ignore_ascii_case(&item_name)) {
+                continue;
+            }
+
+            let Ok(canonical_item) = item_path.canonicalize() else { continue };
+            if !canonical_item.starts_with(canonical_base) {
+                continue;
+            }
+
+            let item_relative = canonical_item
+                .strip_prefix(canonical_base)
+                .ok()
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_else(String::new);
+
+            if !is_entry_visible(
+                &item_name,
+                &item_relative,
+                item_meta.is_dir(),
+                visibility.include_hidden,
+                visibility.extensions,
+                visibility.hide_patterns,
+            ) {
+                continue;
+            }
+
+            items.push((item_name, item_meta.is_dir(), canonical_item));
+        }
+
+        items.sort_by(|a, b| match (a.1, b.1) {
+            (true, false) => std::cmp::Ordering::Less,
+            (false, true) => std::cmp::Ordering::Greater,
+            _ => a.0.to_lowercase().cmp(&b.0.to_lowercase()),
+        });
+
+        if items.len() > max_entries {
+            truncated = true;
+            items.truncate(max_entries);
+        }
+
+        for (_, _, canonical_item) in items {
+            children.push(build_tree_node(
+                canonical_base,
+                root_id,
+                &canonical_item,
+                depth + 1,
+                max_depth,
+                max_entries,
+                ignore,
+                visibility,
+            )?);
+        }
+    }
+
+    Ok(WorkspaceTreeNode { entry, children, truncated })
+}
+
+pub fn build_workspace_tree(
     config: &ControlRoomConfig,
     workspace_id: &str,
+    root_id: Option<&str>,
     rel_or_abs: &str,
-) -> Result<Vec<WorkspaceEntry>, String> {
+    max_depth: usize,
+    max_entries: usize,
+    include_hidden: bool,
+    extensions: Option<&[String]>,
+) -> Result<WorkspaceTreeNode, String> {
+    let (resolved_root_id, base) = resolve_workspace_root(config, workspace_id, root_id)?;
+    let target = secure_target_path(&base, rel_or_abs)?;
+    let canonical_base = base
+        .canonicalize()
+        .map_err(|e| format!("workspace canonicalize failed: {e}"))?;
+
+    let hide_patterns = workspace_hide_patterns(config, workspace_id)?;
+    let visibility = EntryVisibility { include_hidden, extensions, hide_patterns: &hide_patterns };
+
+    let ignore: Vec<String> = find_workspace(config, workspace_id)?
+        .ignore
+        .clone()
+        .unwrap_or_else(|| DEFAULT_TREE_IGNORE.iter().map(|s| s.to_string()).collect());
+
+    build_tree_node(&canonical_base, &resolved_root_id, &target, 0, max_depth, max_entries, &ignore, &visibility)
+}
+
+/// Bound on how many of the largest files found during a `workspace_disk_usage` walk
+/// are kept in `top_files`.
+const DU_TOP_FILES: usize = 20;
+/// Fallback time budget for a disk-usage walk when the caller doesn't specify one.
+const DU_DEFAULT_TIMEOUT_MS: u64 = 10_000;
+
+#[derive(Default)]
+struct DuAccumulator {
+    total_bytes: u64,
+    file_count: u64,
+    dir_count: u64,
+    top_files: Vec<(String, u64)>,
+}
+
+/// Recursively accumulates sizes/counts under `dir` into `acc`. Returns `false` if the
+/// walk was cut short by `deadline`, in which case `acc` holds a partial (under-)count.
+/// Symlinks are skipped outright rather than followed, so a link back out of the
+/// workspace (or a cycle) can't be walked into.
+fn du_walk(root: &Path, dir: &Path, depth: usize, max_depth: usize, deadline: Instant, acc: &mut DuAccumulator) -> bool {
+    if Instant::now() >= deadline {
+        return false;
+    }
+    let Ok(read_dir) = std::fs::read_dir(dir) else {
+        return true;
+    };
+
+    for item in read_dir {
+        if Instant::now() >= deadline {
+            return false;
+        }
+        let Ok(item) = item else { continue };
+        let Ok(file_type) = item.file_type() else { continue };
+        if file_type.is_symlink() {
+            continue;
+        }
+        let Ok(canonical_item) = item.path().canonicalize() else { continue };
+        if !canonical_item.starts_with(root) {
+            continue;
+        }
+
+        if file_type.is_dir() {
+            acc.dir_count += 1;
+            if depth < max_depth && !du_walk(root, &canonical_item, depth + 1, max_depth, deadline, acc) {
+                return false;
+            }
+        } else if file_type.is_file() {
+            let Ok(meta) = item.metadata() else { continue };
+            acc.file_count += 1;
+            acc.total_bytes += meta.len();
+            let relative = canonical_item
+                .strip_prefix(root)
+                .ok()
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_default();
+            acc.top_files.push((relative, meta.len()));
+        }
+    }
+    true
+}
+
+/// Summarizes disk usage under `rel_or_abs`: cumulative size per immediate child plus
+/// the largest files anywhere in the subtree, walked on the calling thread up to
+/// `max_depth` levels deep or until `timeout_ms` elapses (whichever comes first).
+pub fn workspace_disk_usage(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    rel_or_abs: &str,
+    max_depth: usize,
+    timeout_ms: Option<u64>,
+) -> Result<WorkspaceDiskUsage, String> {
+    let base = workspace_base_path(config, workspace_id)?;
+    let target = secure_target_path(&base, rel_or_abs)?;
+
+    let meta = std::fs::metadata(&target).map_err(|e| format!("metadata failed for {}: {e}", target.display()))?;
+    if !meta.is_dir() {
+        return Err(format!("not a directory: {}", target.display()));
+    }
+
+    let deadline = Instant::now() + Duration::from_millis(timeout_ms.unwrap_or(DU_DEFAULT_TIMEOUT_MS));
+    let read_dir = std::fs::read_dir(&target).map_err(|e| format!("read_dir failed for {}: {e}", target.display()))?;
+
+    let mut children = Vec::new();
+    let mut total_bytes = 0u64;
+    let mut file_count = 0u64;
+    let mut dir_count = 0u64;
+    let mut top_files: Vec<(String, u64)> = Vec::new();
+    let mut completed = true;
+
+    for item in read_dir {
+        if Instant::now() >= deadline {
+            completed = false;
+            break;
+        }
+        let Ok(item) = item else { continue };
+        let Ok(file_type) = item.file_type() else { continue };
+        if file_type.is_symlink() {
+            continue;
+        }
+        let Ok(canonical_item) = item.path().canonicalize() else { continue };
+        if !canonical_item.starts_with(&target) {
+            continue;
+        }
+
+        let name = item.file_name().to_string_lossy().to_string();
+        let relative = canonical_item
+            .strip_prefix(&target)
+            .ok()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|| name.clone());
+
+        if file_type.is_dir() {
+            let mut acc = DuAccumulator::default();
+            completed &= du_walk(&target, &canonical_item, 1, max_depth, deadline, &mut acc);
+            dir_count += 1 + acc.dir_count;
+            file_count += acc.file_count;
+            total_bytes += acc.total_bytes;
+            top_files.extend(acc.top_files);
+            children.push(WorkspaceDiskUsageChild {
+                name,
+                path: relative,
+                is_directory: true,
+                total_bytes: acc.total_bytes,
+                file_count: acc.file_count,
+                dir_count: acc.dir_count,
+            });
+        } else if file_type.is_file() {
+            let Ok(item_meta) = item.metadata() else { continue };
+            file_count += 1;
+            total_bytes += item_meta.len();
+            top_files.push((relative.clone(), item_meta.len()));
+            children.push(WorkspaceDiskUsageChild {
+                name,
+                path: relative,
+                is_directory: false,
+                total_bytes: item_meta.len(),
+                file_count: 1,
+                dir_count: 0,
+            });
+        }
+    }
+
+    top_files.sort_by(|a, b| b.1.cmp(&a.1));
+    top_files.truncate(DU_TOP_FILES);
+
+    Ok(WorkspaceDiskUsage {
+        path: rel_or_abs.to_string(),
+        total_bytes,
+        file_count,
+        dir_count,
+        children,
+        top_files: top_files
+            .into_iter()
+            .map(|(path, size)| WorkspaceLargeFile { path, size })
+            .collect(),
+        completed,
+    })
+}
+
+/// Bound on the read buffer used while streaming a file's bytes into an archive entry,
+/// matching `CHECKSUM_CHUNK_BYTES` so a single large file is never read into memory
+/// whole.
+const ARCHIVE_CHUNK_BYTES: usize = 1024 * 1024;
+
+/// Collects every regular file under `dir` (recursively) as a path relative to `base`
+/// paired with its size, in the same style as `collect_checksum_files`: symlinks are
+/// skipped outright (a link pointing outside the workspace is never followed), and any
+/// directory whose name matches `ignore` (case-insensitively) is skipped, matching
+/// `build_workspace_tree`'s ignore semantics.
+fn collect_archive_files(
+    base: &Path,
+    dir: &Path,
+    ignore: &[String],
+    out: &mut Vec<(String, u64)>,
+) -> Result<(), String> {
+    let read_dir =
+        std::fs::read_dir(dir).map_err(|e| format!("read_dir failed for {}: {e}", dir.display()))?;
+    for entry in read_dir {
+        let entry = entry.map_err(|e| format!("read_dir entry failed in {}: {e}", dir.display()))?;
+        let path = entry.path();
+        let file_type = entry
+            .file_type()
+            .map_err(|e| format!("file_type failed for {}: {e}", path.display()))?;
+        if file_type.is_symlink() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().to_string();
+        if file_type.is_dir() {
+            if ignore.iter().any(|excluded| excluded.eq_ignore_ascii_case(&name)) {
+                continue;
+            }
+            collect_archive_files(base, &path, ignore, out)?;
+        } else if file_type.is_file() {
+            if let Ok(relative) = path.strip_prefix(base) {
+                let meta = entry
+                    .metadata()
+                    .map_err(|e| format!("metadata failed for {}: {e}", path.display()))?;
+                out.push((relative.to_string_lossy().replace('\\', "/"), meta.len()));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Resolves an archive/export target the same way `ProcessManager::export_logs` does: a
+/// relative path is joined onto the current working directory, and the parent directory
+/// is created if it doesn't exist yet.
+fn resolve_export_target(target_path: &str) -> Result<PathBuf, String> {
+    let target = PathBuf::from(target_path);
+    let resolved = if target.is_absolute() {
+        target
+    } else {
+        std::env::current_dir()
+            .map_err(|e| format!("failed to read cwd: {e}"))?
+            .join(target)
+    };
+
+    if let Some(parent) = resolved.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("failed to create export parent {}: {e}", parent.display()))?;
+    }
+
+    Ok(resolved)
+}
+
+/// Streams the directory at `rel_or_abs` into a zip or tar.gz archive at `target_path`.
+/// Files are copied through a fixed-size buffer rather than read whole into memory, and
+/// entries falling under the workspace's `ignore` patterns are left out, mirroring
+/// `build_workspace_tree`. `on_progress` is called after each file finishes writing with
+/// `(files_done, files_total, bytes_done)` so a caller can drive a progress bar.
+pub fn archive_workspace_path(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    rel_or_abs: &str,
+    target_path: &str,
+    format: WorkspaceArchiveFormat,
+    mut on_progress: impl FnMut(u64, u64, u64),
+) -> Result<WorkspaceArchiveResult, String> {
     let base = workspace_base_path(config, workspace_id)?;
     let target = secure_target_path(&base, rel_or_abs)?;
+    let meta = std::fs::metadata(&target).map_err(|e| format!("metadata failed for {}: {e}", target.display()))?;
+    if !meta.is_dir() {
+        return Err(format!("not a directory: {}", target.display()));
+    }
+
+    let ignore: Vec<String> = find_workspace(config, workspace_id)?
+        .ignore
+        .clone()
+        .unwrap_or_else(|| DEFAULT_TREE_IGNORE.iter().map(|s| s.to_string()).collect());
+
+    let mut files = Vec::new();
+    collect_archive_files(&target, &target, &ignore, &mut files)?;
+    files.sort();
+    let files_total = files.len() as u64;
+
+    let archive_path = resolve_export_target(target_path)?;
+    let archive_file = std::fs::File::create(&archive_path)
+        .map_err(|e| format!("failed to create archive at {}: {e}", archive_path.display()))?;
+
+    let mut files_done = 0u64;
+    let mut bytes_done = 0u64;
+
+    match format {
+        WorkspaceArchiveFormat::Zip => {
+            let options = zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+            let mut writer = zip::ZipWriter::new(archive_file);
+            let mut buf = vec![0u8; ARCHIVE_CHUNK_BYTES];
+            for (relative, _size) in &files {
+                writer
+                    .start_file(relative.as_str(), options)
+                    .map_err(|e| format!("failed to start archive entry {relative}: {e}"))?;
+                let file_path = target.join(relative);
+                let mut reader = std::fs::File::open(&file_path)
+                    .map_err(|e| format!("open failed for {}: {e}", file_path.display()))?;
+                loop {
+                    let read = reader
+                        .read(&mut buf)
+                        .map_err(|e| format!("read failed for {}: {e}", file_path.display()))?;
+                    if read == 0 {
+                        break;
+                    }
+                    writer
+                        .write_all(&buf[..read])
+                        .map_err(|e| format!("write failed for archive entry {relative}: {e}"))?;
+                    bytes_done += read as u64;
+                }
+                files_done += 1;
+                on_progress(files_done, files_total, bytes_done);
+            }
+            writer.finish().map_err(|e| format!("failed to finalize zip archive: {e}"))?;
+        }
+        WorkspaceArchiveFormat::TarGz => {
+            let encoder = flate2::write::GzEncoder::new(archive_file, flate2::Compression::default());
+            let mut builder = tar::Builder::new(encoder);
+            for (relative, size) in &files {
+                let file_path = target.join(relative);
+                let mut reader = std::fs::File::open(&file_path)
+                    .map_err(|e| format!("open failed for {}: {e}", file_path.display()))?;
+                let mut header = tar::Header::new_gnu();
+                header.set_size(*size);
+                header.set_mode(0o644);
+                header
+                    .set_path(relative)
+                    .map_err(|e| format!("failed to set archive entry path {relative}: {e}"))?;
+                header.set_cksum();
+                builder
+                    .append(&header, &mut reader)
+                    .map_err(|e| format!("failed to append archive entry {relative}: {e}"))?;
+                bytes_done += size;
+                files_done += 1;
+                on_progress(files_done, files_total, bytes_done);
+            }
+            builder
+                .into_inner()
+                .map_err(|e| format!("failed to finalize tar.gz archive: {e}"))?
+                .finish()
+                .map_err(|e| format!("failed to finalize tar.gz archive: {e}"))?;
+        }
+    }
+
+    Ok(WorkspaceArchiveResult {
+        path: archive_path.to_string_lossy().to_string(),
+        bytes_written: bytes_done,
+        file_count: files_done,
+    })
+}
+
+/// Lists one root's entries at `rel_or_abs` inside it, tagging each with `root_id`.
+/// Shared by `list_workspace_entries`, which either calls this once (a specific root
+/// requested) or once per root and merges the results (no root requested).
+fn list_entries_in_root(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    root_id: &str,
+    base: &Path,
+    rel_or_abs: &str,
+    options: &WorkspaceListOptions,
+) -> Result<Vec<WorkspaceEntry>, String> {
+    let target = secure_target_path(base, rel_or_abs)?;
+    let canonical_base = base
+        .canonicalize()
+        .map_err(|e| format!("workspace canonicalize failed: {e}"))?;
 
     let mut entries = Vec::new();
     let dir = std::fs::read_dir(&target)
         .map_err(|e| format!("read_dir failed for {}: {e}", target.display()))?;
 
+    let filter = options
+        .filter
+        .as_ref()
+        .filter(|f| !f.is_empty())
+        .map(|f| f.to_lowercase());
+    let include_hidden = options.include_hidden.unwrap_or(false);
+    let include_hashes = options.include_hashes.unwrap_or(false);
+    let hide_patterns = workspace_hide_patterns(config, workspace_id)?;
+
     for item in dir {
         let item = item.map_err(|e| format!("read_dir entry error: {e}"))?;
         let item_path = item.path();
@@ -61,9 +928,6 @@ pub fn list_workspace_entries(
             .metadata()
             .map_err(|e| format!("metadata failed for {}: {e}", item_path.display()))?;
 
-        let canonical_base = base
-            .canonicalize()
-            .map_err(|e| format!("workspace canonicalize failed: {e}"))?;
         let canonical_item = item_path
             .canonicalize()
             .map_err(|e| format!("entry canonicalize failed: {e}"))?;
@@ -83,31 +947,102 @@ pub fn list_workspace_entries(
             .to_string_lossy()
             .to_string();
 
+        if let Some(filter) = &filter {
+            if !name.to_lowercase().contains(filter.as_str()) {
+                continue;
+            }
+        }
+
+        if !is_entry_visible(
+            &name,
+            &relative,
+            meta.is_dir(),
+            include_hidden,
+            options.extensions.as_deref(),
+            &hide_patterns,
+        ) {
+            continue;
+        }
+
+        let (is_symlink, symlink_target, readonly, mode) = entry_permission_fields(&item_path, &meta);
+        let hash = if include_hashes && meta.is_file() && meta.len() <= MAX_HASHED_LIST_FILE_BYTES {
+            std::fs::read(&item_path).ok().map(|bytes| content_hash(&bytes))
+        } else {
+            None
+        };
         entries.push(WorkspaceEntry {
             name,
             path: relative,
+            root_id: root_id.to_string(),
             is_directory: meta.is_dir(),
             size: if meta.is_file() { Some(meta.len()) } else { None },
             modified_ms: now_modified_ms(&meta),
+            is_symlink,
+            symlink_target,
+            readonly,
+            mode,
+            content_hash: hash,
         });
     }
 
-    entries.sort_by(|a, b| match (a.is_directory, b.is_directory) {
-        (true, false) => std::cmp::Ordering::Less,
-        (false, true) => std::cmp::Ordering::Greater,
-        _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
-    });
-
     Ok(entries)
 }
 
+pub fn list_workspace_entries(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    rel_or_abs: &str,
+    options: &WorkspaceListOptions,
+) -> Result<WorkspaceListResult, String> {
+    let mut entries = Vec::new();
+    match options.root_id.as_deref() {
+        Some(root_id) => {
+            let (resolved_root_id, base) = resolve_workspace_root(config, workspace_id, Some(root_id))?;
+            entries.extend(list_entries_in_root(config, workspace_id, &resolved_root_id, &base, rel_or_abs, options)?);
+        }
+        None => {
+            for (root_id, base) in workspace_all_roots(config, workspace_id)? {
+                entries.extend(list_entries_in_root(config, workspace_id, &root_id, &base, rel_or_abs, options)?);
+            }
+        }
+    }
+
+    match options.sort_by {
+        Some(WorkspaceEntrySortBy::Size) => {
+            entries.sort_by(|a, b| b.size.unwrap_or(0).cmp(&a.size.unwrap_or(0)));
+        }
+        Some(WorkspaceEntrySortBy::Modified) => {
+            entries.sort_by(|a, b| b.modified_ms.unwrap_or(0).cmp(&a.modified_ms.unwrap_or(0)));
+        }
+        Some(WorkspaceEntrySortBy::Name) | None => {
+            entries.sort_by(|a, b| match (a.is_directory, b.is_directory) {
+                (true, false) => std::cmp::Ordering::Less,
+                (false, true) => std::cmp::Ordering::Greater,
+                _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            });
+        }
+    }
+
+    let total = entries.len() as u64;
+    let offset = options.offset.unwrap_or(0) as usize;
+    let limit = options.limit.map(|value| value as usize).unwrap_or(entries.len());
+    let page = entries.into_iter().skip(offset).take(limit).collect();
+
+    Ok(WorkspaceListResult {
+        entries: page,
+        total,
+        offset: offset as u64,
+    })
+}
+
 pub fn read_workspace_file(
     config: &ControlRoomConfig,
     workspace_id: &str,
+    root_id: Option<&str>,
     rel_or_abs: &str,
     max_bytes: usize,
-) -> Result<String, String> {
-    let base = workspace_base_path(config, workspace_id)?;
+) -> Result<WorkspaceFileContent, String> {
+    let (_, base) = resolve_workspace_root(config, workspace_id, root_id)?;
     let target = secure_target_path(&base, rel_or_abs)?;
 
     let meta = std::fs::metadata(&target)
@@ -125,16 +1060,204 @@ pub fn read_workspace_file(
 
     let raw = std::fs::read(&target)
         .map_err(|e| format!("read failed for {}: {e}", target.display()))?;
-    Ok(String::from_utf8_lossy(&raw).to_string())
+    if looks_binary(&raw) {
+        return Err(format!(
+            "binary file: {}; use controlroom_workspace_read_binary instead",
+            target.display()
+        ));
+    }
+    let (content, encoding, has_bom) = decode_text(&raw);
+    Ok(WorkspaceFileContent {
+        content,
+        modified_ms: now_modified_ms(&meta),
+        hash: content_hash(&raw),
+        encoding,
+        has_bom,
+    })
 }
 
-pub fn write_workspace_file(
+/// Reads a file's raw bytes (up to `max_bytes`) and returns it base64-encoded with a
+/// sniffed MIME type, for content that would be corrupted by the lossy-UTF-8 text path.
+pub fn read_workspace_file_binary(
     config: &ControlRoomConfig,
     workspace_id: &str,
+    root_id: Option<&str>,
     rel_or_abs: &str,
-    content: &str,
     max_bytes: usize,
-) -> Result<bool, String> {
+) -> Result<WorkspaceBinaryFile, String> {
+    let (_, base) = resolve_workspace_root(config, workspace_id, root_id)?;
+    let target = secure_target_path(&base, rel_or_abs)?;
+
+    let meta = std::fs::metadata(&target)
+        .map_err(|e| format!("metadata failed for {}: {e}", target.display()))?;
+    if !meta.is_file() {
+        return Err(format!("not a file: {}", target.display()));
+    }
+
+    let mut file =
+        std::fs::File::open(&target).map_err(|e| format!("open failed for {}: {e}", target.display()))?;
+    let mut raw = vec![0u8; meta.len().min(max_bytes as u64) as usize];
+    file.read_exact(&mut raw)
+        .map_err(|e| format!("read failed for {}: {e}", target.display()))?;
+
+    let mime = infer::get(&raw)
+        .map(|kind| kind.mime_type().to_string())
+        .or_else(|| mime_from_extension(&target))
+        .unwrap_or_else(|| "application/octet-stream".to_string());
+
+    Ok(WorkspaceBinaryFile {
+        base64: STANDARD.encode(&raw),
+        mime,
+        size: meta.len(),
+        is_truncated: meta.len() as usize > raw.len(),
+    })
+}
+
+/// Reads `length` bytes starting at `offset`, without the `read_workspace_file` size
+/// cap, so a multi-GB log can be paged through instead of loaded whole. Trims to a
+/// valid UTF-8 boundary if the requested range lands mid-codepoint.
+pub fn read_workspace_file_range(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    root_id: Option<&str>,
+    rel_or_abs: &str,
+    offset: u64,
+    length: usize,
+) -> Result<WorkspaceFileRange, String> {
+    let (_, base) = resolve_workspace_root(config, workspace_id, root_id)?;
+    let target = secure_target_path(&base, rel_or_abs)?;
+
+    let meta = std::fs::metadata(&target)
+        .map_err(|e| format!("metadata failed for {}: {e}", target.display()))?;
+    if !meta.is_file() {
+        return Err(format!("not a file: {}", target.display()));
+    }
+
+    let total_size = meta.len();
+    let start = offset.min(total_size);
+    let end = start.saturating_add(length as u64).min(total_size);
+
+    let mut file =
+        std::fs::File::open(&target).map_err(|e| format!("open failed for {}: {e}", target.display()))?;
+    file.seek(SeekFrom::Start(start))
+        .map_err(|e| format!("seek failed for {}: {e}", target.display()))?;
+    let mut buf = vec![0u8; (end - start) as usize];
+    file.read_exact(&mut buf)
+        .map_err(|e| format!("read failed for {}: {e}", target.display()))?;
+
+    let (trimmed, dropped_front) = trim_to_utf8_boundary(&buf);
+    let content_start = start + dropped_front as u64;
+
+    Ok(WorkspaceFileRange {
+        content: String::from_utf8_lossy(trimmed).to_string(),
+        start: content_start,
+        end: content_start + trimmed.len() as u64,
+        total_size,
+    })
+}
+
+/// Reads the last `last_n_lines` lines of a file by scanning backwards in fixed-size
+/// chunks from the end, so a 200MB log doesn't have to be loaded whole just to see its
+/// tail. Bounded by both `TAIL_MAX_LINES` and `TAIL_MAX_SCAN_BYTES` so a file with few
+/// (or no) newlines can't force an unbounded read.
+pub fn tail_workspace_file(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    root_id: Option<&str>,
+    rel_or_abs: &str,
+    last_n_lines: usize,
+) -> Result<WorkspaceFileRange, String> {
+    let (_, base) = resolve_workspace_root(config, workspace_id, root_id)?;
+    let target = secure_target_path(&base, rel_or_abs)?;
+
+    let meta = std::fs::metadata(&target)
+        .map_err(|e| format!("metadata failed for {}: {e}", target.display()))?;
+    if !meta.is_file() {
+        return Err(format!("not a file: {}", target.display()));
+    }
+
+    let total_size = meta.len();
+    let last_n_lines = last_n_lines.clamp(1, TAIL_MAX_LINES);
+
+    let mut file =
+        std::fs::File::open(&target).map_err(|e| format!("open failed for {}: {e}", target.display()))?;
+
+    let mut start = total_size;
+    let mut newline_count = 0usize;
+    let mut scanned = 0u64;
+    while start > 0 && newline_count <= last_n_lines && scanned < TAIL_MAX_SCAN_BYTES {
+        let chunk_len = TAIL_CHUNK_BYTES.min(start);
+        start -= chunk_len;
+        scanned += chunk_len;
+
+        file.seek(SeekFrom::Start(start))
+            .map_err(|e| format!("seek failed for {}: {e}", target.display()))?;
+        let mut chunk = vec![0u8; chunk_len as usize];
+        file.read_exact(&mut chunk)
+            .map_err(|e| format!("read failed for {}: {e}", target.display()))?;
+        newline_count += chunk.iter().filter(|&&b| b == b'\n').count();
+    }
+
+    file.seek(SeekFrom::Start(start))
+        .map_err(|e| format!("seek failed for {}: {e}", target.display()))?;
+    let mut buf = vec![0u8; (total_size - start) as usize];
+    file.read_exact(&mut buf)
+        .map_err(|e| format!("read failed for {}: {e}", target.display()))?;
+
+    // Drop whole lines from the front of `buf` until only the last `last_n_lines` remain,
+    // unless the scan ran out of file or budget before finding that many newlines.
+    // A final line with no trailing newline still counts as a line.
+    let unterminated_last_line = buf.last().is_some_and(|&byte| byte != b'\n');
+    let extra_lines = (newline_count + usize::from(unterminated_last_line)).saturating_sub(last_n_lines);
+    let mut drop_before = 0usize;
+    if extra_lines > 0 {
+        let mut seen = 0usize;
+        for (i, &byte) in buf.iter().enumerate() {
+            if byte == b'\n' {
+                seen += 1;
+                if seen == extra_lines {
+                    drop_before = i + 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    let (trimmed, dropped_front) = trim_to_utf8_boundary(&buf[drop_before..]);
+    let content_start = start + (drop_before + dropped_front) as u64;
+
+    Ok(WorkspaceFileRange {
+        content: String::from_utf8_lossy(trimmed).to_string(),
+        start: content_start,
+        end: content_start + trimmed.len() as u64,
+        total_size,
+    })
+}
+
+/// Writes `content` atomically: a temp file is written and fsynced in the target's own
+/// directory, then renamed over the target so a crash or full disk mid-write can never
+/// leave a truncated file in place. On any failure the temp file is removed and the
+/// error names the phase that failed.
+///
+/// Unless `force` is set, `expected_modified_ms`/`expected_hash` (as returned by a prior
+/// `read_workspace_file`) are checked against the file's current state first; a mismatch
+/// means someone else wrote the file since it was read, and the write is skipped in favor
+/// of returning a `WorkspaceWriteConflict` the frontend can use to offer a diff/merge.
+#[allow(clippy::too_many_arguments)]
+pub fn write_workspace_file(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    root_id: Option<&str>,
+    rel_or_abs: &str,
+    content: &str,
+    max_bytes: usize,
+    backup: bool,
+    expected_modified_ms: Option<u64>,
+    expected_hash: Option<&str>,
+    force: bool,
+    encoding: Option<&str>,
+) -> Result<WorkspaceWriteResult, String> {
+    ensure_workspace_writable(config, workspace_id)?;
     if content.as_bytes().len() > max_bytes {
         return Err(format!(
             "content too large for editor save: {} bytes (max {})",
@@ -143,7 +1266,7 @@ pub fn write_workspace_file(
         ));
     }
 
-    let base = workspace_base_path(config, workspace_id)?;
+    let (_, base) = resolve_workspace_root(config, workspace_id, root_id)?;
     let target = secure_target_path(&base, rel_or_abs)?;
     let meta = std::fs::metadata(&target)
         .map_err(|e| format!("metadata failed for {}: {e}", target.display()))?;
@@ -151,7 +1274,1930 @@ pub fn write_workspace_file(
         return Err(format!("not a file: {}", target.display()));
     }
 
-    std::fs::write(&target, content)
-        .map_err(|e| format!("write failed for {}: {e}", target.display()))?;
+    if !force && (expected_modified_ms.is_some() || expected_hash.is_some()) {
+        let current_modified_ms = now_modified_ms(&meta);
+        let current_bytes = std::fs::read(&target)
+            .map_err(|e| format!("read failed for {}: {e}", target.display()))?;
+        let current_hash = content_hash(&current_bytes);
+
+        let modified_matches = expected_modified_ms
+            .map(|expected| Some(expected) == current_modified_ms)
+            .unwrap_or(true);
+        let hash_matches = expected_hash.map(|expected| expected == current_hash).unwrap_or(true);
+
+        if !modified_matches || !hash_matches {
+            return Ok(WorkspaceWriteResult {
+                written: false,
+                conflict: Some(WorkspaceWriteConflict { current_hash, current_modified_ms }),
+            });
+        }
+    }
+
+    let existing_bom = {
+        let mut probe = [0u8; 3];
+        std::fs::File::open(&target)
+            .and_then(|mut file| file.read(&mut probe))
+            .ok()
+            .and_then(|n| encoding_rs::Encoding::for_bom(&probe[..n]).map(|(encoding, _)| encoding))
+    };
+    let encoded = encode_text(content, encoding, existing_bom)?;
+
+    if backup {
+        let canonical_base = base
+            .canonicalize()
+            .map_err(|e| format!("workspace canonicalize failed: {e}"))?;
+        let backups_dir = canonical_base.join(".controlroom-backups");
+        std::fs::create_dir_all(&backups_dir)
+            .map_err(|e| format!("backup phase: failed to create backups dir: {e}"))?;
+        let file_name = target.file_name().and_then(|n| n.to_str()).unwrap_or("file");
+        let backup_path = backups_dir.join(format!("{file_name}.{}.bak", now_ms()));
+        std::fs::copy(&target, &backup_path)
+            .map_err(|e| format!("backup phase: failed to copy {} to {}: {e}", target.display(), backup_path.display()))?;
+    }
+
+    let parent = target
+        .parent()
+        .ok_or_else(|| "write phase: target has no parent directory".to_string())?;
+    let temp_path = parent.join(format!(
+        ".controlroom-tmp-{}-{}",
+        std::process::id(),
+        now_ms()
+    ));
+
+    let write_result = (|| -> Result<(), String> {
+        let mut temp_file = std::fs::File::create(&temp_path)
+            .map_err(|e| format!("write phase: failed to create temp file: {e}"))?;
+        temp_file
+            .write_all(&encoded)
+            .map_err(|e| format!("write phase: failed to write temp file: {e}"))?;
+        temp_file
+            .sync_all()
+            .map_err(|e| format!("write phase: failed to fsync temp file: {e}"))?;
+        std::fs::set_permissions(&temp_path, meta.permissions())
+            .map_err(|e| format!("permissions phase: failed to set permissions on temp file: {e}"))?;
+        std::fs::rename(&temp_path, &target)
+            .map_err(|e| format!("rename phase: failed to replace {}: {e}", target.display()))?;
+        Ok(())
+    })();
+
+    if let Err(error) = write_result {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err(error);
+    }
+
+    Ok(WorkspaceWriteResult { written: true, conflict: None })
+}
+
+/// Bytes read per chunk while streaming a file through a hasher, so gigabyte files
+/// don't need to be loaded into memory at once.
+const CHECKSUM_CHUNK_BYTES: usize = 1024 * 1024;
+
+enum StreamingHasher {
+    Sha256(Sha256),
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl StreamingHasher {
+    fn new(algorithm: &WorkspaceChecksumAlgorithm) -> Self {
+        match algorithm {
+            WorkspaceChecksumAlgorithm::Sha256 => StreamingHasher::Sha256(Sha256::new()),
+            WorkspaceChecksumAlgorithm::Blake3 => StreamingHasher::Blake3(Box::new(blake3::Hasher::new())),
+        }
+    }
+
+    fn update(&mut self, chunk: &[u8]) {
+        match self {
+            StreamingHasher::Sha256(hasher) => hasher.update(chunk),
+            StreamingHasher::Blake3(hasher) => {
+                hasher.update(chunk);
+            }
+        }
+    }
+
+    fn finalize_hex(self) -> String {
+        match self {
+            StreamingHasher::Sha256(hasher) => format!("{:x}", hasher.finalize()),
+            StreamingHasher::Blake3(hasher) => hasher.finalize().to_hex().to_string(),
+        }
+    }
+}
+
+/// Streams `reader` through `algorithm` in fixed-size chunks, checking `cancel` and
+/// `max_bytes` between each one so a huge or unbounded source can't block the caller
+/// (or blow past a caller-imposed size limit) indefinitely.
+fn hash_reader_streaming(
+    mut reader: impl Read,
+    algorithm: &WorkspaceChecksumAlgorithm,
+    max_bytes: Option<u64>,
+    cancel: &CancellationToken,
+) -> Result<(String, u64), String> {
+    let mut hasher = StreamingHasher::new(algorithm);
+    let mut buf = vec![0u8; CHECKSUM_CHUNK_BYTES];
+    let mut bytes_hashed = 0u64;
+
+    loop {
+        if cancel.is_cancelled() {
+            return Err("checksum cancelled".to_string());
+        }
+        let read = reader.read(&mut buf).map_err(|e| format!("read failed: {e}"))?;
+        if read == 0 {
+            break;
+        }
+        bytes_hashed += read as u64;
+        if let Some(max) = max_bytes {
+            if bytes_hashed > max {
+                return Err(format!("content exceeds max size of {max} bytes for checksum"));
+            }
+        }
+        hasher.update(&buf[..read]);
+    }
+
+    Ok((hasher.finalize_hex(), bytes_hashed))
+}
+
+/// Collects every regular file under `dir` (recursively) as a path relative to `base`,
+/// using forward slashes regardless of platform so manifests are comparable across
+/// machines. Symlinks are skipped so a link pointing outside the workspace can't be
+/// followed into hashing something outside the tree.
+fn collect_checksum_files(
+    base: &Path,
+    dir: &Path,
+    out: &mut Vec<String>,
+    cancel: &CancellationToken,
+) -> Result<(), String> {
+    if cancel.is_cancelled() {
+        return Err("checksum cancelled".to_string());
+    }
+    let read_dir =
+        std::fs::read_dir(dir).map_err(|e| format!("read_dir failed for {}: {e}", dir.display()))?;
+    for entry in read_dir {
+        let entry = entry.map_err(|e| format!("read_dir entry failed in {}: {e}", dir.display()))?;
+        let path = entry.path();
+        let file_type = entry
+            .file_type()
+            .map_err(|e| format!("file_type failed for {}: {e}", path.display()))?;
+        if file_type.is_symlink() {
+            continue;
+        }
+        if file_type.is_dir() {
+            collect_checksum_files(base, &path, out, cancel)?;
+        } else if file_type.is_file() {
+            if let Ok(relative) = path.strip_prefix(base) {
+                out.push(relative.to_string_lossy().replace('\\', "/"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Hashes a canonical manifest of `dir`'s contents (sorted relative paths paired with
+/// each file's own hash), so two trees with identical content hash identically
+/// regardless of on-disk ordering.
+fn checksum_directory(
+    dir: &Path,
+    algorithm: &WorkspaceChecksumAlgorithm,
+    max_bytes: Option<u64>,
+    cancel: &CancellationToken,
+) -> Result<(String, u64), String> {
+    let mut relative_paths = Vec::new();
+    collect_checksum_files(dir, dir, &mut relative_paths, cancel)?;
+    relative_paths.sort();
+
+    let mut manifest = String::new();
+    let mut bytes_hashed = 0u64;
+    for relative in &relative_paths {
+        let file_path = dir.join(relative);
+        let file = std::fs::File::open(&file_path)
+            .map_err(|e| format!("open failed for {}: {e}", file_path.display()))?;
+        let (hex, hashed) = hash_reader_streaming(file, algorithm, max_bytes, cancel)?;
+        bytes_hashed += hashed;
+        manifest.push_str(relative);
+        manifest.push(':');
+        manifest.push_str(&hex);
+        manifest.push('\n');
+    }
+
+    let (manifest_hex, _) = hash_reader_streaming(manifest.as_bytes(), algorithm, None, cancel)?;
+    Ok((manifest_hex, bytes_hashed))
+}
+
+/// Computes a checksum for a file or directory. `max_bytes` bounds each individual
+/// file streamed through the hasher; `cancel` lets a caller abort mid-walk (e.g. the
+/// frontend navigated away before a large directory finished hashing).
+pub fn checksum_workspace_entry(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    root_id: Option<&str>,
+    rel_or_abs: &str,
+    algorithm: WorkspaceChecksumAlgorithm,
+    max_bytes: Option<u64>,
+    cancel: &CancellationToken,
+) -> Result<WorkspaceChecksumResult, String> {
+    let (_, base) = resolve_workspace_root(config, workspace_id, root_id)?;
+    let target = secure_target_path(&base, rel_or_abs)?;
+    let started = Instant::now();
+
+    let meta = std::fs::metadata(&target)
+        .map_err(|e| format!("metadata failed for {}: {e}", target.display()))?;
+
+    let (hex, bytes_hashed) = if meta.is_dir() {
+        checksum_directory(&target, &algorithm, max_bytes, cancel)?
+    } else {
+        let file = std::fs::File::open(&target)
+            .map_err(|e| format!("open failed for {}: {e}", target.display()))?;
+        hash_reader_streaming(file, &algorithm, max_bytes, cancel)?
+    };
+
+    Ok(WorkspaceChecksumResult {
+        algorithm,
+        hex,
+        bytes_hashed,
+        duration_ms: started.elapsed().as_millis() as u64,
+    })
+}
+
+pub fn delete_workspace_entry(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    root_id: Option<&str>,
+    rel_or_abs: &str,
+    recursive: bool,
+    use_trash: bool,
+) -> Result<bool, String> {
+    ensure_workspace_writable(config, workspace_id)?;
+    let (_, base) = resolve_workspace_root(config, workspace_id, root_id)?;
+    let target = secure_target_path(&base, rel_or_abs)?;
+
+    let canonical_base = base
+        .canonicalize()
+        .map_err(|e| format!("workspace canonicalize failed: {e}"))?;
+    if target == canonical_base {
+        return Err("refusing to delete the workspace root".to_string());
+    }
+
+    let meta = std::fs::metadata(&target)
+        .map_err(|e| format!("metadata failed for {}: {e}", target.display()))?;
+
+    if meta.is_dir() && !recursive {
+        let count = std::fs::read_dir(&target)
+            .map_err(|e| format!("read_dir failed for {}: {e}", target.display()))?
+            .count();
+        if count > 0 {
+            return Err(format!(
+                "directory not empty ({count} entries); pass recursive to delete anyway: {}",
+                target.display()
+            ));
+        }
+    }
+
+    if use_trash {
+        if let Err(trash_error) = trash::delete(&target) {
+            // Fall back to an in-workspace trash folder when the platform trash API
+            // is unavailable (e.g. a headless/sandboxed environment).
+            let fallback_dir = canonical_base.join(".controlroom-trash");
+            std::fs::create_dir_all(&fallback_dir)
+                .map_err(|e| format!("failed to create fallback trash dir: {e}"))?;
+
+            let file_name = target
+                .file_name()
+                .ok_or_else(|| "target path has no file name".to_string())?;
+            let mut fallback_target = fallback_dir.join(file_name);
+            if fallback_target.exists() {
+                let suffix = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|d| d.as_millis())
+                    .unwrap_or(0);
+                fallback_target = fallback_dir.join(format!("{}-{suffix}", file_name.to_string_lossy()));
+            }
+
+            std::fs::rename(&target, &fallback_target)
+                .map_err(|e| format!("trash delete failed ({trash_error}) and fallback move failed: {e}"))?;
+        }
+    } else if meta.is_dir() {
+        std::fs::remove_dir_all(&target)
+            .map_err(|e| format!("failed to delete directory {}: {e}", target.display()))?;
+    } else {
+        std::fs::remove_file(&target)
+            .map_err(|e| format!("failed to delete file {}: {e}", target.display()))?;
+    }
+
     Ok(true)
 }
+
+/// Convenience wrapper over `delete_workspace_entry` that always moves to trash
+/// (falling back to `.controlroom-trash/` if the platform trash API is unavailable)
+/// rather than permanently deleting, for callers that want a safe default without
+/// exposing the `use_trash`/`recursive` flags. Permanent, non-recoverable delete is
+/// still available directly through `delete_workspace_entry`.
+pub fn trash_workspace_entry(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    root_id: Option<&str>,
+    rel_or_abs: &str,
+) -> Result<bool, String> {
+    delete_workspace_entry(config, workspace_id, root_id, rel_or_abs, true, true)
+}
+
+/// Launches the OS-default application for `rel_or_abs` (e.g. the system editor for a
+/// text file) via the opener plugin, which spawns the external process and returns
+/// immediately rather than waiting for it to exit.
+pub fn open_workspace_file_external(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    root_id: Option<&str>,
+    rel_or_abs: &str,
+    app: &AppHandle,
+) -> Result<(), String> {
+    let (_, base) = resolve_workspace_root(config, workspace_id, root_id)?;
+    let target = secure_target_path(&base, rel_or_abs)?;
+
+    let meta = std::fs::metadata(&target)
+        .map_err(|e| format!("metadata failed for {}: {e}", target.display()))?;
+    if !meta.is_file() {
+        return Err(format!("not a file: {}", target.display()));
+    }
+
+    app.opener()
+        .open_path(target.to_string_lossy().to_string(), None::<&str>)
+        .map_err(|e| format!("failed to open {} externally: {e}", target.display()))
+}
+
+pub fn move_workspace_entry(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    root_id: Option<&str>,
+    from_rel: &str,
+    to_rel: &str,
+    overwrite: bool,
+) -> Result<WorkspaceEntry, String> {
+    ensure_workspace_writable(config, workspace_id)?;
+    let (resolved_root_id, base) = resolve_workspace_root(config, workspace_id, root_id)?;
+    let from = secure_target_path(&base, from_rel)?;
+
+    let canonical_base = base
+        .canonicalize()
+        .map_err(|e| format!("workspace canonicalize failed: {e}"))?;
+    if from == canonical_base {
+        return Err("refusing to move the workspace root".to_string());
+    }
+
+    // The destination doesn't need to exist yet, so validate it the same way a
+    // not-yet-existing create target is validated.
+    let to = secure_target_path_for_create(&base, to_rel, true)?;
+
+    if from == to {
+        return Err("source and destination are the same path".to_string());
+    }
+
+    let from_is_dir = from.is_dir();
+    if from_is_dir && to.starts_with(&from) {
+        return Err("cannot move a directory into its own descendant".to_string());
+    }
+
+    if to.exists() {
+        if !overwrite {
+            return Err(format!("destination already exists: {}", to.display()));
+        }
+        if to.is_dir() {
+            std::fs::remove_dir_all(&to)
+                .map_err(|e| format!("failed to remove existing destination {}: {e}", to.display()))?;
+        } else {
+            std::fs::remove_file(&to)
+                .map_err(|e| format!("failed to remove existing destination {}: {e}", to.display()))?;
+        }
+    }
+
+    if let Err(rename_error) = std::fs::rename(&from, &to) {
+        // A cross-device move (EXDEV) can't be renamed in place; fall back to
+        // copy-then-delete for files. Directories can't be portably copied this way,
+        // so a directory move failure is reported as-is.
+        if from_is_dir {
+            return Err(format!("failed to move {} to {}: {rename_error}", from.display(), to.display()));
+        }
+        std::fs::copy(&from, &to)
+            .map_err(|copy_error| format!(
+                "failed to move {} to {} (rename failed: {rename_error}, fallback copy failed: {copy_error})",
+                from.display(),
+                to.display()
+            ))?;
+        std::fs::remove_file(&from)
+            .map_err(|e| format!("copied {} to {} but failed to remove source: {e}", from.display(), to.display()))?;
+    }
+
+    entry_for_target(&base, &resolved_root_id, &to)
+}
+
+/// The widest mode this function will apply: the standard `rwxrwxrwx` bits. Setuid,
+/// setgid, and sticky bits are rejected outright rather than silently masked off, since
+/// a caller asking for them almost certainly made a mistake and this is IPC-reachable
+/// from the frontend.
+const MAX_SANE_MODE: u32 = 0o777;
+
+/// Applies `mode` to `rel_or_abs` within the workspace. On unix this is a literal
+/// `chmod`. Other platforms have no notion of the executable/group/other bits, so
+/// `mode` is only used to derive the read-only attribute (owner-write bit absent =>
+/// read-only). `secure_target_path` requires the entry to already exist and resolves a
+/// symlink, so the mode is applied to the link's target, and a link whose target lies
+/// outside the workspace is rejected.
+pub fn set_workspace_entry_permissions(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    root_id: Option<&str>,
+    rel_or_abs: &str,
+    mode: u32,
+) -> Result<WorkspaceEntry, String> {
+    ensure_workspace_writable(config, workspace_id)?;
+    let (resolved_root_id, base) = resolve_workspace_root(config, workspace_id, root_id)?;
+    let target = secure_target_path(&base, rel_or_abs)?;
+
+    if mode > MAX_SANE_MODE {
+        return Err(format!(
+            "mode {mode:#o} is out of range; only the standard rwxrwxrwx permission bits (0o000-0o777) are allowed"
+        ));
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&target, std::fs::Permissions::from_mode(mode))
+            .map_err(|e| format!("failed to set permissions on {}: {e}", target.display()))?;
+    }
+
+    #[cfg(not(unix))]
+    {
+        let mut permissions = std::fs::metadata(&target)
+            .map_err(|e| format!("metadata failed for {}: {e}", target.display()))?
+            .permissions();
+        permissions.set_readonly(mode & 0o200 == 0);
+        std::fs::set_permissions(&target, permissions)
+            .map_err(|e| format!("failed to set permissions on {}: {e}", target.display()))?;
+    }
+
+    entry_for_target(&base, &resolved_root_id, &target)
+}
+
+/// Builds a single-workspace config for tests, with everything but `id`/`name`/`path`
+/// left at its default so new `WorkspaceConfig` fields don't need touching here.
+#[cfg(test)]
+fn config_with_workspace(path: &str) -> ControlRoomConfig {
+    let mut config = ControlRoomConfig::default();
+    config.workspaces.push(crate::controlroom::types::WorkspaceConfig {
+        id: "ws".to_string(),
+        name: "ws".to_string(),
+        path: path.to_string(),
+        ignore: None,
+        hide_patterns: None,
+        extra_paths: None,
+        read_only: None,
+    });
+    config
+}
+
+#[cfg(test)]
+mod grep_tests {
+    use super::*;
+
+    fn empty_options() -> WorkspaceGrepOptions {
+        WorkspaceGrepOptions {
+            regex: None,
+            case_sensitive: None,
+            include_globs: None,
+            exclude_globs: None,
+            max_matches: None,
+            max_file_size_bytes: None,
+            respect_gitignore: None,
+            timeout_ms: None,
+        }
+    }
+
+    fn fixture_tree(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::create_dir_all(dir.join("vendor")).unwrap();
+        std::fs::write(dir.join("src/main.rs"), "fn main() {\n    println!(\"hello world\");\n}\n").unwrap();
+        std::fs::write(dir.join("src/lib.rs"), "pub fn helper() -> u32 {\n    42\n}\n").unwrap();
+        std::fs::write(dir.join("vendor/skip.rs"), "// should be excluded\nfn skip() {}\n").unwrap();
+        std::fs::write(dir.join("binary.bin"), [0u8, 1, 2, 3, 0, 5]).unwrap();
+        dir
+    }
+
+    #[test]
+    fn grep_matches_via_regex() {
+        let dir = fixture_tree("controlroom-grep-test-regex");
+        let config = config_with_workspace(&dir.to_string_lossy());
+
+        let mut options = empty_options();
+        options.regex = Some(true);
+        let result = grep_workspace(&config, "ws", r"fn \w+\(\)", &options).unwrap();
+
+        assert!(result.matches.iter().any(|m| m.path == "src/main.rs"));
+        assert!(result.matches.iter().any(|m| m.path == "vendor/skip.rs"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn grep_respects_exclude_globs() {
+        let dir = fixture_tree("controlroom-grep-test-exclude");
+        let config = config_with_workspace(&dir.to_string_lossy());
+
+        let mut options = empty_options();
+        options.regex = Some(true);
+        options.exclude_globs = Some(vec!["vendor/*".to_string()]);
+        let result = grep_workspace(&config, "ws", r"fn \w+\(\)", &options).unwrap();
+
+        assert!(result.matches.iter().any(|m| m.path == "src/main.rs"));
+        assert!(!result.matches.iter().any(|m| m.path == "vendor/skip.rs"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn grep_skips_binary_files() {
+        let dir = fixture_tree("controlroom-grep-test-binary");
+        let config = config_with_workspace(&dir.to_string_lossy());
+
+        let options = empty_options();
+        let result = grep_workspace(&config, "ws", "hello", &options).unwrap();
+
+        assert_eq!(result.files_skipped_binary, 1);
+        assert!(result.matches.iter().any(|m| m.path == "src/main.rs"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
+
+#[cfg(test)]
+mod move_tests {
+    use super::*;
+
+    #[test]
+    fn move_renames_file_in_place() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-move-test-rename");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join("old.txt"), b"hi").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let entry = move_workspace_entry(&config, "ws", None, "old.txt", "new.txt", false).unwrap();
+        assert_eq!(entry.name, "new.txt");
+        assert!(!workspace_dir.join("old.txt").exists());
+        assert!(workspace_dir.join("new.txt").exists());
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn move_relocates_file_into_subdirectory() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-move-test-subdir");
+        let sub_dir = workspace_dir.join("nested");
+        std::fs::create_dir_all(&sub_dir).unwrap();
+        std::fs::write(workspace_dir.join("file.txt"), b"hi").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let entry = move_workspace_entry(&config, "ws", None, "file.txt", "nested/file.txt", false).unwrap();
+        assert_eq!(entry.path, "nested/file.txt");
+        assert!(sub_dir.join("file.txt").exists());
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn move_refuses_overwrite_without_flag() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-move-test-overwrite");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join("a.txt"), b"a").unwrap();
+        std::fs::write(workspace_dir.join("b.txt"), b"b").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let result = move_workspace_entry(&config, "ws", None, "a.txt", "b.txt", false);
+        assert!(result.is_err());
+        assert_eq!(std::fs::read_to_string(workspace_dir.join("b.txt")).unwrap(), "b");
+
+        let overwritten = move_workspace_entry(&config, "ws", None, "a.txt", "b.txt", true).unwrap();
+        assert_eq!(overwritten.name, "b.txt");
+        assert_eq!(std::fs::read_to_string(workspace_dir.join("b.txt")).unwrap(), "a");
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn move_rejects_destination_traversal() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-move-test-traversal");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join("secret.txt"), b"hi").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let result = move_workspace_entry(&config, "ws", None, "secret.txt", "../escaped.txt", false);
+        assert!(result.is_err());
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+}
+
+#[cfg(all(test, unix))]
+mod permissions_tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    #[test]
+    fn set_permissions_makes_script_executable_and_list_reflects_it() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-permissions-test-exec");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join("run.sh"), b"#!/bin/sh\necho hi\n").unwrap();
+        std::fs::set_permissions(workspace_dir.join("run.sh"), std::fs::Permissions::from_mode(0o644)).unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let entry = set_workspace_entry_permissions(&config, "ws", None, "run.sh", 0o755).unwrap();
+        assert_eq!(entry.mode, Some(0o755));
+
+        let page = list_workspace_entries(&config, "ws", "", &WorkspaceListOptions {
+            offset: None,
+            limit: None,
+            sort_by: None,
+            filter: None,
+            include_hidden: None,
+            extensions: None,
+            root_id: None,
+            include_hashes: None,
+        })
+        .unwrap();
+        let listed = page.entries.iter().find(|e| e.name == "run.sh").unwrap();
+        assert_eq!(listed.mode, Some(0o755));
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn set_permissions_rejects_out_of_range_mode() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-permissions-test-range");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join("file.txt"), b"hi").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let result = set_workspace_entry_permissions(&config, "ws", None, "file.txt", 0o4755);
+        assert!(result.is_err());
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+}
+
+#[cfg(test)]
+mod write_tests {
+    use super::*;
+
+    #[test]
+    fn write_rejects_oversized_content_before_touching_disk() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-write-test-oversized");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join("file.txt"), b"original").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let result = write_workspace_file(&config, "ws", None, "file.txt", "too big", 3, false, None, None, false, None);
+        assert!(result.is_err());
+        assert_eq!(std::fs::read_to_string(workspace_dir.join("file.txt")).unwrap(), "original");
+        let leftover_temp_files = std::fs::read_dir(&workspace_dir)
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .any(|entry| entry.file_name().to_string_lossy().starts_with(".controlroom-tmp-"));
+        assert!(!leftover_temp_files);
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn write_replaces_content_atomically() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-write-test-atomic");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join("file.txt"), b"original").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let result = write_workspace_file(&config, "ws", None, "file.txt", "updated", 1024, false, None, None, false, None);
+        assert!(result.unwrap().written);
+        assert_eq!(std::fs::read_to_string(workspace_dir.join("file.txt")).unwrap(), "updated");
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn write_with_backup_preserves_previous_content() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-write-test-backup");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join("file.txt"), b"original").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let result = write_workspace_file(&config, "ws", None, "file.txt", "updated", 1024, true, None, None, false, None);
+        assert!(result.unwrap().written);
+
+        let backups_dir = workspace_dir.join(".controlroom-backups");
+        let backup_content = std::fs::read_dir(&backups_dir)
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .find_map(|entry| std::fs::read_to_string(entry.path()).ok());
+        assert_eq!(backup_content, Some("original".to_string()));
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn write_reports_conflict_when_file_changed_since_read() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-write-test-conflict");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join("file.txt"), b"original").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let read = read_workspace_file(&config, "ws", None, "file.txt", 1024).unwrap();
+
+        // Someone else writes the file between the read and our write.
+        std::fs::write(workspace_dir.join("file.txt"), b"changed by someone else").unwrap();
+
+        let result = write_workspace_file(
+            &config,
+            "ws",
+            None,
+            "file.txt",
+            "my update",
+            1024,
+            false,
+            read.modified_ms,
+            Some(&read.hash),
+            false,
+            None,
+        )
+        .unwrap();
+        assert!(!result.written);
+        assert!(result.conflict.is_some());
+        assert_eq!(
+            std::fs::read_to_string(workspace_dir.join("file.txt")).unwrap(),
+            "changed by someone else"
+        );
+
+        // `force: true` bypasses the check and overwrites anyway.
+        let forced = write_workspace_file(
+            &config,
+            "ws",
+            None,
+            "file.txt",
+            "my update",
+            1024,
+            false,
+            read.modified_ms,
+            Some(&read.hash),
+            true,
+            None,
+        )
+        .unwrap();
+        assert!(forced.written);
+        assert_eq!(std::fs::read_to_string(workspace_dir.join("file.txt")).unwrap(), "my update");
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+}
+
+#[cfg(test)]
+mod range_tests {
+    use super::*;
+
+    #[test]
+    fn read_range_returns_requested_slice_and_total_size() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-range-test-slice");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join("file.txt"), b"0123456789").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let range = read_workspace_file_range(&config, "ws", None, "file.txt", 3, 4).unwrap();
+        assert_eq!(range.content, "3456");
+        assert_eq!(range.start, 3);
+        assert_eq!(range.end, 7);
+        assert_eq!(range.total_size, 10);
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn read_range_trims_partial_utf8_codepoint_at_edges() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-range-test-utf8");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        // "a" + a 3-byte multi-byte character + "b"
+        let bytes = [b"a", "\u{20ac}".as_bytes(), b"b"].concat();
+        std::fs::write(workspace_dir.join("file.txt"), &bytes).unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        // Requesting bytes [1, 3) lands entirely inside the multi-byte character.
+        let range = read_workspace_file_range(&config, "ws", None, "file.txt", 1, 2).unwrap();
+        assert_eq!(range.content, "");
+        assert_eq!(range.start, range.end);
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn tail_returns_last_n_lines_without_loading_whole_file() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-range-test-tail");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        let lines: Vec<String> = (0..50).map(|i| format!("line{i}")).collect();
+        std::fs::write(workspace_dir.join("file.txt"), lines.join("\n")).unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let tail = tail_workspace_file(&config, "ws", None, "file.txt", 3).unwrap();
+        assert_eq!(tail.content, "line47\nline48\nline49");
+        assert_eq!(tail.total_size, lines.join("\n").len() as u64);
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn tail_caps_requested_line_count() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-range-test-tail-cap");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join("file.txt"), "only\ntwo\nlines").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let tail = tail_workspace_file(&config, "ws", None, "file.txt", 1_000_000).unwrap();
+        assert_eq!(tail.content, "only\ntwo\nlines");
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+}
+
+#[cfg(test)]
+mod checksum_tests {
+    use super::*;
+
+    #[test]
+    fn checksum_file_matches_known_sha256() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-checksum-test-file");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join("file.txt"), b"hello").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let result = checksum_workspace_entry(
+            &config,
+            "ws",
+            None,
+            "file.txt",
+            WorkspaceChecksumAlgorithm::Sha256,
+            None,
+            &CancellationToken::new(),
+        )
+        .unwrap();
+        assert_eq!(result.hex, "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824");
+        assert_eq!(result.bytes_hashed, 5);
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn checksum_rejects_files_over_max_size() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-checksum-test-oversized");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join("file.txt"), b"more than three bytes").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let result = checksum_workspace_entry(
+            &config,
+            "ws",
+            None,
+            "file.txt",
+            WorkspaceChecksumAlgorithm::Blake3,
+            Some(3),
+            &CancellationToken::new(),
+        );
+        assert!(result.is_err());
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn checksum_directory_is_stable_regardless_of_walk_order() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-checksum-test-dir");
+        std::fs::create_dir_all(workspace_dir.join("sub")).unwrap();
+        std::fs::write(workspace_dir.join("a.txt"), b"aaa").unwrap();
+        std::fs::write(workspace_dir.join("sub/b.txt"), b"bbb").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let first = checksum_workspace_entry(
+            &config,
+            "ws",
+            None,
+            "",
+            WorkspaceChecksumAlgorithm::Blake3,
+            None,
+            &CancellationToken::new(),
+        )
+        .unwrap();
+        let second = checksum_workspace_entry(
+            &config,
+            "ws",
+            None,
+            "",
+            WorkspaceChecksumAlgorithm::Blake3,
+            None,
+            &CancellationToken::new(),
+        )
+        .unwrap();
+        assert_eq!(first.hex, second.hex);
+        assert_eq!(first.bytes_hashed, 6);
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn checksum_respects_pre_cancelled_token() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-checksum-test-cancel");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join("file.txt"), b"hello").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let result =
+            checksum_workspace_entry(&config, "ws", None, "file.txt", WorkspaceChecksumAlgorithm::Sha256, None, &cancel);
+        assert!(result.is_err());
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+}
+
+#[cfg(test)]
+mod du_tests {
+    use super::*;
+
+    #[test]
+    fn du_aggregates_sizes_per_immediate_child() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-du-test-children");
+        std::fs::create_dir_all(workspace_dir.join("sub")).unwrap();
+        std::fs::write(workspace_dir.join("top.txt"), b"12345").unwrap();
+        std::fs::write(workspace_dir.join("sub/nested.txt"), b"1234567890").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let du = workspace_disk_usage(&config, "ws", "", 5, None).unwrap();
+        assert!(du.completed);
+        assert_eq!(du.total_bytes, 15);
+        assert_eq!(du.file_count, 2);
+        assert_eq!(du.dir_count, 1);
+
+        let sub = du.children.iter().find(|c| c.name == "sub").unwrap();
+        assert!(sub.is_directory);
+        assert_eq!(sub.total_bytes, 10);
+        assert_eq!(sub.file_count, 1);
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn du_top_files_are_sorted_largest_first_and_truncated() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-du-test-top-files");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join("small.txt"), b"a").unwrap();
+        std::fs::write(workspace_dir.join("big.txt"), b"aaaaaaaaaa").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let du = workspace_disk_usage(&config, "ws", "", 5, None).unwrap();
+        assert_eq!(du.top_files.first().unwrap().path, "big.txt");
+        assert_eq!(du.top_files.first().unwrap().size, 10);
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn du_skips_symlinked_escapes() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-du-test-symlink");
+        let outside_dir = std::env::temp_dir().join("controlroom-du-test-symlink-outside");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::create_dir_all(&outside_dir).unwrap();
+        std::fs::write(outside_dir.join("secret.txt"), b"0123456789").unwrap();
+
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&outside_dir, workspace_dir.join("escape")).unwrap();
+
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+        let du = workspace_disk_usage(&config, "ws", "", 5, None).unwrap();
+        assert_eq!(du.total_bytes, 0);
+        assert_eq!(du.file_count, 0);
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+        let _ = std::fs::remove_dir_all(&outside_dir);
+    }
+
+    #[test]
+    fn du_reports_incomplete_when_timeout_elapses() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-du-test-timeout");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        for i in 0..50 {
+            std::fs::write(workspace_dir.join(format!("file{i}.txt")), b"data").unwrap();
+        }
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let du = workspace_disk_usage(&config, "ws", "", 5, Some(0)).unwrap();
+        assert!(!du.completed);
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+}
+
+#[cfg(test)]
+mod list_tests {
+    use super::*;
+    use crate::controlroom::types::WorkspaceConfig;
+
+    #[test]
+    fn list_paginates_and_reports_total() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-list-test-page");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        for i in 0..10 {
+            std::fs::write(workspace_dir.join(format!("file{i:02}.txt")), b"x").unwrap();
+        }
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let page = list_workspace_entries(
+            &config,
+            "ws",
+            "",
+            &WorkspaceListOptions {
+                offset: Some(2),
+                limit: Some(3),
+                sort_by: None,
+                filter: None,
+                include_hidden: None,
+                extensions: None,
+                root_id: None,
+                include_hashes: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(page.total, 10);
+        assert_eq!(page.offset, 2);
+        assert_eq!(page.entries.len(), 3);
+        assert_eq!(page.entries[0].name, "file02.txt");
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn list_filters_by_substring() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-list-test-filter");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join("keep-me.txt"), b"x").unwrap();
+        std::fs::write(workspace_dir.join("other.txt"), b"x").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let page = list_workspace_entries(
+            &config,
+            "ws",
+            "",
+            &WorkspaceListOptions {
+                offset: None,
+                limit: None,
+                sort_by: None,
+                filter: Some("keep".to_string()),
+                include_hidden: None,
+                extensions: None,
+                root_id: None,
+                include_hashes: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(page.total, 1);
+        assert_eq!(page.entries[0].name, "keep-me.txt");
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn list_sorts_by_size_descending() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-list-test-sort-size");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join("small.txt"), b"a").unwrap();
+        std::fs::write(workspace_dir.join("big.txt"), b"aaaaaaaaaa").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let page = list_workspace_entries(
+            &config,
+            "ws",
+            "",
+            &WorkspaceListOptions {
+                offset: None,
+                limit: None,
+                sort_by: Some(WorkspaceEntrySortBy::Size),
+                filter: None,
+                include_hidden: None,
+                extensions: None,
+                root_id: None,
+                include_hashes: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(page.entries[0].name, "big.txt");
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn list_hides_dotfiles_by_default_but_shows_them_when_asked() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-list-test-hidden");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join(".DS_Store"), b"x").unwrap();
+        std::fs::write(workspace_dir.join("visible.txt"), b"x").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let default_page = list_workspace_entries(
+            &config,
+            "ws",
+            "",
+            &WorkspaceListOptions {
+                offset: None,
+                limit: None,
+                sort_by: None,
+                filter: None,
+                include_hidden: None,
+                extensions: None,
+                root_id: None,
+                include_hashes: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(default_page.total, 1);
+        assert_eq!(default_page.entries[0].name, "visible.txt");
+
+        let shown_page = list_workspace_entries(
+            &config,
+            "ws",
+            "",
+            &WorkspaceListOptions {
+                offset: None,
+                limit: None,
+                sort_by: None,
+                filter: None,
+                include_hidden: Some(true),
+                extensions: None,
+                root_id: None,
+                include_hashes: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(shown_page.total, 2);
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn list_applies_extension_whitelist_to_files_only() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-list-test-extensions");
+        std::fs::create_dir_all(workspace_dir.join("sub")).unwrap();
+        std::fs::write(workspace_dir.join("keep.rs"), b"x").unwrap();
+        std::fs::write(workspace_dir.join("skip.txt"), b"x").unwrap();
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let page = list_workspace_entries(
+            &config,
+            "ws",
+            "",
+            &WorkspaceListOptions {
+                offset: None,
+                limit: None,
+                sort_by: None,
+                filter: None,
+                include_hidden: None,
+                extensions: Some(vec!["rs".to_string()]),
+                root_id: None,
+                include_hashes: None,
+            },
+        )
+        .unwrap();
+        let names: Vec<&str> = page.entries.iter().map(|e| e.name.as_str()).collect();
+        assert!(names.contains(&"keep.rs"));
+        assert!(names.contains(&"sub"));
+        assert!(!names.contains(&"skip.txt"));
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    #[test]
+    fn list_applies_workspace_hide_patterns() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-list-test-hide-patterns");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        std::fs::write(workspace_dir.join("keep.txt"), b"x").unwrap();
+        std::fs::write(workspace_dir.join("scratch.tmp"), b"x").unwrap();
+        let mut config = ControlRoomConfig::default();
+        config.workspaces.push(WorkspaceConfig {
+            id: "ws".to_string(),
+            name: "ws".to_string(),
+            path: workspace_dir.to_string_lossy().to_string(),
+            ignore: None,
+            hide_patterns: Some(vec!["*.tmp".to_string()]),
+            extra_paths: None,
+            read_only: None,
+        });
+
+        let page = list_workspace_entries(
+            &config,
+            "ws",
+            "",
+            &WorkspaceListOptions {
+                offset: None,
+                limit: None,
+                sort_by: None,
+                filter: None,
+                include_hidden: None,
+                extensions: None,
+                root_id: None,
+                include_hashes: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(page.total, 1);
+        assert_eq!(page.entries[0].name, "keep.txt");
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+
+    /// Benchmark-style: a 50k-entry directory should list quickly now that the
+    /// canonical base is computed once up front instead of once per entry.
+    #[test]
+    fn list_handles_a_50k_entry_directory_quickly() {
+        let workspace_dir = std::env::temp_dir().join("controlroom-list-test-bench");
+        std::fs::create_dir_all(&workspace_dir).unwrap();
+        for i in 0..50_000 {
+            std::fs::write(workspace_dir.join(format!("file{i}.txt")), b"x").unwrap();
+        }
+        let config = config_with_workspace(&workspace_dir.to_string_lossy());
+
+        let started = Instant::now();
+        let page = list_workspace_entries(
+            &config,
+            "ws",
+            "",
+            &WorkspaceListOptions {
+                offset: Some(0),
+                limit: Some(100),
+                sort_by: None,
+                filter: None,
+                include_hidden: None,
+                extensions: None,
+                root_id: None,
+                include_hashes: None,
+            },
+        )
+        .unwrap();
+        let elapsed = started.elapsed();
+
+        assert_eq!(page.total, 50_000);
+        assert_eq!(page.entries.len(), 100);
+        assert!(elapsed < Duration::from_secs(5), "listing took too long: {elapsed:?}");
+
+        let _ = std::fs::remove_dir_all(&workspace_dir);
+    }
+}
+
+const DEFAULT_SEARCH_EXCLUDE_DIRS: [&str; 2] = [".git", "node_modules"];
+const DEFAULT_SEARCH_MAX_DEPTH: usize = 32;
+
+/// Shell-style wildcard match (`*` any run of characters, `?` any single character),
+/// case-insensitive. Used for filename search patterns rather than full glob crate
+/// support, since only the file name (not path segments) is matched against `pattern`.
+fn glob_match(name: &str, pattern: &str) -> bool {
+    let name: Vec<char> = name.to_lowercase().chars().collect();
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+
+    let (mut ni, mut pi) = (0usize, 0usize);
+    let (mut star_pi, mut star_ni) = (None::<usize>, 0usize);
+
+    while ni < name.len() {
+        if pi < pattern.len() && (pattern[pi] == '?' || pattern[pi] == name[ni]) {
+            ni += 1;
+            pi += 1;
+        } else if pi < pattern.len() && pattern[pi] == '*' {
+            star_pi = Some(pi);
+            star_ni = ni;
+            pi += 1;
+        } else if let Some(sp) = star_pi {
+            pi = sp + 1;
+            star_ni += 1;
+            ni = star_ni;
+        } else {
+            return false;
+        }
+    }
+
+    while pi < pattern.len() && pattern[pi] == '*' {
+        pi += 1;
+    }
+
+    pi == pattern.len()
+}
+
+fn name_matches(name: &str, pattern: &str) -> bool {
+    if pattern.contains('*') || pattern.contains('?') {
+        glob_match(name, pattern)
+    } else {
+        name.to_lowercase().contains(&pattern.to_lowercase())
+    }
+}
+
+/// Searches one root's tree for names matching `pattern`, stopping once `entries`
+/// (shared across roots by the caller) reaches `max_results`. Shared by
+/// `search_workspace_names`, which calls this once per workspace root and merges the
+/// results so a match under an `extraPaths` root isn't missed.
+#[allow(clippy::too_many_arguments)]
+fn search_names_in_root(
+    root_id: &str,
+    base: &Path,
+    pattern: &str,
+    max_results: usize,
+    include_hidden: bool,
+    depth_limit: usize,
+    excluded: &[String],
+    entries: &mut Vec<WorkspaceEntry>,
+) -> Result<bool, String> {
+    let canonical_base = base
+        .canonicalize()
+        .map_err(|e| format!("workspace canonicalize failed: {e}"))?;
+
+    let mut truncated = false;
+    let mut stack = vec![(canonical_base.clone(), 0usize)];
+
+    'walk: while let Some((dir, depth)) = stack.pop() {
+        let read_dir = match std::fs::read_dir(&dir) {
+            Ok(read_dir) => read_dir,
+            Err(_) => continue,
+        };
+
+        for item in read_dir {
+            let Ok(item) = item else { continue };
+            let item_path = item.path();
+            let name = item.file_name().to_string_lossy().to_string();
+
+            if !include_hidden && name.starts_with('.') {
+                continue;
+            }
+
+            let Ok(meta) = item.metadata() else { continue };
+
+            if meta.is_dir() {
+                if excluded.iter().any(|excluded_name| excluded_name == &name.to_lowercase()) {
+                    continue;
+                }
+                if depth < depth_limit {
+                    let canonical_item = match item_path.canonicalize() {
+                        Ok(canonical) => canonical,
+                        Err(_) => continue,
+                    };
+                    if canonical_item.starts_with(&canonical_base) {
+                        stack.push((canonical_item, depth + 1));
+                    }
+                }
+            }
+
+            if name_matches(&name, pattern) {
+                let canonical_item = match item_path.canonicalize() {
+                    Ok(canonical) => canonical,
+                    Err(_) => continue,
+                };
+                if !canonical_item.starts_with(&canonical_base) {
+                    continue;
+                }
+                let relative = canonical_item
+                    .strip_prefix(&canonical_base)
+                    .ok()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .unwrap_or_else(String::new);
+
+                let (is_symlink, symlink_target, readonly, mode) = entry_permission_fields(&item_path, &meta);
+                entries.push(WorkspaceEntry {
+                    name,
+                    path: relative,
+                    root_id: root_id.to_string(),
+                    is_directory: meta.is_dir(),
+                    size: if meta.is_file() { Some(meta.len()) } else { None },
+                    modified_ms: now_modified_ms(&meta),
+                    is_symlink,
+                    symlink_target,
+                    readonly,
+                    mode,
+                    content_hash: None,
+                });
+
+                if entries.len() >= max_results {
+                    truncated = true;
+                    break 'walk;
+                }
+            }
+        }
+    }
+
+    Ok(truncated)
+}
+
+/// Recursively walks every workspace root looking for the `limit` most recently
+/// modified files, sorted descending by `modified_ms`. Symlinks are skipped entirely
+/// (neither reported nor followed) rather than risking a walk outside the workspace or
+/// a cycle. Directories are not reported, only files.
+pub fn list_recently_modified_files(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    limit: usize,
+    max_depth: Option<usize>,
+) -> Result<Vec<WorkspaceEntry>, String> {
+    let depth_limit = max_depth.unwrap_or(DEFAULT_SEARCH_MAX_DEPTH);
+
+    let mut entries = Vec::new();
+    for (root_id, base) in workspace_all_roots(config, workspace_id)? {
+        collect_recently_modified_in_root(&root_id, &base, depth_limit, &mut entries)?;
+    }
+
+    entries.sort_by(|a, b| b.modified_ms.unwrap_or(0).cmp(&a.modified_ms.unwrap_or(0)));
+    entries.truncate(limit);
+    Ok(entries)
+}
+
+fn collect_recently_modified_in_root(
+    root_id: &str,
+    base: &Path,
+    depth_limit: usize,
+    entries: &mut Vec<WorkspaceEntry>,
+) -> Result<(), String> {
+    let canonical_base = base
+        .canonicalize()
+        .map_err(|e| format!("workspace canonicalize failed: {e}"))?;
+
+    let mut stack = vec![(canonical_base.clone(), 0usize)];
+    while let Some((dir, depth)) = stack.pop() {
+        let read_dir = match std::fs::read_dir(&dir) {
+            Ok(read_dir) => read_dir,
+            Err(_) => continue,
+        };
+
+        for item in read_dir {
+            let Ok(item) = item else { continue };
+            let Ok(file_type) = item.file_type() else { continue };
+            if file_type.is_symlink() {
+                continue;
+            }
+
+            let item_path = item.path();
+            let name = item.file_name().to_string_lossy().to_string();
+            let Ok(meta) = item.metadata() else { continue };
+
+            if file_type.is_dir() {
+                if depth < depth_limit {
+                    stack.push((item_path, depth + 1));
+                }
+                continue;
+            }
+            if !file_type.is_file() {
+                continue;
+            }
+
+            let relative = item_path
+                .strip_prefix(&canonical_base)
+                .ok()
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or(name.clone());
+
+            entries.push(WorkspaceEntry {
+                name,
+                path: relative,
+                root_id: root_id.to_string(),
+                is_directory: false,
+                size: Some(meta.len()),
+                modified_ms: now_modified_ms(&meta),
+                is_symlink: false,
+                symlink_target: None,
+                readonly: meta.permissions().readonly(),
+                mode: unix_mode(&meta),
+                content_hash: None,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Walks the workspace tree looking for file/directory names matching `pattern`,
+/// stopping early once `max_results` entries are found. Symlinks are followed only
+/// when their target resolves inside the workspace base; `.git`/`node_modules` (or
+/// `exclude_dirs` if given) are skipped entirely rather than descended into.
+pub fn search_workspace_names(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    pattern: &str,
+    max_results: usize,
+    include_hidden: bool,
+    max_depth: Option<usize>,
+    exclude_dirs: Option<&[String]>,
+) -> Result<WorkspaceSearchResult, String> {
+    let depth_limit = max_depth.unwrap_or(DEFAULT_SEARCH_MAX_DEPTH);
+    let excluded: Vec<String> = exclude_dirs
+        .map(|dirs| dirs.iter().map(|d| d.to_lowercase()).collect())
+        .unwrap_or_else(|| DEFAULT_SEARCH_EXCLUDE_DIRS.iter().map(|d| d.to_lowercase()).collect());
+
+    let mut entries = Vec::new();
+    let mut truncated = false;
+    for (root_id, base) in workspace_all_roots(config, workspace_id)? {
+        if entries.len() >= max_results {
+            truncated = true;
+            break;
+        }
+        let root_truncated = search_names_in_root(
+            &root_id,
+            &base,
+            pattern,
+            max_results,
+            include_hidden,
+            depth_limit,
+            &excluded,
+            &mut entries,
+        )?;
+        truncated = truncated || root_truncated;
+    }
+
+    Ok(WorkspaceSearchResult { entries, truncated })
+}
+
+const DEFAULT_GREP_MAX_MATCHES: usize = 500;
+const DEFAULT_GREP_MAX_FILE_SIZE_BYTES: u64 = 5 * 1024 * 1024;
+const DEFAULT_GREP_TIMEOUT_MS: u64 = 10_000;
+const BINARY_SNIFF_BYTES: usize = 8000;
+
+fn looks_binary(bytes: &[u8]) -> bool {
+    bytes[..bytes.len().min(BINARY_SNIFF_BYTES)].contains(&0u8)
+}
+
+/// Best-effort `.gitignore` support: only the workspace root's own file is read, and
+/// only plain glob lines are honored (no negation, no nested `.gitignore` files). This
+/// covers the common case without pulling in a full gitignore-matching crate.
+fn load_gitignore_patterns(base: &Path) -> Vec<String> {
+    let Ok(content) = std::fs::read_to_string(base.join(".gitignore")) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with('!'))
+        .map(|line| line.trim_start_matches('/').trim_end_matches('/').to_string())
+        .collect()
+}
+
+fn path_matches_any(relative: &str, patterns: &[String]) -> bool {
+    patterns.iter().any(|pattern| {
+        glob_match(relative, pattern)
+            || relative
+                .split('/')
+                .any(|segment| glob_match(segment, pattern))
+    })
+}
+
+/// Searches file contents across the workspace for `query`, in literal or regex mode.
+/// Binary files (detected via a null-byte sniff of the first few KB) and files above
+/// `max_file_size_bytes` are skipped and counted rather than erroring the whole search.
+/// The walk stops early once `max_matches` matching lines are found or `timeout_ms`
+/// elapses, so a huge workspace can't hang the caller.
+pub fn grep_workspace(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    query: &str,
+    options: &WorkspaceGrepOptions,
+) -> Result<WorkspaceGrepResult, String> {
+    let base = workspace_base_path(config, workspace_id)?;
+    let canonical_base = base
+        .canonicalize()
+        .map_err(|e| format!("workspace canonicalize failed: {e}"))?;
+
+    let case_sensitive = options.case_sensitive.unwrap_or(true);
+    let pattern_source = if options.regex.unwrap_or(false) {
+        query.to_string()
+    } else {
+        regex::escape(query)
+    };
+    let pattern = regex::RegexBuilder::new(&pattern_source)
+        .case_insensitive(!case_sensitive)
+        .build()
+        .map_err(|e| format!("invalid grep pattern: {e}"))?;
+
+    let max_matches = options.max_matches.map(|v| v as usize).unwrap_or(DEFAULT_GREP_MAX_MATCHES);
+    let max_file_size = options.max_file_size_bytes.unwrap_or(DEFAULT_GREP_MAX_FILE_SIZE_BYTES);
+    let deadline = Instant::now() + Duration::from_millis(options.timeout_ms.unwrap_or(DEFAULT_GREP_TIMEOUT_MS));
+
+    let gitignore_patterns = if options.respect_gitignore.unwrap_or(false) {
+        load_gitignore_patterns(&canonical_base)
+    } else {
+        Vec::new()
+    };
+
+    let mut result = WorkspaceGrepResult {
+        matches: Vec::new(),
+        files_searched: 0,
+        files_skipped_binary: 0,
+        files_skipped_too_large: 0,
+        truncated: false,
+        timed_out: false,
+    };
+
+    let mut stack = vec![canonical_base.clone()];
+
+    'walk: while let Some(dir) = stack.pop() {
+        if Instant::now() >= deadline {
+            result.timed_out = true;
+            break;
+        }
+
+        let Ok(read_dir) = std::fs::read_dir(&dir) else { continue };
+        for item in read_dir {
+            let Ok(item) = item else { continue };
+            let item_path = item.path();
+            let name = item.file_name().to_string_lossy().to_string();
+            let Ok(meta) = item.metadata() else { continue };
+
+            if meta.is_dir() {
+                if DEFAULT_SEARCH_EXCLUDE_DIRS.iter().any(|excluded| excluded.eq_ignore_ascii_case(&name)) {
+                    continue;
+                }
+                let Ok(canonical_item) = item_path.canonicalize() else { continue };
+                if canonical_item.starts_with(&canonical_base) {
+                    stack.push(canonical_item);
+                }
+                continue;
+            }
+
+            if !meta.is_file() {
+                continue;
+            }
+
+            let Ok(canonical_item) = item_path.canonicalize() else { continue };
+            if !canonical_item.starts_with(&canonical_base) {
+                continue;
+            }
+            let relative = canonical_item
+                .strip_prefix(&canonical_base)
+                .ok()
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_default();
+
+            if let Some(include) = &options.include_globs {
+                if !path_matches_any(&relative, include) {
+                    continue;
+                }
+            }
+            if let Some(exclude) = &options.exclude_globs {
+                if path_matches_any(&relative, exclude) {
+                    continue;
+                }
+            }
+            if path_matches_any(&relative, &gitignore_patterns) {
+                continue;
+            }
+
+            if meta.len() > max_file_size {
+                result.files_skipped_too_large += 1;
+                continue;
+            }
+
+            let Ok(bytes) = std::fs::read(&canonical_item) else { continue };
+            if looks_binary(&bytes) {
+                result.files_skipped_binary += 1;
+                continue;
+            }
+
+            result.files_searched += 1;
+            let text = String::from_utf8_lossy(&bytes);
+            for (line_index, line) in text.lines().enumerate() {
+                let ranges: Vec<MatchRange> = pattern
+                    .find_iter(line)
+                    .map(|m| MatchRange { start: m.start(), end: m.end() })
+                    .collect();
+                if ranges.is_empty() {
+                    continue;
+                }
+
+                result.matches.push(WorkspaceGrepMatch {
+                    path: relative.clone(),
+                    line_number: (line_index + 1) as u64,
+                    line_text: line.to_string(),
+                    match_ranges: ranges,
+                });
+
+                if result.matches.len() >= max_matches {
+                    result.truncated = true;
+                    break 'walk;
+                }
+            }
+
+            if Instant::now() >= deadline {
+                result.timed_out = true;
+                break 'walk;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+pub fn create_workspace_entry(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    root_id: Option<&str>,
+    rel_or_abs: &str,
+    kind: WorkspaceEntryKind,
+    initial_content: Option<String>,
+    overwrite: bool,
+    recursive: bool,
+) -> Result<WorkspaceEntry, String> {
+    ensure_workspace_writable(config, workspace_id)?;
+    let (resolved_root_id, base) = resolve_workspace_root(config, workspace_id, root_id)?;
+    let target = secure_target_path_for_create(&base, rel_or_abs, recursive)?;
+
+    if target.exists() && !overwrite {
+        return Err(format!("entry already exists: {}", target.display()));
+    }
+
+    match kind {
+        WorkspaceEntryKind::Directory => {
+            std::fs::create_dir_all(&target)
+                .map_err(|e| format!("failed to create directory {}: {e}", target.display()))?;
+        }
+        WorkspaceEntryKind::File => {
+            if target.is_dir() {
+                return Err(format!("entry already exists as a directory: {}", target.display()));
+            }
+            std::fs::write(&target, initial_content.unwrap_or_default())
+                .map_err(|e| format!("failed to create file {}: {e}", target.display()))?;
+        }
+    }
+
+    entry_for_target(&base, &resolved_root_id, &target)
+}
+
+/// Permission bits of `meta` (the file-type bits of `st_mode` are masked off).
+#[cfg(unix)]
+fn unix_mode(meta: &std::fs::Metadata) -> Option<u32> {
+    use std::os::unix::fs::PermissionsExt;
+    Some(meta.permissions().mode() & 0o7777)
+}
+
+#[cfg(not(unix))]
+fn unix_mode(_meta: &std::fs::Metadata) -> Option<u32> {
+    None
+}
+
+/// Symlink-aware fields for `WorkspaceEntry`: whether `path` itself is a symlink, its
+/// target if so (reported for display, never dereferenced outside the workspace base),
+/// whether it's read-only, and its unix permission bits (`None` on non-unix). `meta` is
+/// the caller's already-fetched (symlink-following) metadata, reused here for
+/// `readonly`/`mode` so this doesn't need its own extra stat call.
+fn entry_permission_fields(
+    path: &Path,
+    meta: &std::fs::Metadata,
+) -> (bool, Option<String>, bool, Option<u32>) {
+    let is_symlink = std::fs::symlink_metadata(path)
+        .map(|link_meta| link_meta.file_type().is_symlink())
+        .unwrap_or(false);
+    let symlink_target = if is_symlink {
+        std::fs::read_link(path).ok().map(|target| target.to_string_lossy().to_string())
+    } else {
+        None
+    };
+    (is_symlink, symlink_target, meta.permissions().readonly(), unix_mode(meta))
+}
+
+/// Describes `target` (which must already exist on disk) as a `WorkspaceEntry` relative
+/// to `base`, used by every operation that creates or writes a new workspace entry and
+/// then reports back what it made.
+fn entry_for_target(base: &Path, root_id: &str, target: &Path) -> Result<WorkspaceEntry, String> {
+    let meta = std::fs::metadata(target)
+        .map_err(|e| format!("metadata failed for {}: {e}", target.display()))?;
+    let canonical_base = base
+        .canonicalize()
+        .map_err(|e| format!("workspace canonicalize failed: {e}"))?;
+    let canonical_target = target
+        .canonicalize()
+        .map_err(|e| format!("target canonicalize failed: {e}"))?;
+    let relative = canonical_target
+        .strip_prefix(&canonical_base)
+        .ok()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(String::new);
+    let name = target
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let (is_symlink, symlink_target, readonly, mode) = entry_permission_fields(target, &meta);
+
+    Ok(WorkspaceEntry {
+        name,
+        path: relative,
+        root_id: root_id.to_string(),
+        is_directory: meta.is_dir(),
+        size: if meta.is_file() { Some(meta.len()) } else { None },
+        modified_ms: now_modified_ms(&meta),
+        is_symlink,
+        symlink_target,
+        readonly,
+        mode,
+        content_hash: None,
+    })
+}
+
+/// Bound on the read buffer used while streaming a source file into the workspace,
+/// matching `ARCHIVE_CHUNK_BYTES` so a large import never has to be read into memory
+/// whole.
+const IMPORT_CHUNK_BYTES: usize = 1024 * 1024;
+
+/// Copies the file at `source_path` (anywhere on disk) into the workspace at
+/// `dest_relative_path`, streaming through a fixed-size buffer and preserving the
+/// source's mtime. Destination validation goes through `secure_target_path_for_create`
+/// on the parent directory, same as `create_workspace_entry`; `overwrite` defaults to
+/// `false` at the command layer. `on_progress` is called after each chunk with
+/// `(bytes_done, bytes_total)` so a caller can drive a progress bar for large files.
+pub fn import_workspace_file(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    source_path: &str,
+    dest_relative_path: &str,
+    overwrite: bool,
+    mut on_progress: impl FnMut(u64, u64),
+) -> Result<WorkspaceImportResult, String> {
+    ensure_workspace_writable(config, workspace_id)?;
+    let base = workspace_base_path(config, workspace_id)?;
+    let target = secure_target_path_for_create(&base, dest_relative_path, true)?;
+
+    if target.exists() && !overwrite {
+        return Err(format!("entry already exists: {}", target.display()));
+    }
+    if target.is_dir() {
+        return Err(format!("entry already exists as a directory: {}", target.display()));
+    }
+
+    let source = PathBuf::from(source_path);
+    let source_meta = std::fs::metadata(&source)
+        .map_err(|e| format!("metadata failed for {}: {e}", source.display()))?;
+    if !source_meta.is_file() {
+        return Err(format!("not a file: {}", source.display()));
+    }
+    let bytes_total = source_meta.len();
+
+    let mut reader =
+        std::fs::File::open(&source).map_err(|e| format!("open failed for {}: {e}", source.display()))?;
+    let writer = std::fs::File::create(&target)
+        .map_err(|e| format!("failed to create {}: {e}", target.display()))?;
+    let mut writer = std::io::BufWriter::new(writer);
+
+    let mut buf = vec![0u8; IMPORT_CHUNK_BYTES];
+    let mut bytes_done = 0u64;
+    loop {
+        let read = reader
+            .read(&mut buf)
+            .map_err(|e| format!("read failed for {}: {e}", source.display()))?;
+        if read == 0 {
+            break;
+        }
+        writer
+            .write_all(&buf[..read])
+            .map_err(|e| format!("write failed for {}: {e}", target.display()))?;
+        bytes_done += read as u64;
+        on_progress(bytes_done, bytes_total);
+    }
+    writer
+        .into_inner()
+        .map_err(|e| format!("failed to flush {}: {e}", target.display()))?
+        .set_modified(
+            source_meta
+                .modified()
+                .map_err(|e| format!("failed to read mtime for {}: {e}", source.display()))?,
+        )
+        .map_err(|e| format!("failed to preserve mtime on {}: {e}", target.display()))?;
+
+    Ok(WorkspaceImportResult {
+        path: entry_for_target(&base, PRIMARY_WORKSPACE_ROOT_ID, &target)?.path,
+        bytes_copied: bytes_done,
+    })
+}
+
+/// Writes `base64`-decoded bytes to `rel_or_abs` as a new file, for small binary
+/// uploads sent directly from the webview (large transfers should go through
+/// `import_workspace_file` instead). Destination validation goes through
+/// `secure_target_path_for_create` on the parent, same as `create_workspace_entry`.
+pub fn write_workspace_file_binary(
+    config: &ControlRoomConfig,
+    workspace_id: &str,
+    root_id: Option<&str>,
+    rel_or_abs: &str,
+    base64: &str,
+    overwrite: bool,
+) -> Result<WorkspaceEntry, String> {
+    ensure_workspace_writable(config, workspace_id)?;
+    let (resolved_root_id, base) = resolve_workspace_root(config, workspace_id, root_id)?;
+    let target = secure_target_path_for_create(&base, rel_or_abs, true)?;
+
+    if target.exists() && !overwrite {
+        return Err(format!("entry already exists: {}", target.display()));
+    }
+    if target.is_dir() {
+        return Err(format!("entry already exists as a directory: {}", target.display()));
+    }
+
+    let bytes = STANDARD.decode(base64).map_err(|e| format!("invalid base64 content: {e}"))?;
+    std::fs::write(&target, &bytes).map_err(|e| format!("failed to write file {}: {e}", target.display()))?;
+
+    entry_for_target(&base, &resolved_root_id, &target)
+}
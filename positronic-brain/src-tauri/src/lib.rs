@@ -10,6 +10,7 @@ use websocket_server::WebSocketServer;
 use controlroom::ControlRoomState;
 use std::sync::Arc;
 use std::sync::atomic::AtomicU16;
+use tauri::Manager;
 
 // Global atomic to store the WebSocket port (shared between backend and frontend)
 pub static WEBSOCKET_PORT: AtomicU16 = AtomicU16::new(0);
@@ -72,26 +73,110 @@ pub fn run() {
             commands::get_gpu_stats,
             commands::get_websocket_port,
             commands_controlroom::controlroom_load_config,
+            commands_controlroom::controlroom_is_initialized,
+            commands_controlroom::controlroom_reload_config,
+            commands_controlroom::controlroom_save_config,
+            commands_controlroom::controlroom_config_issues,
             commands_controlroom::controlroom_get_services,
             commands_controlroom::controlroom_service_start,
             commands_controlroom::controlroom_service_stop,
             commands_controlroom::controlroom_service_restart,
+            commands_controlroom::controlroom_service_pause,
+            commands_controlroom::controlroom_service_resume,
+            commands_controlroom::controlroom_service_metrics,
+            commands_controlroom::controlroom_service_rolling_restart,
+            commands_controlroom::controlroom_start_all_services,
             commands_controlroom::controlroom_service_status,
             commands_controlroom::controlroom_service_status_all,
+            commands_controlroom::controlroom_service_status_summary,
+            commands_controlroom::controlroom_service_health_history,
+            commands_controlroom::controlroom_service_log_stats,
+            commands_controlroom::controlroom_service_log_stats_all,
             commands_controlroom::controlroom_service_clear_logs,
             commands_controlroom::controlroom_service_logs,
+            commands_controlroom::controlroom_service_logs_all,
             commands_controlroom::controlroom_runner_execute,
+            commands_controlroom::controlroom_runner_presets,
+            commands_controlroom::controlroom_runner_execute_preset,
+            commands_controlroom::controlroom_runner_rerun,
             commands_controlroom::controlroom_runner_cancel,
+            commands_controlroom::controlroom_runner_cancel_all,
+            commands_controlroom::controlroom_runner_cancel_for_workspace,
+            commands_controlroom::controlroom_runner_write_stdin,
+            commands_controlroom::controlroom_runner_close_stdin,
+            commands_controlroom::controlroom_runner_resize,
+            commands_controlroom::controlroom_runner_export,
+            commands_controlroom::controlroom_runner_list,
+            commands_controlroom::controlroom_runner_history,
+            commands_controlroom::controlroom_runner_output,
             commands_controlroom::controlroom_workspace_list,
             commands_controlroom::controlroom_workspace_read_file,
+            commands_controlroom::controlroom_workspace_recent,
+            commands_controlroom::controlroom_workspace_quick_open,
+            commands_controlroom::controlroom_workspace_read_binary,
+            commands_controlroom::controlroom_workspace_read_range,
+            commands_controlroom::controlroom_workspace_tail,
             commands_controlroom::controlroom_workspace_write_file,
+            commands_controlroom::controlroom_workspace_checksum,
+            commands_controlroom::controlroom_workspace_cancel_checksum,
+            commands_controlroom::controlroom_workspace_create,
+            commands_controlroom::controlroom_workspace_write_binary,
+            commands_controlroom::controlroom_workspace_import,
+            commands_controlroom::controlroom_workspace_delete,
+            commands_controlroom::controlroom_workspace_trash,
+            commands_controlroom::controlroom_workspace_open_external,
+            commands_controlroom::controlroom_workspace_move,
+            commands_controlroom::controlroom_workspace_set_permissions,
+            commands_controlroom::controlroom_workspace_search,
+            commands_controlroom::controlroom_workspace_recent_files,
+            commands_controlroom::controlroom_workspace_grep,
+            commands_controlroom::controlroom_workspace_tree,
+            commands_controlroom::controlroom_workspace_du,
+            commands_controlroom::controlroom_workspace_archive,
+            commands_controlroom::controlroom_workspace_watch,
+            commands_controlroom::controlroom_workspace_unwatch,
+            commands_controlroom::controlroom_workspace_follow,
+            commands_controlroom::controlroom_workspace_unfollow,
             commands_controlroom::controlroom_git_commits,
+            commands_controlroom::controlroom_git_branches,
+            commands_controlroom::controlroom_git_status,
+            commands_controlroom::controlroom_git_commit_detail,
+            commands_controlroom::controlroom_git_file_history,
+            commands_controlroom::controlroom_git_blame,
+            commands_controlroom::controlroom_git_diff_file,
+            commands_controlroom::controlroom_git_diff_file_from_head,
+            commands_controlroom::controlroom_git_stage,
+            commands_controlroom::controlroom_git_unstage,
+            commands_controlroom::controlroom_git_commit,
+            commands_controlroom::controlroom_git_checkout,
+            commands_controlroom::controlroom_git_sync_state,
+            commands_controlroom::controlroom_git_sync_state_all,
+            commands_controlroom::controlroom_git_tags,
+            commands_controlroom::controlroom_git_stash_list,
+            commands_controlroom::controlroom_git_stash_push,
+            commands_controlroom::controlroom_git_stash_apply,
+            commands_controlroom::controlroom_git_fetch,
+            commands_controlroom::controlroom_git_pull,
+            commands_controlroom::controlroom_git_push,
             commands_controlroom::controlroom_export_logs,
+            commands_controlroom::controlroom_export_logs_query,
             commands_controlroom::controlroom_video_launch_native,
             commands_controlroom::controlroom_video_snapshot_analyze,
+            commands_controlroom::controlroom_video_start_snapshot_scheduler,
+            commands_controlroom::controlroom_video_stop_snapshot_scheduler,
+            commands_controlroom::controlroom_video_cleanup_snapshots,
+            commands_controlroom::controlroom_video_export_events,
             // Note: PTY terminal I/O now uses WebSocket instead of IPC
             // WebSocket server runs on a dynamically assigned port (9001-9010)
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app_handle, event| {
+            if let tauri::RunEvent::Exit = event {
+                let state = app_handle.state::<ControlRoomState>();
+                tauri::async_runtime::block_on(async {
+                    state.shutdown(app_handle).await;
+                });
+            }
+        });
 }